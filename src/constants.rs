@@ -5,6 +5,8 @@ pub const MAX_PARTICLE_HEIGHT: i32 = 32;
 
 pub const INVENTORY_SIZE: usize = 12;
 
+pub const MAX_SCENT_STRENGTH: u8 = 100;
+
 pub const WINDOW_WIDTH: usize = 800;
 pub const WINDOW_HEIGHT: usize = 600;
 pub const RENDER_SCALE: f32 = 2.0;
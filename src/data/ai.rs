@@ -4,7 +4,13 @@ pub mod ai_goal;
 pub mod ai_goal_result;
 pub mod ai_goal_status;
 pub mod ai_goal_trait;
+pub mod chunk_graph;
 pub mod diet;
 pub mod disposition;
+pub mod drink_source;
+pub mod flow_field;
+pub mod flow_field_target;
 pub mod goals;
+pub mod pet_order;
 pub mod shadowcast;
+pub mod wake_condition;
@@ -10,10 +10,12 @@ pub enum AIAction {
     AttackInDirection {
         direction: Direction,
         attack: Attack,
+        swing_direction: Option<RotationDirection>,
         attack_offsets: Option<Vec<IPosition>>,
     },
     AttackEntity {
         target: Entity,
+        attack: Attack,
     },
     StowItemFromGround {
         item: Entity,
@@ -31,6 +33,13 @@ pub enum AIAction {
     EatFromGround {
         target: Entity,
     },
+    Drink {
+        source: DrinkSource,
+    },
+    FillContainer {
+        item: Entity,
+        pos: IPosition,
+    },
     BuildAtLocation {
         pos: IPosition,
         tile_type: TileType,
@@ -40,4 +49,24 @@ pub enum AIAction {
         recipe: Recipe,
         ingredients: Vec<Entity>,
     },
+    FeedEntity {
+        target: Entity,
+        item: Entity,
+    },
+    CommandPets {
+        order: PetOrder,
+    },
+    Sleep {
+        wake_condition: WakeCondition,
+    },
+    Throw {
+        item: Entity,
+        target: IPosition,
+    },
+    Butcher {
+        target: Entity,
+    },
+    Dig {
+        pos: IPosition,
+    },
 }
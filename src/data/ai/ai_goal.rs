@@ -14,9 +14,23 @@ pub enum AIGoal {
     Eat(EatGoal),
     Build(BuildGoal),
     Craft(CraftGoal),
+    GatherItem(GatherItemGoal),
+    BuildShelter(BuildShelterGoal),
+    Settle(SettleGoal),
+    FeedEntity(FeedEntityGoal),
+    CommandPets(CommandPetsGoal),
+    ObeyOwner(ObeyOwnerGoal),
     FulfilHunger(FulfilHungerGoal),
+    Drink(DrinkGoal),
+    FulfilThirst(FulfilThirstGoal),
+    Sleep(SleepGoal),
+    Throw(ThrowGoal),
+    Butcher(ButcherGoal),
+    Dig(DigGoal),
     FleeDanger(FleeDangerGoal),
     GroupWithAllies(GroupWithAlliesGoal),
+    InvestigateNoise(InvestigateNoiseGoal),
+    TrackScent(TrackScentGoal),
     KillEntity(KillEntityGoal),
     AttackEntity(AttackEntityGoal),
 }
@@ -35,9 +49,23 @@ impl AIGoalTrait for AIGoal {
             Self::Eat(goal) => goal.resolve(parent_entity, data),
             Self::Build(goal) => goal.resolve(parent_entity, data),
             Self::Craft(goal) => goal.resolve(parent_entity, data),
+            Self::GatherItem(goal) => goal.resolve(parent_entity, data),
+            Self::BuildShelter(goal) => goal.resolve(parent_entity, data),
+            Self::Settle(goal) => goal.resolve(parent_entity, data),
+            Self::FeedEntity(goal) => goal.resolve(parent_entity, data),
+            Self::CommandPets(goal) => goal.resolve(parent_entity, data),
+            Self::ObeyOwner(goal) => goal.resolve(parent_entity, data),
             Self::FulfilHunger(goal) => goal.resolve(parent_entity, data),
+            Self::Drink(goal) => goal.resolve(parent_entity, data),
+            Self::FulfilThirst(goal) => goal.resolve(parent_entity, data),
+            Self::Sleep(goal) => goal.resolve(parent_entity, data),
+            Self::Throw(goal) => goal.resolve(parent_entity, data),
+            Self::Butcher(goal) => goal.resolve(parent_entity, data),
+            Self::Dig(goal) => goal.resolve(parent_entity, data),
             Self::FleeDanger(goal) => goal.resolve(parent_entity, data),
             Self::GroupWithAllies(goal) => goal.resolve(parent_entity, data),
+            Self::InvestigateNoise(goal) => goal.resolve(parent_entity, data),
+            Self::TrackScent(goal) => goal.resolve(parent_entity, data),
             Self::KillEntity(goal) => goal.resolve(parent_entity, data),
             Self::AttackEntity(goal) => goal.resolve(parent_entity, data),
         }
@@ -56,11 +84,60 @@ impl AIGoalTrait for AIGoal {
             Self::Eat(goal) => goal.get_textual_representation(data),
             Self::Build(goal) => goal.get_textual_representation(data),
             Self::Craft(goal) => goal.get_textual_representation(data),
+            Self::GatherItem(goal) => goal.get_textual_representation(data),
+            Self::BuildShelter(goal) => goal.get_textual_representation(data),
+            Self::Settle(goal) => goal.get_textual_representation(data),
+            Self::FeedEntity(goal) => goal.get_textual_representation(data),
+            Self::CommandPets(goal) => goal.get_textual_representation(data),
+            Self::ObeyOwner(goal) => goal.get_textual_representation(data),
             Self::FulfilHunger(goal) => goal.get_textual_representation(data),
+            Self::Drink(goal) => goal.get_textual_representation(data),
+            Self::FulfilThirst(goal) => goal.get_textual_representation(data),
+            Self::Sleep(goal) => goal.get_textual_representation(data),
+            Self::Throw(goal) => goal.get_textual_representation(data),
+            Self::Butcher(goal) => goal.get_textual_representation(data),
+            Self::Dig(goal) => goal.get_textual_representation(data),
             Self::FleeDanger(goal) => goal.get_textual_representation(data),
             Self::GroupWithAllies(goal) => goal.get_textual_representation(data),
+            Self::InvestigateNoise(goal) => goal.get_textual_representation(data),
+            Self::TrackScent(goal) => goal.get_textual_representation(data),
             Self::KillEntity(goal) => goal.get_textual_representation(data),
             Self::AttackEntity(goal) => goal.get_textual_representation(data),
         }
     }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        match self {
+            Self::Wander(goal) => goal.get_path(),
+            Self::MoveInDirection(goal) => goal.get_path(),
+            Self::AttackInDirection(goal) => goal.get_path(),
+            Self::TravelPath(goal) => goal.get_path(),
+            Self::TravelToPosition(goal) => goal.get_path(),
+            Self::StowItem(goal) => goal.get_path(),
+            Self::DropItem(goal) => goal.get_path(),
+            Self::HoldItem(goal) => goal.get_path(),
+            Self::Eat(goal) => goal.get_path(),
+            Self::Build(goal) => goal.get_path(),
+            Self::Craft(goal) => goal.get_path(),
+            Self::GatherItem(goal) => goal.get_path(),
+            Self::BuildShelter(goal) => goal.get_path(),
+            Self::Settle(goal) => goal.get_path(),
+            Self::FeedEntity(goal) => goal.get_path(),
+            Self::CommandPets(goal) => goal.get_path(),
+            Self::ObeyOwner(goal) => goal.get_path(),
+            Self::FulfilHunger(goal) => goal.get_path(),
+            Self::Drink(goal) => goal.get_path(),
+            Self::FulfilThirst(goal) => goal.get_path(),
+            Self::Sleep(goal) => goal.get_path(),
+            Self::Throw(goal) => goal.get_path(),
+            Self::Butcher(goal) => goal.get_path(),
+            Self::Dig(goal) => goal.get_path(),
+            Self::FleeDanger(goal) => goal.get_path(),
+            Self::GroupWithAllies(goal) => goal.get_path(),
+            Self::InvestigateNoise(goal) => goal.get_path(),
+            Self::TrackScent(goal) => goal.get_path(),
+            Self::KillEntity(goal) => goal.get_path(),
+            Self::AttackEntity(goal) => goal.get_path(),
+        }
+    }
 }
@@ -5,6 +5,11 @@ pub trait AIGoalTrait {
     fn get_textual_representation(&self, data: &RenderData) -> String;
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult;
 
+    ///The path this goal or one of its child goals is currently following, used for debugging
+    fn get_path(&self) -> Option<&[IPosition]> {
+        None
+    }
+
     fn success() -> AIGoalResult {
         Ok(true)
     }
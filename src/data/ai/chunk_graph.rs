@@ -0,0 +1,318 @@
+use std::{cmp::Ordering, collections::BinaryHeap, convert::TryFrom};
+
+use fxhash::FxHashMap;
+use ndarray::Array2;
+
+use crate::prelude::*;
+
+/// A connected region of passable tiles within a single chunk
+/// These are the nodes of the abstract graph used for long distance route planning
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChunkNode {
+    pub chunk_pos: IPosition,
+    pub region: u16,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChunkConnectivity {
+    //The region each tile belongs to, or None if the tile collides
+    regions: Array2<Option<u16>>,
+}
+
+impl ChunkConnectivity {
+    pub fn from_chunk(chunk: &Chunk) -> Self {
+        let mut regions = Array2::from_elem((CHUNK_SIZE, CHUNK_SIZE), None);
+        let mut stack = Vec::new();
+        let mut next_region = 0;
+
+        for (idx, chunk_tile) in chunk.tiles.indexed_iter() {
+            if chunk_tile.tile.tile_type.collides() || regions[idx].is_some() {
+                continue;
+            }
+
+            //Flood fill with diagonal steps, the same as the tile level A*
+            regions[idx] = Some(next_region);
+            stack.push(idx);
+
+            while let Some((x, y)) = stack.pop() {
+                for dx in -1..=1 {
+                    for dy in -1..=1 {
+                        let neighbour = (
+                            usize::try_from(x as isize + dx),
+                            usize::try_from(y as isize + dy),
+                        );
+
+                        if let (Ok(nx), Ok(ny)) = neighbour {
+                            if nx < CHUNK_SIZE
+                                && ny < CHUNK_SIZE
+                                && regions[(nx, ny)].is_none()
+                                && !chunk.tiles[(nx, ny)].tile.tile_type.collides()
+                            {
+                                regions[(nx, ny)] = Some(next_region);
+                                stack.push((nx, ny));
+                            }
+                        }
+                    }
+                }
+            }
+
+            next_region += 1;
+        }
+
+        Self { regions }
+    }
+
+    pub fn region(&self, local_pos: UPosition) -> Option<u16> {
+        self.regions[local_pos.to_idx().unwrap()]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RouteFrontierNode {
+    node: ChunkNode,
+    distance: u32,
+    heuristic: u32,
+}
+
+impl RouteFrontierNode {
+    fn estimate(&self) -> u32 {
+        self.distance + self.heuristic
+    }
+}
+
+impl PartialEq for RouteFrontierNode {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.estimate().eq(&rhs.estimate())
+    }
+}
+
+impl Eq for RouteFrontierNode {}
+
+impl PartialOrd for RouteFrontierNode {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(&rhs))
+    }
+}
+
+impl Ord for RouteFrontierNode {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        // BinaryHeap is a max-heap, so we invert the Ord results to make it a min-heap
+        self.estimate().cmp(&rhs.estimate()).reverse()
+    }
+}
+
+/// Chunk level connectivity of every chunk that has been generated, whether it is loaded or saved to disk
+#[derive(Debug, Clone, Default)]
+pub struct ChunkGraph {
+    chunks: FxHashMap<IPosition, ChunkConnectivity>,
+}
+
+impl ChunkGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update_chunk(&mut self, chunk_pos: IPosition, chunk: &Chunk) {
+        self.chunks
+            .insert(chunk_pos, ChunkConnectivity::from_chunk(chunk));
+    }
+
+    pub fn node_at(&self, pos: IPosition) -> Option<ChunkNode> {
+        let (chunk_pos, local_pos) = pos.global_to_local();
+
+        self.chunks
+            .get(&chunk_pos)?
+            .region(local_pos)
+            .map(|region| ChunkNode { chunk_pos, region })
+    }
+
+    /// Every tile of `from` that has a neighbour in `to`
+    pub fn crossings(&self, from: ChunkNode, to: ChunkNode) -> Vec<IPosition> {
+        border_positions()
+            .map(|local_pos| IPosition::global_from_local(from.chunk_pos, local_pos))
+            .filter(|pos| self.node_at(*pos) == Some(from))
+            .filter(|pos| {
+                outside_neighbours(*pos, from.chunk_pos)
+                    .any(|neighbour| self.node_at(neighbour) == Some(to))
+            })
+            .collect()
+    }
+
+    pub fn neighbours(&self, node: ChunkNode) -> Vec<ChunkNode> {
+        let mut neighbours = Vec::new();
+
+        for local_pos in border_positions() {
+            let pos = IPosition::global_from_local(node.chunk_pos, local_pos);
+
+            if self.node_at(pos) != Some(node) {
+                continue;
+            }
+
+            for neighbour in outside_neighbours(pos, node.chunk_pos) {
+                if let Some(neighbour_node) = self.node_at(neighbour) {
+                    if !neighbours.contains(&neighbour_node) {
+                        neighbours.push(neighbour_node);
+                    }
+                }
+            }
+        }
+
+        neighbours
+    }
+
+    /// Plans a coarse route of chunk regions from start to end, both inclusive
+    /// Unlike tile paths, routes are returned in travel order
+    pub fn route(&self, start: IPosition, end: IPosition) -> Option<Vec<ChunkNode>> {
+        let start_node = self.node_at(start)?;
+        let end_node = self.node_at(end)?;
+
+        let heuristic = |node: ChunkNode| {
+            let diff = end_node.chunk_pos - node.chunk_pos;
+            diff.x.abs().max(diff.y.abs()) as u32
+        };
+
+        let mut visited: FxHashMap<ChunkNode, (u32, Option<ChunkNode>)> = FxHashMap::default();
+        let mut frontier = BinaryHeap::new();
+
+        visited.insert(start_node, (0, None));
+        frontier.push(RouteFrontierNode {
+            node: start_node,
+            distance: 0,
+            heuristic: heuristic(start_node),
+        });
+
+        while let Some(current) = frontier.pop() {
+            if current.node == end_node {
+                let mut route = vec![end_node];
+
+                while let Some((_, Some(previous))) = visited.get(route.last().unwrap()) {
+                    route.push(*previous);
+                }
+
+                route.reverse();
+                return Some(route);
+            }
+
+            if current.distance > visited[&current.node].0 {
+                //A shorter way here has already been expanded
+                continue;
+            }
+
+            for successor in self.neighbours(current.node) {
+                let distance = current.distance + 1;
+
+                let better_route = visited
+                    .get(&successor)
+                    .map_or(true, |(visited_distance, _)| distance < *visited_distance);
+
+                if better_route {
+                    visited.insert(successor, (distance, Some(current.node)));
+                    frontier.push(RouteFrontierNode {
+                        node: successor,
+                        distance,
+                        heuristic: heuristic(successor),
+                    });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+fn border_positions() -> impl Iterator<Item = UPosition> {
+    let max = CHUNK_SIZE as u32 - 1;
+
+    (0..=max)
+        .flat_map(move |x| (0..=max).map(move |y| UPosition::new(x, y)))
+        .filter(move |pos| pos.x == 0 || pos.y == 0 || pos.x == max || pos.y == max)
+}
+
+fn outside_neighbours(pos: IPosition, chunk_pos: IPosition) -> impl Iterator<Item = IPosition> {
+    (-1..=1)
+        .flat_map(|dy| (-1..=1).map(move |dx| IPosition::new(dx, dy)))
+        .map(move |diff| pos + diff)
+        .filter(move |neighbour| neighbour.global_to_local().0 != chunk_pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_across_chunks() {
+        let mut graph = ChunkGraph::new();
+
+        for x in 0..3 {
+            graph.update_chunk(IPosition::new(x, 0), &Chunk::default());
+        }
+
+        let route = graph
+            .route(IPosition::new(1, 1), IPosition::new(CHUNK_SIZE as i32 * 2 + 5, 5))
+            .unwrap();
+
+        let chunks: Vec<_> = route.iter().map(|node| node.chunk_pos).collect();
+
+        assert_eq!(
+            chunks,
+            vec![
+                IPosition::new(0, 0),
+                IPosition::new(1, 0),
+                IPosition::new(2, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_route_blocked_by_wall() {
+        let mut graph = ChunkGraph::new();
+        let mut walled = Chunk::default();
+
+        for y in 0..CHUNK_SIZE {
+            walled.tiles[[CHUNK_SIZE / 2, y]].tile.tile_type = TileType::Wall {
+                material: Material::Stone,
+            };
+        }
+
+        graph.update_chunk(IPosition::new(0, 0), &Chunk::default());
+        graph.update_chunk(IPosition::new(1, 0), &walled);
+        graph.update_chunk(IPosition::new(2, 0), &Chunk::default());
+
+        assert!(graph
+            .route(IPosition::new(1, 1), IPosition::new(CHUNK_SIZE as i32 * 2 + 5, 5))
+            .is_none());
+
+        //Going around through another chunk row is fine
+        graph.update_chunk(IPosition::new(1, 1), &Chunk::default());
+
+        let route = graph
+            .route(IPosition::new(1, 1), IPosition::new(CHUNK_SIZE as i32 * 2 + 5, 5))
+            .unwrap();
+
+        assert!(route.iter().any(|node| node.chunk_pos == IPosition::new(1, 1)));
+        assert_eq!(route.last().unwrap().chunk_pos, IPosition::new(2, 0));
+    }
+
+    #[test]
+    fn test_regions_split_by_wall() {
+        let mut chunk = Chunk::default();
+
+        for y in 0..CHUNK_SIZE {
+            chunk.tiles[[4, y]].tile.tile_type = TileType::Wall {
+                material: Material::Stone,
+            };
+        }
+
+        let connectivity = ChunkConnectivity::from_chunk(&chunk);
+
+        assert!(connectivity.region(UPosition::new(4, 0)).is_none());
+        assert_ne!(
+            connectivity.region(UPosition::new(0, 0)),
+            connectivity.region(UPosition::new(8, 0))
+        );
+        assert_eq!(
+            connectivity.region(UPosition::new(8, 0)),
+            connectivity.region(UPosition::new(31, 31))
+        );
+    }
+}
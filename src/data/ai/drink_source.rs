@@ -0,0 +1,9 @@
+use specs::Entity;
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DrinkSource {
+    Tile { pos: IPosition },
+    Container { item: Entity },
+}
@@ -0,0 +1,198 @@
+use std::{cmp::Reverse, collections::BinaryHeap, convert::TryFrom};
+
+use ndarray::Array2;
+
+use crate::prelude::*;
+
+//Flee maps are scaled by slightly more than -1 so that fleeing creatures will run past a threat into open space
+//rather than backing themselves into a corner
+const FLEE_NUMERATOR: i32 = -6;
+const FLEE_DENOMINATOR: i32 = 5;
+
+/// A Dijkstra map storing the travel cost from every tile to the nearest source
+/// Any number of creatures can follow it by stepping to their lowest valued neighbour
+#[derive(Debug, Clone)]
+pub struct FlowField {
+    origin: IPosition,
+    values: Array2<Option<i32>>,
+    frontier: BinaryHeap<Reverse<(i32, usize, usize)>>,
+    //What the field was last calculated from, so it only needs redoing once they move
+    sources: Vec<IPosition>,
+}
+
+impl FlowField {
+    pub fn new(origin: IPosition, width: usize, height: usize) -> Self {
+        Self {
+            origin,
+            values: Array2::from_elem((width, height), None),
+            frontier: BinaryHeap::with_capacity(width * height),
+            sources: Vec::new(),
+        }
+    }
+
+    pub fn was_calculated_from(&self, origin: IPosition, sources: &[IPosition]) -> bool {
+        self.origin == origin && self.sources == sources
+    }
+
+    pub fn get(&self, pos: IPosition) -> Option<i32> {
+        let local_pos = UPosition::try_from(pos - self.origin).ok()?;
+
+        self.values.get(local_pos.to_idx().ok()?).copied().flatten()
+    }
+
+    /// Recalculates the field from scratch, cost_fn gives the cost of stepping onto a tile or None if it is impassable
+    pub fn calculate<I, F>(&mut self, origin: IPosition, sources: I, cost_fn: F)
+    where
+        I: IntoIterator<Item = IPosition>,
+        F: Fn(IPosition) -> Option<u32>,
+    {
+        self.origin = origin;
+        self.values.fill(None);
+        self.frontier.clear();
+        self.sources.clear();
+        self.sources.extend(sources);
+
+        for source in self.sources.iter().copied() {
+            if let Some((x, y)) = self.local_idx(source) {
+                self.values[[x, y]] = Some(0);
+                self.frontier.push(Reverse((0, x, y)));
+            }
+        }
+
+        self.flood(&cost_fn);
+    }
+
+    /// Turns a field leading towards its sources into one leading away from them
+    pub fn invert(&mut self, cost_fn: impl Fn(IPosition) -> Option<u32>) {
+        self.frontier.clear();
+
+        for ((x, y), value) in self.values.indexed_iter_mut() {
+            if let Some(value) = value {
+                *value = *value * FLEE_NUMERATOR / FLEE_DENOMINATOR;
+                self.frontier.push(Reverse((*value, x, y)));
+            }
+        }
+
+        //Rescanning lets tiles far from any threat drain towards the best escape routes
+        self.flood(&cost_fn);
+    }
+
+    /// The neighbouring position that leads furthest down the field, if any is an improvement on standing still
+    pub fn downhill(&self, pos: IPosition) -> Option<IPosition> {
+        let current = self.get(pos)?;
+
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| IPosition::new(dx, dy)))
+            .map(|diff| pos + diff)
+            .filter_map(|neighbour| self.get(neighbour).map(|value| (neighbour, value)))
+            .filter(|(_, value)| *value < current)
+            .min_by_key(|(_, value)| *value)
+            .map(|(neighbour, _)| neighbour)
+    }
+
+    fn local_idx(&self, pos: IPosition) -> Option<(usize, usize)> {
+        let [x, y] = UPosition::try_from(pos - self.origin).ok()?.to_idx().ok()?;
+        let (width, height) = self.values.dim();
+
+        (x < width && y < height).then(|| (x, y))
+    }
+
+    fn flood(&mut self, cost_fn: &impl Fn(IPosition) -> Option<u32>) {
+        while let Some(Reverse((value, x, y))) = self.frontier.pop() {
+            if self.values[[x, y]].map_or(false, |current| value > current) {
+                //Already reached this tile more cheaply
+                continue;
+            }
+
+            let pos = self.origin + IPosition::new(x as i32, y as i32);
+
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    let neighbour = pos + IPosition::new(dx, dy);
+
+                    if let Some((nx, ny)) = self.local_idx(neighbour) {
+                        if let Some(cost) = cost_fn(neighbour) {
+                            //Same step weighting as the tile pathfinder, diagonals cost a little more
+                            let step = (1 + dx.abs() + dy.abs()) * cost as i32;
+                            let new_value = value + step;
+
+                            if self.values[[nx, ny]].map_or(true, |current| new_value < current) {
+                                self.values[[nx, ny]] = Some(new_value);
+                                self.frontier.push(Reverse((new_value, nx, ny)));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_downhill_towards_source() {
+        let mut field = FlowField::new(IPosition::ZERO, 10, 10);
+
+        field.calculate(IPosition::ZERO, vec![IPosition::new(9, 5)], |_| Some(1));
+
+        assert_eq!(field.get(IPosition::new(9, 5)), Some(0));
+        assert_eq!(
+            field.downhill(IPosition::new(2, 5)),
+            Some(IPosition::new(3, 5))
+        );
+        assert_eq!(field.downhill(IPosition::new(9, 5)), None);
+    }
+
+    #[test]
+    fn test_walls_are_avoided() {
+        let mut field = FlowField::new(IPosition::ZERO, 10, 10);
+
+        //A wall along x = 5 with a gap at the bottom
+        field.calculate(IPosition::ZERO, vec![IPosition::new(9, 0)], |pos| {
+            (pos.x != 5 || pos.y == 9).then(|| 1)
+        });
+
+        assert_eq!(field.get(IPosition::new(5, 0)), None);
+
+        let mut pos = IPosition::new(0, 0);
+
+        while let Some(next) = field.downhill(pos) {
+            assert!(next.x != 5 || next.y == 9);
+            pos = next;
+        }
+
+        assert_eq!(pos, IPosition::new(9, 0));
+    }
+
+    #[test]
+    fn test_remembers_sources() {
+        let mut field = FlowField::new(IPosition::ZERO, 10, 10);
+        let sources = [IPosition::new(2, 3), IPosition::new(7, 7)];
+
+        field.calculate(IPosition::ZERO, sources.iter().copied(), |_| Some(1));
+
+        assert!(field.was_calculated_from(IPosition::ZERO, &sources));
+        assert!(!field.was_calculated_from(IPosition::ZERO, &sources[..1]));
+        assert!(!field.was_calculated_from(IPosition::new(1, 0), &sources));
+    }
+
+    #[test]
+    fn test_inverted_field_leads_away() {
+        let mut field = FlowField::new(IPosition::ZERO, 20, 1);
+
+        field.calculate(IPosition::ZERO, vec![IPosition::new(5, 0)], |_| Some(1));
+        field.invert(|_| Some(1));
+
+        assert_eq!(
+            field.downhill(IPosition::new(7, 0)),
+            Some(IPosition::new(8, 0))
+        );
+        assert_eq!(
+            field.downhill(IPosition::new(3, 0)),
+            Some(IPosition::new(2, 0))
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+
+/// The shared destinations that flow fields are calculated for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlowFieldTarget {
+    //The average position of every loaded member of a species
+    HerdCentre { species: Species },
+    //Every player controlled creature
+    Player,
+    //Every loaded water tile
+    Water,
+    //Leads away from every loaded creature that the species considers a threat
+    AwayFromThreats { species: Species },
+}
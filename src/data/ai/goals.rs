@@ -2,19 +2,33 @@ pub mod arm_self;
 pub mod attack_entity;
 pub mod attack_in_direction;
 pub mod build;
+pub mod build_shelter;
+pub mod butcher;
+pub mod command_pets;
 pub mod craft;
+pub mod dig;
+pub mod drink;
 pub mod drop_item;
 pub mod eat;
 pub mod eat_from_inventory;
 pub mod eat_from_world;
+pub mod feed_entity;
 pub mod flee_danger;
 pub mod fulfil_hunger;
+pub mod fulfil_thirst;
+pub mod gather_item;
 pub mod group_with_allies;
 pub mod hold_item;
+pub mod investigate_noise;
 pub mod kill_entity;
 pub mod move_in_direction;
 pub mod move_to_entity;
+pub mod obey_owner;
+pub mod settle;
+pub mod sleep;
 pub mod stow_item;
+pub mod throw;
+pub mod track_scent;
 pub mod travel_path;
 pub mod travel_to_position;
 pub mod wander;
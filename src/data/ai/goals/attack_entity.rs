@@ -15,29 +15,39 @@ impl AIGoalTrait for AttackEntityGoal {
         format!("Attack {}", data.name.get(self.target).unwrap().name)
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.move_to_entity_goal.as_ref()?.get_path()
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
         let target = self.target;
-        
+        let this_pos = data.position.get(parent_entity).unwrap().pos;
+        let target_pos = data.position.get(target).unwrap().pos;
+
         //Move there
-        if self
-            .move_to_entity_goal
-            .get_or_insert_with(|| MoveToEntityGoal {
-                target,
-                travel_to_position_goal: None,
-            })
-            .resolve(parent_entity, data)?
-        {
+        if !this_pos.is_adjacent(target_pos) {
+            if !self
+                .move_to_entity_goal
+                .get_or_insert_with(|| MoveToEntityGoal {
+                    target,
+                    travel_to_position_goal: None,
+                })
+                .resolve(parent_entity, data)?
+            {
+                return Self::failure();
+            }
+
+            self.move_to_entity_goal = None;
+
+            //We got where we were going, but the target has moved on
             return Self::failure();
         }
 
         //Attack
-        let this_pos = data.position.get(parent_entity).unwrap().pos;
-        let target_pos = data.position.get(target).unwrap().pos;
-
-        if self
+        if !self
             .attack_in_direction_goal
-            .get_or_insert_with(|| AttackInDirectionGoal {
-                direction: Direction::from_positions(this_pos, target_pos),
+            .get_or_insert_with(|| {
+                AttackInDirectionGoal::new(Direction::from_positions(target_pos, this_pos))
             })
             .resolve(parent_entity, data)?
         {
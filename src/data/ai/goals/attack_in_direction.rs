@@ -7,47 +7,55 @@ use crate::prelude::*;
 pub struct AttackInDirectionGoal {
     //Child goals and data here
     pub direction: Direction,
+    //Picked at random from what's to hand if not chosen
+    pub attack: Option<Attack>,
+    pub swing_direction: Option<RotationDirection>,
+    pub attempted: bool,
+}
+
+impl AttackInDirectionGoal {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            attack: None,
+            swing_direction: None,
+            attempted: false,
+        }
+    }
 }
 
 impl AIGoalTrait for AttackInDirectionGoal {
-    fn get_textual_representation(&self, data: &RenderData) -> String {
-        format!("Attack towards {:?}", self.direction)
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        match &self.attack {
+            Some(attack) => format!("{} towards {:?}", attack.name, self.direction),
+            None => format!("Attack towards {:?}", self.direction),
+        }
     }
 
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
-        //TODO: rewrite:
-        //if Hold a weapon succeeds
-        //-Attack using that weapon
-        //else if I have an attack
-        //-Attack using natural attack
-        //else
-        //-fail
-
-        if let Some(man) = data.manipulator.get(parent_entity) {
-            if let Some(wep) = man.held_item {
-                if let Some(atk) = data.attack.get(wep) {
-                    let attack = atk.available_attacks.choose(&mut thread_rng()).unwrap();
-
-                    Self::action(AIAction::AttackInDirection {
-                        direction: self.direction,
-                        attack: attack.clone(),
-                        attack_offsets: None,
-                    })
-                } else {
-                    println!("Entity attempting to attack using a weapon that has no attacks!");
-                    Self::failure()
-                }
-            } else {
-                //TODO: add a basic attack to creatures, and use this here instead
-                // act.current_action =
-                //     Some(AIAction::AttackEntity {
-                //         target: *entity,
-                //     });
-                println!("Entity attempting to attack without a weapon!");
-                Self::failure()
-            }
+        if self.attempted {
+            return Self::success();
+        }
+
+        let attacks = AttackComponent::get_attacks(parent_entity, &data.attack, &data.manipulator);
+
+        let attack = match &self.attack {
+            //The weapon might have been dropped since the attack was chosen
+            Some(attack) => attacks.iter().find(|available| available.name == attack.name),
+            None => attacks.choose(&mut thread_rng()),
+        };
+
+        if let Some(attack) = attack {
+            self.attempted = true;
+
+            Self::action(AIAction::AttackInDirection {
+                direction: self.direction,
+                attack: attack.clone(),
+                swing_direction: self.swing_direction,
+                attack_offsets: None,
+            })
         } else {
-            println!("Entity attempting to attack without the ability to hold a weapon!");
+            println!("Entity attempting to attack without any way to attack!");
             Self::failure()
         }
     }
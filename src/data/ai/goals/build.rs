@@ -8,6 +8,29 @@ pub struct BuildGoal {
     pub pos: Option<IPosition>,
     pub tile_type: Option<TileType>,
     pub consumed_entity: Option<Entity>,
+    pub travel_to_position_goal: Option<TravelToPositionGoal>,
+    pub attempted: bool,
+}
+
+impl BuildGoal {
+    pub fn new(pos: IPosition, tile_type: Option<TileType>) -> Self {
+        Self {
+            pos: Some(pos),
+            tile_type,
+            consumed_entity: None,
+            travel_to_position_goal: None,
+            attempted: false,
+        }
+    }
+
+    fn fulfils_requirements(item: Entity, tile_type: TileType, data: &GoalData) -> bool {
+        data.crafting_data
+            .material
+            .get(item)
+            .map_or(false, |material| {
+                fulfills_material_requirements(material, tile_type.get_build_requirements())
+            })
+    }
 }
 
 impl AIGoalTrait for BuildGoal {
@@ -19,7 +42,7 @@ impl AIGoalTrait for BuildGoal {
         };
 
         let consumed_entity_name =
-            if let Some(name_component) = self.consumed_entity.map(|e| data.name.get(e).unwrap()) {
+            if let Some(name_component) = self.consumed_entity.and_then(|e| data.name.get(e)) {
                 &name_component.name
             } else {
                 "something"
@@ -37,141 +60,134 @@ impl AIGoalTrait for BuildGoal {
         )
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.travel_to_position_goal.as_ref()?.get_path()
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
-        let pos = data.position.get(parent_entity).unwrap().pos;
+        let build_pos = if let Some(build_pos) = self.pos {
+            build_pos
+        } else {
+            println!("Entity trying to build doesn't know where to build");
+            return Self::failure();
+        };
 
-        if let Some(chunk_tile) = data.tile_world.get(pos) {
-            if let Some(inv) = data.inventory.get(parent_entity) {
-                if let Some(tile_type) = self.tile_type {
-                    if let Some(consumed_entity) = self.consumed_entity {
-                        Self::action(AIAction::BuildAtLocation {
-                            pos,
-                            tile_type: tile_type,
-                            consumed_entity: consumed_entity,
-                        })
-                    } else {
-                        panic!()
-                        // if let Some(inp) = inp {
-                        //     let item_goals = inv
-                        //         .items
-                        //         .iter()
-                        //         .enumerate()
-                        //         .filter_map(|(i, slot)| {
-                        //             slot.and_then(|item| {
-                        //                 if let Some(material) =
-                        //                     crd.material.get(item)
-                        //                 {
-                        //                     if fulfills_material_requirements(
-                        //                         material,
-                        //                         tile_type.get_build_requirements(),
-                        //                     ) {
-                        //                         Some(PopupListItem::from((
-                        //                             i,
-                        //                             if let Some(item_name) =
-                        //                                 nam.get(item)
-                        //                             {
-                        //                                 Some(item_name.name.clone())
-                        //                             } else {
-                        //                                 None
-                        //                             },
-                        //                             AIGoal::Build {
-                        //                                 x: *x,
-                        //                                 y: *y,
-                        //                                 tile_type: Some(*tile_type),
-                        //                                 consumed_entity: Some(item),
-                        //                             },
-                        //                         )))
-                        //                     } else {
-                        //                         None
-                        //                     }
-                        //                 } else {
-                        //                     None
-                        //                 }
-                        //             })
-                        //         })
-                        //         .collect();
-
-                        //     inp.popup = Some(Popup::list(
-                        //         format!("Build with what?",),
-                        //         item_goals,
-                        //     ));
-                        // } else {
-                        //     println!("Entity trying to find building material doesn't have input component");
-                        // }
-                    }
-                } else {
-                    panic!()
-                    // if let Some(inp) = inp {
-                    //     let available_materials: Vec<_> = inv
-                    //         .items
-                    //         .iter()
-                    //         .filter_map(|slot| {
-                    //             if let Some(item) = slot {
-                    //                 if let Some(material) = crd.material.get(*item)
-                    //                 {
-                    //                     Some(material)
-                    //                 } else {
-                    //                     None
-                    //                 }
-                    //             } else {
-                    //                 None
-                    //             }
-                    //         })
-                    //         .collect();
-
-                    //     let tile_goals = chunk_tile
-                    //         .tile
-                    //         .tile_type
-                    //         .available_buildings()
-                    //         .iter()
-                    //         .filter(|building| {
-                    //             let build_requirements =
-                    //                 building.get_build_requirements();
-
-                    //             for available_material in &available_materials {
-                    //                 if fulfills_material_requirements(
-                    //                     available_material,
-                    //                     build_requirements,
-                    //                 ) {
-                    //                     return true;
-                    //                 }
-                    //             }
-
-                    //             false
-                    //         })
-                    //         .enumerate()
-                    //         .map(|(i, tile_type)| {
-                    //             (
-                    //                 i,
-                    //                 Some(String::from(tile_type.get_name())),
-                    //                 AIGoal::Build {
-                    //                     x: *x,
-                    //                     y: *y,
-                    //                     tile_type: Some(*tile_type),
-                    //                     consumed_entity: consumed_entity.clone(),
-                    //                 },
-                    //             )
-                    //         })
-                    //         .map(PopupListItem::from)
-                    //         .collect();
-
-                    //     inp.popup =
-                    //         Some(Popup::list(format!("Build what?"), tile_goals));
-                    // } else {
-                    //     println!(
-                    //         "Entity trying to decide building doesn't have input component"
-                    //     );
-                    // }
-                }
+        let current_tile_type = if let Some(chunk_tile) = data.tile_world.get(build_pos) {
+            chunk_tile.tile.tile_type
+        } else {
+            println!("Entity trying to build in an unloaded tile!");
+            return Self::failure();
+        };
+
+        if self.attempted {
+            return if Some(current_tile_type) == self.tile_type {
+                Self::success()
             } else {
-                println!(
-                    "Entity trying to find building material doesn't have inventory component"
-                );
-                panic!()
+                println!("Entity failed to build");
+                Self::failure()
+            };
+        }
+
+        let inv = if let Some(inv) = data.inventory.get(parent_entity) {
+            inv
+        } else {
+            println!("Entity trying to find building material doesn't have inventory component");
+            return Self::failure();
+        };
+
+        let carried: Vec<_> = inv.items.iter().flatten().copied().collect();
+
+        let tile_type = if let Some(tile_type) = self.tile_type.or_else(|| {
+            current_tile_type
+                .available_buildings()
+                .into_iter()
+                .find(|building| {
+                    carried
+                        .iter()
+                        .any(|item| Self::fulfils_requirements(*item, *building, data))
+                })
+        }) {
+            *self.tile_type.insert(tile_type)
+        } else {
+            println!("Entity has nothing it can build with");
+            return Self::failure();
+        };
+
+        //Material chosen earlier may have been used or dropped since
+        if let Some(consumed_entity) = self.consumed_entity {
+            if !inv.contains(consumed_entity) {
+                self.consumed_entity = None;
             }
+        }
+
+        let consumed_entity = if let Some(consumed_entity) = self.consumed_entity.or_else(|| {
+            carried
+                .iter()
+                .copied()
+                .find(|item| Self::fulfils_requirements(*item, tile_type, data))
+        }) {
+            *self.consumed_entity.insert(consumed_entity)
         } else {
-            println!("Entity trying to build in an unloaded tile!");
-            panic!()
+            println!(
+                "Entity doesn't have the materials to build a {}",
+                tile_type.get_name()
+            );
+            return Self::failure();
+        };
+
+        let pos = data.position.get(parent_entity).unwrap().pos;
+
+        if pos.is_adjacent(build_pos) {
+            self.attempted = true;
+
+            return Self::action(AIAction::BuildAtLocation {
+                pos: build_pos,
+                tile_type,
+                consumed_entity,
+            });
+        }
+
+        //Builders need to stand next to what they build, and can't stand inside it
+        let size = data
+            .species
+            .get(parent_entity)
+            .map(|species| species.species.get_size())
+            .unwrap_or_default();
+
+        let standing_pos = if let Some(standing_pos) = data.tile_world.get_standing_position(
+            build_pos,
+            pos,
+            size,
+            &data.collider,
+            &data.movement_cost,
+        ) {
+            standing_pos
+        } else {
+            println!("Entity cannot find anywhere to stand while building");
+            return Self::failure();
+        };
+
+        if self
+            .travel_to_position_goal
+            .as_ref()
+            .map_or(true, |travel| travel.target_pos != standing_pos)
+        {
+            self.travel_to_position_goal = Some(TravelToPositionGoal {
+                target_pos: standing_pos,
+                travel_path: None,
+            });
+        }
+
+        if self
+            .travel_to_position_goal
+            .as_mut()
+            .unwrap()
+            .resolve(parent_entity, data)?
+        {
+            self.travel_to_position_goal = None;
+            self.resolve(parent_entity, data)
+        } else {
+            Self::failure()
         }
     }
 }
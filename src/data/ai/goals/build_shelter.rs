@@ -0,0 +1,128 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+fn log_requirement() -> RecipeRequirement {
+    RecipeRequirement::All(vec![
+        RecipeRequirement::Material {
+            material: Material::Wood,
+        },
+        RecipeRequirement::Shape {
+            shape: MaterialShape::Log,
+        },
+    ])
+}
+
+const SHELTER_WALL: TileType = TileType::ConstructedWall {
+    material: Material::Wood,
+    material_shape: MaterialShape::Log,
+    wall_feature: None,
+};
+
+//The gap left in the ring of walls so the builder can get in and out
+const DOORWAY_OFFSET: IPosition = IPosition { x: 0, y: 1 };
+
+#[derive(Debug, Clone)]
+pub struct BuildShelterGoal {
+    //Child goals and data here
+    pub home: Option<IPosition>,
+    pub gather_item_goal: Option<GatherItemGoal>,
+    pub build_goal: Option<BuildGoal>,
+}
+
+impl BuildShelterGoal {
+    pub fn new() -> Self {
+        Self {
+            home: None,
+            gather_item_goal: None,
+            build_goal: None,
+        }
+    }
+
+    fn remaining_walls(home: IPosition, data: &GoalData) -> Vec<IPosition> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| IPosition::new(dx, dy)))
+            .filter(|offset| *offset != IPosition::ZERO && *offset != DOORWAY_OFFSET)
+            .map(|offset| home + offset)
+            .filter(|wall_pos| {
+                data.tile_world.get(*wall_pos).map_or(false, |chunk_tile| {
+                    chunk_tile.tile.tile_type == TileType::Ground
+                        && !chunk_tile
+                            .entities
+                            .iter()
+                            .any(|entity| data.collider.get(*entity).is_some())
+                })
+            })
+            .collect()
+    }
+}
+
+impl AIGoalTrait for BuildShelterGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        if let Some(home) = self.home {
+            format!("Build a shelter at {}", home)
+        } else {
+            String::from("Build a shelter")
+        }
+    }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        if let Some(gather_item_goal) = &self.gather_item_goal {
+            gather_item_goal.get_path()
+        } else {
+            self.build_goal.as_ref()?.get_path()
+        }
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        let pos = data.position.get(parent_entity).unwrap().pos;
+        let home = *self.home.get_or_insert(pos);
+
+        if let Some(build_goal) = &mut self.build_goal {
+            if build_goal.resolve(parent_entity, data)? {
+                self.build_goal = None;
+            } else {
+                println!("Entity failed to build part of its shelter");
+                return Self::failure();
+            }
+        }
+
+        let next_wall = if let Some(next_wall) = Self::remaining_walls(home, data)
+            .into_iter()
+            .min_by_key(|wall_pos| {
+                let diff = *wall_pos - pos;
+                diff.x * diff.x + diff.y * diff.y
+            }) {
+            next_wall
+        } else {
+            println!("Entity finished building its shelter");
+            return Self::success();
+        };
+
+        let log = log_requirement();
+
+        let has_log = data.inventory.get(parent_entity).map_or(false, |inv| {
+            inv.items
+                .iter()
+                .flatten()
+                .any(|item| log.requirement_fulfilled(*item, &data.crafting_data))
+        });
+
+        if !has_log {
+            if self
+                .gather_item_goal
+                .get_or_insert_with(|| GatherItemGoal::new(log, 1))
+                .resolve(parent_entity, data)?
+            {
+                self.gather_item_goal = None;
+            } else {
+                println!("Entity could not find logs for its shelter");
+                return Self::failure();
+            }
+        }
+
+        self.build_goal
+            .insert(BuildGoal::new(next_wall, Some(SHELTER_WALL)))
+            .resolve(parent_entity, data)
+    }
+}
@@ -0,0 +1,106 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct ButcherGoal {
+    //Child goals and data here
+    pub target: Entity,
+    pub move_to_entity_goal: Option<MoveToEntityGoal>,
+    pub attempted: bool,
+}
+
+impl ButcherGoal {
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            move_to_entity_goal: None,
+            attempted: false,
+        }
+    }
+
+    //Whether the entity has teeth, claws or a blade to cut with right now
+    pub fn can_butcher(entity: Entity, data: &GoalData) -> bool {
+        AttackComponent::get_attacks(entity, &data.attack, &data.manipulator)
+            .iter()
+            .any(|attack| attack.cuts())
+    }
+}
+
+impl AIGoalTrait for ButcherGoal {
+    fn get_textual_representation(&self, data: &RenderData) -> String {
+        format!(
+            "Butcher {}",
+            data.name
+                .get(self.target)
+                .map_or("something", |name| name.name.as_str())
+        )
+    }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.move_to_entity_goal.as_ref()?.get_path()
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        if self.attempted {
+            return Self::success();
+        }
+
+        if data.crafting_data.butcherable.get(self.target).is_none() {
+            println!("Entity attempting to butcher something that can't be butchered");
+            return Self::failure();
+        }
+
+        if !Self::can_butcher(parent_entity, data) {
+            println!("Entity has nothing to butcher with");
+            if data.input.get(parent_entity).is_some() {
+                data.message_log
+                    .push("You need something sharp to butcher with.");
+            }
+            return Self::failure();
+        }
+
+        let this_pos = data.position.get(parent_entity).unwrap().pos;
+        let target_pos = if let Some(target_pos) = data.position.get(self.target) {
+            target_pos.pos
+        } else {
+            println!("Entity attempting to butcher something that is not in the world");
+            return Self::failure();
+        };
+
+        if this_pos.is_adjacent_or_same(target_pos) {
+            self.attempted = true;
+
+            return Self::action(AIAction::Butcher {
+                target: self.target,
+            });
+        }
+
+        let target = self.target;
+
+        if !self
+            .move_to_entity_goal
+            .get_or_insert_with(|| MoveToEntityGoal {
+                target,
+                travel_to_position_goal: None,
+            })
+            .resolve(parent_entity, data)?
+        {
+            println!("Entity unable to move to carcass");
+            return Self::failure();
+        }
+
+        let this_pos = data.position.get(parent_entity).unwrap().pos;
+
+        if !this_pos.is_adjacent_or_same(target_pos) {
+            println!("Entity finished moving but still can't reach the carcass");
+            return Self::failure();
+        }
+
+        self.attempted = true;
+
+        Self::action(AIAction::Butcher {
+            target: self.target,
+        })
+    }
+}
@@ -0,0 +1,25 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct CommandPetsGoal {
+    //Child goals and data here
+    pub order: PetOrder,
+    pub attempted: bool,
+}
+
+impl AIGoalTrait for CommandPetsGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        format!("Order pets to {}", self.order.get_name())
+    }
+
+    fn resolve(&mut self, _parent_entity: Entity, _data: &mut GoalData) -> AIGoalResult {
+        if self.attempted {
+            Self::success()
+        } else {
+            self.attempted = true;
+            Self::action(AIAction::CommandPets { order: self.order })
+        }
+    }
+}
@@ -7,106 +7,237 @@ pub struct CraftGoal {
     //Child goals and data here
     pub recipe: Recipe,
     pub ingredients: Vec<Entity>,
+    pub gather_item_goal: Option<GatherItemGoal>,
+    pub attempted: bool,
+}
+
+impl CraftGoal {
+    pub fn new(recipe: Recipe) -> Self {
+        Self {
+            recipe,
+            ingredients: Vec::new(),
+            gather_item_goal: None,
+            attempted: false,
+        }
+    }
+
+    //The player picks ingredients one at a time, so their goal is handed over part filled in
+    pub fn with_ingredient(&self, item: Entity) -> Self {
+        let mut ingredients = self.ingredients.clone();
+        ingredients.push(item);
+
+        Self {
+            ingredients,
+            ..Self::new(self.recipe.clone())
+        }
+    }
+
+    //Still waiting on the player for some of its ingredients
+    pub fn is_partly_chosen(&self) -> bool {
+        !self.ingredients.is_empty() && self.ingredients.len() < self.recipe.get_slots().len()
+    }
+
+    //Picks a different carried item for each ingredient, leaving something over to hold as the tool
+    //If something is missing, returns what it needs and how many fitting items must be carried to have enough
+    fn choose_ingredients(
+        &self,
+        parent_entity: Entity,
+        data: &GoalData,
+    ) -> Result<Vec<Entity>, (RecipeRequirement, usize)> {
+        let carried: Vec<_> = data
+            .inventory
+            .get(parent_entity)
+            .map(|inv| inv.items.iter().flatten().copied().collect())
+            .unwrap_or_default();
+
+        let chosen = match self.recipe.choose_ingredients(&carried, &data.crafting_data) {
+            Ok(chosen) => chosen,
+            Err(missing) => {
+                let already_carried = carried
+                    .iter()
+                    .filter(|item| {
+                        missing
+                            .requirement
+                            .requirement_fulfilled(**item, &data.crafting_data)
+                    })
+                    .count();
+
+                return Err((missing.requirement.clone(), already_carried + 1));
+            }
+        };
+
+        let held = data
+            .manipulator
+            .get(parent_entity)
+            .and_then(|man| man.held_item);
+
+        if let Some(tool) = self
+            .recipe
+            .get_missing_tool(held, &chosen, &data.crafting_data)
+        {
+            let fulfils = |item: &Entity| {
+                tool.requirement
+                    .requirement_fulfilled(*item, &data.crafting_data)
+            };
+
+            if !carried
+                .iter()
+                .any(|item| !chosen.contains(item) && fulfils(item))
+            {
+                let already_used = chosen.iter().filter(|item| fulfils(*item)).count();
+
+                return Err((tool.requirement.clone(), already_used + 1));
+            }
+        }
+
+        Ok(chosen)
+    }
+
+    //Equipment keeps hold of what it was made from, while furniture burns its ingredients up
+    //Either way, a craft that didn't go through leaves the ingredients just as they were
+    fn was_crafted(&self, data: &GoalData) -> bool {
+        if self.recipe.output.get_furniture_builder().is_some() {
+            self.ingredients
+                .iter()
+                .all(|item| !data.entities.is_alive(*item))
+        } else {
+            (&data.entities, &data.durability)
+                .join()
+                .any(|(_, dur)| dur.parts == self.ingredients)
+        }
+    }
 }
 
 impl AIGoalTrait for CraftGoal {
     fn get_textual_representation(&self, data: &RenderData) -> String {
-        //TODO: have the string print the ingredients if they exist
+        let ingredient_names: Vec<_> = self
+            .ingredients
+            .iter()
+            .filter_map(|ingredient| data.name.get(*ingredient))
+            .map(|name| name.name.as_str())
+            .collect();
 
-        format!("Craft a {}", self.recipe.get_resulting_object_name())
+        if ingredient_names.is_empty() {
+            format!("Craft a {}", self.recipe.name)
+        } else {
+            format!(
+                "Craft a {} from {}",
+                self.recipe.name,
+                ingredient_names.join(", ")
+            )
+        }
+    }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.gather_item_goal.as_ref()?.get_path()
     }
 
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
-        //TODO: rewrite
-        //This should be created fully formed from the input component
-
-        // if let Some(inp) = inp {
-        //     if let Some(inv) = inv {
-        //         if let Some(recipe) = recipe {
-        //             let requirements = recipe.get_ingredient_requirements();
-        //             let ing_len = ingredients.len();
-        //             let req_len = requirements.len();
-
-        //             if ing_len == req_len {
-        //                 //Check that all ingredients fulfill their respective requirements
-        //                 act.current_action = Some(AIAction::Craft {
-        //                     recipe: *recipe,
-        //                     ingredients: ingredients.clone(),
-        //                 });
-        //             } else if ing_len < req_len {
-        //                 //Ask for next ingredient
-        //                 let requirement = &requirements[ing_len];
-
-        //                 let ingredient_goals = inv
-        //                     .items
-        //                     .iter()
-        //                     .enumerate()
-        //                     .filter_map(|(i, slot)| {
-        //                         slot.and_then(|item| {
-        //                             if requirement
-        //                                 .requirement
-        //                                 .requirement_fulfilled(item, &crd)
-        //                             {
-        //                                 let mut appended_ingredients =
-        //                                     ingredients.clone();
-        //                                 appended_ingredients.push(item);
-
-        //                                 Some(PopupListItem::from((
-        //                                     i,
-        //                                     if let Some(item_name) = nam.get(item) {
-        //                                         Some(item_name.name.clone())
-        //                                     } else {
-        //                                         None
-        //                                     },
-        //                                     AIGoal::Craft {
-        //                                         recipe: Some(*recipe),
-        //                                         ingredients: appended_ingredients,
-        //                                     },
-        //                                 )))
-        //                             } else {
-        //                                 None
-        //                             }
-        //                         })
-        //                     })
-        //                     .collect();
-
-        //                 inp.popup = Some(Popup::list(
-        //                     format!("Use what for {}?", requirement.part_name),
-        //                     ingredient_goals,
-        //                 ));
-        //             } else {
-        //                 //Something is very, very wrong
-        //                 println!("Entity attempting to pass too many ingredients to recipe!");
-        //             }
-        //         } else {
-        //             //TODO: allow this to take from surrounding tiles
-        //             //Maybe add a utility function to return all surrounding entities
-        //             let craft_goals = Recipe::iter()
-        //                 .filter(|recipe| {
-        //                     recipe.fulfillable_with_inventory_contents(inv, &crd)
-        //                 })
-        //                 .enumerate()
-        //                 .map(|(i, recipe)| {
-        //                     PopupListItem::from((
-        //                         i,
-        //                         None,
-        //                         AIGoal::Craft {
-        //                             recipe: Some(recipe),
-        //                             ingredients: Vec::new(),
-        //                         },
-        //                     ))
-        //                 })
-        //                 .collect();
-
-        //             inp.popup =
-        //                 Some(Popup::list(format!("Craft what?"), craft_goals));
-        //         }
-        //     } else {
-        //         println!("Entity attempting to find recipe ingredients without an inventory!");
-        //     }
-        // } else {
-        //     println!("Entity attempting to find recipe to craft without an input component!");
-        // }
-        Self::success()
+        if self.attempted {
+            return if self.was_crafted(data) {
+                Self::success()
+            } else {
+                println!("Entity did not manage to make a {}", self.recipe.name);
+                Self::failure()
+            };
+        }
+
+        if data.inventory.get(parent_entity).is_none() {
+            println!("Entity attempting to find recipe ingredients without an inventory!");
+            return Self::failure();
+        }
+
+        //Ingredients are given up front by the player, otherwise we pick or go and find our own
+        if self.ingredients.is_empty() {
+            match self.choose_ingredients(parent_entity, data) {
+                Ok(ingredients) => self.ingredients = ingredients,
+                Err((requirement, count)) => {
+                    if self.gather_item_goal.as_ref().map_or(true, |gather| {
+                        gather.requirement != requirement || gather.count != count
+                    }) {
+                        self.gather_item_goal = Some(GatherItemGoal::new(requirement, count));
+                    }
+
+                    if !self
+                        .gather_item_goal
+                        .as_mut()
+                        .unwrap()
+                        .resolve(parent_entity, data)?
+                    {
+                        println!(
+                            "Entity could not gather what it needs for a {}",
+                            self.recipe.name
+                        );
+                        return Self::failure();
+                    }
+
+                    self.gather_item_goal = None;
+
+                    if let Ok(ingredients) = self.choose_ingredients(parent_entity, data) {
+                        self.ingredients = ingredients;
+                    } else {
+                        println!(
+                            "Entity gathered materials but still cannot make a {}",
+                            self.recipe.name
+                        );
+                        return Self::failure();
+                    }
+                }
+            }
+        }
+
+        //The tool has to be in hand before starting, so put away whatever's there and take it out
+        let held = data
+            .manipulator
+            .get(parent_entity)
+            .and_then(|man| man.held_item);
+
+        if let Some(tool) =
+            self.recipe
+                .get_missing_tool(held, &self.ingredients, &data.crafting_data)
+        {
+            if held.is_some() {
+                return Self::action(AIAction::StowHeldItem);
+            }
+
+            let carried_tool = data.inventory.get(parent_entity).and_then(|inv| {
+                inv.items.iter().flatten().copied().find(|item| {
+                    !self.ingredients.contains(item)
+                        && tool
+                            .requirement
+                            .requirement_fulfilled(*item, &data.crafting_data)
+                })
+            });
+
+            return if let Some(item) = carried_tool {
+                Self::action(AIAction::HoldItemFromInventory { item })
+            } else {
+                println!(
+                    "Entity has no {} to hold while making a {}",
+                    tool.part_name, self.recipe.name
+                );
+                Self::failure()
+            };
+        }
+
+        if let Some(workstation) = self.recipe.workstation {
+            let pos = data.position.get(parent_entity).unwrap().pos;
+
+            if !workstation.is_nearby(pos, &data.tile_world, &data.crafting_data) {
+                println!(
+                    "Entity needs to be by a {} to make a {}",
+                    workstation.get_name(),
+                    self.recipe.name
+                );
+                return Self::failure();
+            }
+        }
+
+        self.attempted = true;
+
+        Self::action(AIAction::Craft {
+            recipe: self.recipe.clone(),
+            ingredients: self.ingredients.clone(),
+        })
     }
 }
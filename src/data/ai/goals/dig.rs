@@ -0,0 +1,69 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct DigGoal {
+    //Child goals and data here
+    pub pos: IPosition,
+    pub attempted: bool,
+}
+
+impl DigGoal {
+    pub fn new(pos: IPosition) -> Self {
+        Self {
+            pos,
+            attempted: false,
+        }
+    }
+
+    //Whether the entity is holding a pick or something else that breaks rock
+    pub fn can_dig(entity: Entity, data: &GoalData) -> bool {
+        data.manipulator
+            .get(entity)
+            .and_then(|man| man.held_item)
+            .map_or(false, |item| data.crafting_data.digger.get(item).is_some())
+    }
+}
+
+impl AIGoalTrait for DigGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        format!("Dig at {}", self.pos)
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        if self.attempted {
+            return Self::success();
+        }
+
+        if !data
+            .tile_world
+            .get(self.pos)
+            .map_or(false, |chunk_tile| chunk_tile.tile.tile_type.can_dig())
+        {
+            println!("Entity attempting to dig where there's nothing to dig");
+            return Self::failure();
+        }
+
+        if !Self::can_dig(parent_entity, data) {
+            println!("Entity has nothing to dig with");
+            if data.input.get(parent_entity).is_some() {
+                data.message_log.push("You need a pick to dig with.");
+            }
+            return Self::failure();
+        }
+
+        if !data
+            .position
+            .get(parent_entity)
+            .map_or(false, |pos| pos.pos.is_adjacent(self.pos))
+        {
+            println!("Entity attempting to dig somewhere it cannot reach");
+            return Self::failure();
+        }
+
+        self.attempted = true;
+
+        Self::action(AIAction::Dig { pos: self.pos })
+    }
+}
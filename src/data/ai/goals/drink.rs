@@ -0,0 +1,85 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct DrinkGoal {
+    //Child goals and data here
+    pub source: DrinkSource,
+    pub attempted: bool,
+}
+
+impl DrinkGoal {
+    pub fn new(source: DrinkSource) -> Self {
+        Self {
+            source,
+            attempted: false,
+        }
+    }
+}
+
+impl AIGoalTrait for DrinkGoal {
+    fn get_textual_representation(&self, data: &RenderData) -> String {
+        match self.source {
+            DrinkSource::Tile { pos } => format!("Drink water at {}", pos),
+            DrinkSource::Container { item } => format!(
+                "Drink from {}",
+                data.name
+                    .get(item)
+                    .map_or("something", |name| name.name.as_str())
+            ),
+        }
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        if self.attempted {
+            return Self::success();
+        }
+
+        if data.hydration.get(parent_entity).is_none() {
+            println!("Entity attempting to drink despite never getting thirsty!");
+            return Self::failure();
+        }
+
+        match self.source {
+            DrinkSource::Tile { pos } => {
+                let this_pos = data.position.get(parent_entity).unwrap().pos;
+
+                if !data.tile_world.is_water(pos) {
+                    println!("Entity attempting to drink from a tile with no water!");
+                    return Self::failure();
+                }
+
+                if !this_pos.is_adjacent_or_same(pos) {
+                    println!("Entity attempting to drink from water it cannot reach!");
+                    return Self::failure();
+                }
+            }
+            DrinkSource::Container { item } => {
+                if !data
+                    .inventory
+                    .get(parent_entity)
+                    .map_or(false, |inv| inv.contains(item))
+                {
+                    println!("Entity attempting to drink from a container it isn't carrying!");
+                    return Self::failure();
+                }
+
+                if data
+                    .water_container
+                    .get(item)
+                    .map_or(true, |container| container.water == 0)
+                {
+                    println!("Entity attempting to drink from an empty container!");
+                    return Self::failure();
+                }
+            }
+        }
+
+        self.attempted = true;
+
+        Self::action(AIAction::Drink {
+            source: self.source,
+        })
+    }
+}
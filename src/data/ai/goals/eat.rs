@@ -12,14 +12,39 @@ pub struct EatGoal {
     pub eat_from_world_goal: Option<EatFromWorldGoal>,
 }
 
+impl EatGoal {
+    //Creatures without a personality aren't picky
+    pub fn can_eat(parent_entity: Entity, target: Entity, data: &GoalData) -> bool {
+        data.edible.get(target).map_or(false, |edible| {
+            data.personality
+                .get(parent_entity)
+                .map_or(true, |pers| edible.is_edible_by(&pers.diet))
+        })
+    }
+}
+
 impl AIGoalTrait for EatGoal {
     fn get_textual_representation(&self, data: &RenderData) -> String {
         format!("Eat {}", data.name.get(self.target).unwrap().name)
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.eat_from_world_goal.as_ref()?.get_path()
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
         let target = self.target;
-        
+
+        if !Self::can_eat(parent_entity, target, data) {
+            println!("Entity attempting to eat something that doesn't fit its diet!");
+
+            if data.input.get(parent_entity).is_some() {
+                data.message_log.push("You can't eat that!");
+            }
+
+            return Self::failure();
+        }
+
         if self
             .eat_from_inventory_goal
             .get_or_insert_with(|| EatFromInventoryGoal {
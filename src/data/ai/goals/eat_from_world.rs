@@ -18,6 +18,10 @@ impl AIGoalTrait for EatFromWorldGoal {
         )
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.move_to_entity_goal.as_ref()?.get_path()
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
         let this_pos = data.position.get(parent_entity).unwrap();
 
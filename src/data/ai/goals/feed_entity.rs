@@ -0,0 +1,58 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct FeedEntityGoal {
+    //Child goals and data here
+    pub direction: Direction,
+    pub attempted: bool,
+}
+
+impl AIGoalTrait for FeedEntityGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        format!("Feed towards {:?}", self.direction)
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        if self.attempted {
+            return Self::success();
+        }
+
+        let pos = data.position.get(parent_entity).unwrap().pos;
+        let target_pos = pos + self.direction.get_offset();
+
+        let target =
+            data.tile_world.get(target_pos).and_then(|chunk_tile| {
+                chunk_tile.entities.iter().copied().find(|entity| {
+                    *entity != parent_entity && data.digestion.get(*entity).is_some()
+                })
+            });
+
+        let target = if let Some(target) = target {
+            target
+        } else {
+            println!("Entity has nothing to feed there");
+            return Self::failure();
+        };
+
+        //Only offer what it'll actually eat
+        let diet = data.personality.get(target).map(|pers| &pers.diet);
+
+        let item = data.inventory.get(parent_entity).and_then(|inv| {
+            inv.items.iter().flatten().copied().find(|item| {
+                data.edible.get(*item).map_or(false, |edible| {
+                    diet.map_or(true, |diet| edible.is_edible_by(diet))
+                })
+            })
+        });
+
+        if let Some(item) = item {
+            self.attempted = true;
+            Self::action(AIAction::FeedEntity { target, item })
+        } else {
+            println!("Entity has no food that it will eat");
+            Self::failure()
+        }
+    }
+}
@@ -5,6 +5,9 @@ use crate::prelude::*;
 #[derive(Debug, Clone)]
 pub struct FleeDangerGoal {
     //Child goals and data here
+    //Where an unseen danger was last heard, and how many more turns to keep running from it
+    pub heard_at: Option<IPosition>,
+    pub panic_turns: u32,
 }
 
 impl AIGoalTrait for FleeDangerGoal {
@@ -17,17 +20,57 @@ impl AIGoalTrait for FleeDangerGoal {
 
         if let Some(this_perc) = data.perception.get(parent_entity) {
             if this_perc.threats.len() > 0 {
-                //The average position of threats
-                //TODO: make this scale depending on how far away the threats are
-                let sum_pos = this_perc
-                    .threats
-                    .iter()
-                    .filter_map(|threat| data.position.get(*threat))
-                    .map(|pos| pos.pos)
-                    .sum();
+                //The shared flee map counts a pet's owner as a threat, so pets only run from what they can see
+                let is_pet = data
+                    .tame
+                    .get(parent_entity)
+                    .map_or(false, |tame| tame.tamed);
+
+                let flee_step = data
+                    .species
+                    .get(parent_entity)
+                    .filter(|_| !is_pet)
+                    .and_then(|species| {
+                        data.flow_fields
+                            .get(FlowFieldTarget::AwayFromThreats {
+                                species: species.species,
+                            })
+                            .and_then(|field| field.downhill(pos))
+                    });
+
+                let direction = if let Some(flee_step) = flee_step {
+                    Direction::from_positions(flee_step, pos)
+                } else {
+                    //Without a flee map, run directly away from the average position of threats
+                    //TODO: make this scale depending on how far away the threats are
+                    let threat_positions: Vec<_> = this_perc
+                        .threats
+                        .iter()
+                        .filter_map(|threat| data.position.get(*threat))
+                        .map(|pos| pos.pos)
+                        .collect();
+
+                    if threat_positions.is_empty() {
+                        println!("Entity cannot tell where its threats are!");
+                        return Self::failure();
+                    }
+
+                    let average_pos = threat_positions.iter().copied().sum::<IPosition>()
+                        / threat_positions.len() as i32;
+
+                    Direction::from_positions(pos, average_pos)
+                };
+
+                MoveInDirectionGoal {
+                    direction,
+                    attempted: false,
+                }
+                .resolve(parent_entity, data)
+            } else if let (Some(heard_at), true) = (self.heard_at, self.panic_turns > 0) {
+                self.panic_turns -= 1;
 
                 MoveInDirectionGoal {
-                    direction: Direction::from_positions(pos, sum_pos),
+                    direction: Direction::from_positions(pos, heard_at),
                     attempted: false,
                 }
                 .resolve(parent_entity, data)
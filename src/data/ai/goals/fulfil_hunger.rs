@@ -7,6 +7,43 @@ use crate::prelude::*;
 pub struct FulfilHungerGoal {
     //Child goals and data here
     pub eat_food_goal: Option<EatGoal>,
+    pub butcher_goal: Option<ButcherGoal>,
+}
+
+impl FulfilHungerGoal {
+    //The closest fresh carcass we can see, as long as we've something to cut it with
+    fn find_carcass(&self, parent_entity: Entity, data: &GoalData) -> Option<Entity> {
+        if let Some(butcher_goal) = &self.butcher_goal {
+            return Some(butcher_goal.target);
+        }
+
+        if !ButcherGoal::can_butcher(parent_entity, data) {
+            return None;
+        }
+
+        let parent_pos = data.position.get(parent_entity)?.pos;
+
+        data.perception
+            .get(parent_entity)?
+            .all
+            .iter()
+            .copied()
+            .filter(|entity| {
+                data.crafting_data.butcherable.get(*entity).is_some()
+                    && !data
+                        .crafting_data
+                        .rot
+                        .get(*entity)
+                        .map_or(false, |rot| rot.is_rotten())
+            })
+            .filter_map(|entity| {
+                data.position
+                    .get(entity)
+                    .map(|pos| (entity, pos.pos.chebyshev_distance(&parent_pos)))
+            })
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(entity, _)| entity)
+    }
 }
 
 impl AIGoalTrait for FulfilHungerGoal {
@@ -14,12 +51,34 @@ impl AIGoalTrait for FulfilHungerGoal {
         String::from("Fulfil hunger")
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        if let Some(butcher_goal) = &self.butcher_goal {
+            return butcher_goal.get_path();
+        }
+
+        self.eat_food_goal.as_ref()?.get_path()
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
-        //TODO: add some greed or stomach size percentage to determine whether the creature is sated or not
+        let metabolism = data
+            .species
+            .get(parent_entity)
+            .map(|spc| spc.species.get_metabolism())
+            .unwrap_or_default();
+
         if let Some(dig) = data.digestion.get(parent_entity) {
-            if dig.get_total_nutrition(&data.edible) >= 100 {
+            let nutrition = dig.get_total_nutrition(&data.edible);
+
+            if nutrition >= metabolism.sated_threshold {
                 Self::success()
             } else {
+                //Don't eat anything that would make us throw up
+                let fits = |item: &Entity| {
+                    EatGoal::can_eat(parent_entity, *item, data)
+                        && metabolism
+                            .can_fit(nutrition, data.edible.get(*item).unwrap().nutrient_value)
+                };
+
                 let mut food = None;
 
                 if let Some(inv) = data.inventory.get(parent_entity) {
@@ -27,7 +86,7 @@ impl AIGoalTrait for FulfilHungerGoal {
                         .items
                         .iter()
                         .filter_map(|item| *item)
-                        .find(|item| data.edible.get(*item).is_some());
+                        .find(fits);
                 }
 
                 let parent_pos = data.position.get(parent_entity).unwrap();
@@ -35,7 +94,7 @@ impl AIGoalTrait for FulfilHungerGoal {
                 if food.is_none() {
                     if let Some(perc) = data.perception.get(parent_entity) {
                         // food = perc.food.choose(&mut thread_rng()).copied();
-                        food = perc.food.iter().min_by_key(|a| {
+                        food = perc.food.iter().filter(|a| fits(a)).min_by_key(|a| {
                             let a_pos = data.position.get(**a).unwrap();
 
                             let pos_delta = a_pos.pos - parent_pos.pos;
@@ -53,14 +112,24 @@ impl AIGoalTrait for FulfilHungerGoal {
                             eat_from_world_goal: None,
                         })
                         .resolve(parent_entity, data)
+                } else if let Some(corpse) = self.find_carcass(parent_entity, data) {
+                    //Nothing ready to eat, but there's a carcass we can cut into
+                    let butchered = self
+                        .butcher_goal
+                        .get_or_insert_with(|| ButcherGoal::new(corpse))
+                        .resolve(parent_entity, data)?;
+
+                    if !butchered {
+                        return Self::failure();
+                    }
+
+                    //The meat is on the ground now, so go and eat it
+                    self.butcher_goal = None;
+                    self.resolve(parent_entity, data)
                 } else {
                     //TODO: change this to be a search for food goal
                     WanderGoal {travel_to_position_goal: None}.resolve(parent_entity, data)
                 }
-
-                //TODO:
-                //If food is found on entity but requires harvest:
-                //-Create child goals "Harvest" with entity and "Eat item" with item
             }
         } else {
             println!("Entity is attempting to fulfil its hunger despite not having a digestion component!");
@@ -0,0 +1,146 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct FulfilThirstGoal {
+    //Child goals and data here
+    pub travel_to_position_goal: Option<TravelToPositionGoal>,
+}
+
+impl FulfilThirstGoal {
+    pub fn new() -> Self {
+        Self {
+            travel_to_position_goal: None,
+        }
+    }
+
+    fn find_container(
+        parent_entity: Entity,
+        data: &GoalData,
+        predicate: impl Fn(&WaterContainerComponent) -> bool,
+    ) -> Option<Entity> {
+        data.inventory.get(parent_entity).and_then(|inv| {
+            inv.items.iter().flatten().copied().find(|item| {
+                data.water_container
+                    .get(*item)
+                    .map_or(false, |container| predicate(container))
+            })
+        })
+    }
+}
+
+impl AIGoalTrait for FulfilThirstGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        String::from("Fulfil thirst")
+    }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.travel_to_position_goal.as_ref()?.get_path()
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        let hyd = if let Some(hyd) = data.hydration.get(parent_entity) {
+            hyd
+        } else {
+            println!("Entity is attempting to fulfil its thirst despite not having a hydration component!");
+            return Self::failure();
+        };
+
+        let pos = data.position.get(parent_entity).unwrap().pos;
+
+        let nearest_water = data.perception.get(parent_entity).and_then(|per| {
+            per.water.iter().copied().min_by_key(|water_pos| {
+                let diff = *water_pos - pos;
+                diff.x * diff.x + diff.y * diff.y
+            })
+        });
+        let reachable_water = nearest_water.filter(|water_pos| pos.is_adjacent_or_same(*water_pos));
+
+        if hyd.is_quenched() {
+            //Top up anything we carry water in while we're by the water
+            if let (Some(water_pos), Some(item)) = (
+                reachable_water,
+                Self::find_container(parent_entity, data, |container| !container.is_full()),
+            ) {
+                return Self::action(AIAction::FillContainer {
+                    item,
+                    pos: water_pos,
+                });
+            }
+
+            return Self::success();
+        }
+
+        if let Some(water_pos) = reachable_water {
+            return DrinkGoal::new(DrinkSource::Tile { pos: water_pos })
+                .resolve(parent_entity, data);
+        }
+
+        //Carried water is saved for when there's none around
+        if let Some(item) =
+            Self::find_container(parent_entity, data, |container| container.water > 0)
+        {
+            return DrinkGoal::new(DrinkSource::Container { item }).resolve(parent_entity, data);
+        }
+
+        let water_pos = if let Some(water_pos) = nearest_water {
+            water_pos
+        } else if let Some(step) = data
+            .flow_fields
+            .get(FlowFieldTarget::Water)
+            .and_then(|field| field.downhill(pos))
+        {
+            //Head for the nearest water we know of until it comes into view
+            return MoveInDirectionGoal {
+                direction: Direction::from_positions(step, pos),
+                attempted: false,
+            }
+            .resolve(parent_entity, data);
+        } else {
+            return WanderGoal {
+                travel_to_position_goal: None,
+            }
+            .resolve(parent_entity, data);
+        };
+
+        let size = data
+            .species
+            .get(parent_entity)
+            .map(|species| species.species.get_size())
+            .unwrap_or_default();
+
+        let standing_pos = if let Some(standing_pos) = data.tile_world.get_standing_position(
+            water_pos,
+            pos,
+            size,
+            &data.collider,
+            &data.movement_cost,
+        ) {
+            standing_pos
+        } else {
+            println!("Entity cannot find anywhere to stand to drink");
+            return Self::failure();
+        };
+
+        if self
+            .travel_to_position_goal
+            .as_ref()
+            .map_or(true, |travel| travel.target_pos != standing_pos)
+        {
+            self.travel_to_position_goal = Some(TravelToPositionGoal {
+                target_pos: standing_pos,
+                travel_path: None,
+            });
+        }
+
+        //Once we arrive the water will be in reach, which is handled above
+        self.travel_to_position_goal
+            .as_mut()
+            .unwrap()
+            .resolve(parent_entity, data)?;
+
+        self.travel_to_position_goal = None;
+        Self::failure()
+    }
+}
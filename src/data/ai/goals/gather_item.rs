@@ -0,0 +1,193 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct GatherItemGoal {
+    pub requirement: RecipeRequirement,
+    //How many fitting items should be carried once this is done
+    pub count: usize,
+    pub target: Option<Entity>,
+    pub travel_to_position_goal: Option<TravelToPositionGoal>,
+}
+
+impl GatherItemGoal {
+    pub fn new(requirement: RecipeRequirement, count: usize) -> Self {
+        Self {
+            requirement,
+            count,
+            target: None,
+            travel_to_position_goal: None,
+        }
+    }
+
+    fn is_item(&self, entity: Entity, data: &GoalData) -> bool {
+        data.collider.get(entity).is_none()
+            && self
+                .requirement
+                .requirement_fulfilled(entity, &data.crafting_data)
+    }
+
+    //Anything that drops fitting items when it dies, like a tree dropping logs, has to be cut down first
+    fn is_source(&self, entity: Entity, data: &GoalData) -> bool {
+        data.health.get(entity).is_some()
+            && data.death.get(entity).map_or(false, |death| {
+                death.contained_entities.iter().any(|contained| {
+                    self.requirement
+                        .requirement_fulfilled(*contained, &data.crafting_data)
+                })
+            })
+    }
+
+    fn find_target(
+        &self,
+        parent_entity: Entity,
+        pos: IPosition,
+        data: &GoalData,
+    ) -> Option<Entity> {
+        let per = data.perception.get(parent_entity)?;
+
+        let distance_to = |entity: &Entity| {
+            let diff = data.position.get(*entity).unwrap().pos - pos;
+            diff.x * diff.x + diff.y * diff.y
+        };
+
+        let visible = || {
+            per.all
+                .iter()
+                .copied()
+                .filter(|entity| data.position.get(*entity).is_some())
+        };
+
+        //Prefer picking things up over chopping them down
+        visible()
+            .filter(|entity| self.is_item(*entity, data))
+            .min_by_key(distance_to)
+            .or_else(|| {
+                visible()
+                    .filter(|entity| self.is_source(*entity, data))
+                    .min_by_key(distance_to)
+            })
+    }
+
+    fn travel_to(
+        &mut self,
+        parent_entity: Entity,
+        target_pos: IPosition,
+        data: &mut GoalData,
+    ) -> AIGoalResult {
+        if self
+            .travel_to_position_goal
+            .as_ref()
+            .map_or(true, |travel| travel.target_pos != target_pos)
+        {
+            self.travel_to_position_goal = Some(TravelToPositionGoal {
+                target_pos,
+                travel_path: None,
+            });
+        }
+
+        //Travel only succeeds once we're already standing where we need to be, which is handled before getting here
+        self.travel_to_position_goal
+            .as_mut()
+            .unwrap()
+            .resolve(parent_entity, data)?;
+
+        self.travel_to_position_goal = None;
+        Self::failure()
+    }
+}
+
+impl AIGoalTrait for GatherItemGoal {
+    fn get_textual_representation(&self, data: &RenderData) -> String {
+        if let Some(name) = self.target.and_then(|target| data.name.get(target)) {
+            format!("Gather from {}", name.name)
+        } else {
+            String::from("Look for materials")
+        }
+    }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.travel_to_position_goal.as_ref()?.get_path()
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        if let Some(inv) = data.inventory.get(parent_entity) {
+            let carried = inv
+                .items
+                .iter()
+                .flatten()
+                .filter(|item| {
+                    self.requirement
+                        .requirement_fulfilled(**item, &data.crafting_data)
+                })
+                .count();
+
+            if carried >= self.count {
+                return Self::success();
+            }
+
+            if !inv.any_slot_free() {
+                println!("Entity has no room left for gathered materials");
+                return Self::failure();
+            }
+        } else {
+            println!("Entity attempting to gather materials without an inventory");
+            return Self::failure();
+        }
+
+        let pos = data.position.get(parent_entity).unwrap().pos;
+
+        //Forget targets that have been picked up or cut down since we chose them
+        if let Some(target) = self.target {
+            if data.position.get(target).is_none()
+                || !(self.is_item(target, data) || self.is_source(target, data))
+            {
+                self.target = None;
+            }
+        }
+
+        let target = if let Some(target) = self
+            .target
+            .or_else(|| self.find_target(parent_entity, pos, data))
+        {
+            *self.target.insert(target)
+        } else {
+            println!("Entity cannot see anything to gather");
+            return Self::failure();
+        };
+
+        let target_pos = data.position.get(target).unwrap().pos;
+
+        if self.is_item(target, data) {
+            if pos == target_pos {
+                StowItemGoal { item: target }.resolve(parent_entity, data)
+            } else {
+                self.travel_to(parent_entity, target_pos, data)
+            }
+        } else if pos.is_adjacent(target_pos) {
+            AttackInDirectionGoal::new(Direction::from_positions(target_pos, pos))
+                .resolve(parent_entity, data)
+        } else {
+            let size = data
+                .species
+                .get(parent_entity)
+                .map(|species| species.species.get_size())
+                .unwrap_or_default();
+
+            if let Some(standing_pos) = data.tile_world.get_standing_position(
+                target_pos,
+                pos,
+                size,
+                &data.collider,
+                &data.movement_cost,
+            ) {
+                self.travel_to(parent_entity, standing_pos, data)
+            } else {
+                println!("Entity cannot find anywhere to stand to gather from its target");
+                self.target = None;
+                Self::failure()
+            }
+        }
+    }
+}
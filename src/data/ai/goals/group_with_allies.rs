@@ -1,4 +1,3 @@
-use rand::prelude::*;
 use specs::prelude::*;
 
 use crate::prelude::*;
@@ -21,32 +20,35 @@ impl AIGoalTrait for GroupWithAlliesGoal {
         
         if let Some(this_pos) = data.position.get(parent_entity) {
             if let Some(per) = data.perception.get(parent_entity) {
-                if dbg!(per.allies.len()) < 5 {//TODO: find a comfortable amount of allies
-                    let closest_ally = per.allies.choose(&mut thread_rng());
-                    // per.allies.iter().filter(|ally| data.position.get(**ally).is_some()).min_by_key(|a| {
-                    //     let a_pos = data.position.get(**a).unwrap();
-                    //     let pos_delta = a_pos.pos - this_pos.pos;
-                    //     pos_delta.x.abs() + pos_delta.y.abs()
-                    // });
+                if per.allies.len() < 5 {
+                    //TODO: find a comfortable amount of allies
+                    if per.allies.is_empty() {
+                        println!("Entity attempting to group with allies has no visible allies to group with!");
+                        return Self::failure();
+                    }
+
+                    let herd_field = data.species.get(parent_entity).and_then(|species| {
+                        data.flow_fields.get(FlowFieldTarget::HerdCentre {
+                            species: species.species,
+                        })
+                    });
 
-                    if let Some(ally) = closest_ally {
-                        if let Some(ally_pos) = data.position.get(*ally) {
-                            if this_pos.pos.chebyshev_distance(&ally_pos.pos) > 2 {//TODO: determine a comfortable distance to ally
-                                let move_in_direction = self.move_in_direction_goal.insert(MoveInDirectionGoal {
-                                    direction: Direction::from_positions(ally_pos.pos, this_pos.pos),
-                                    attempted: false,
-                                });
-                                move_in_direction.resolve(parent_entity, data)
-                            } else {
-                                println!("Entity is already close enough to allies");
-                                Self::success()
-                            }
+                    if let Some(herd_field) = herd_field {
+                        //TODO: determine a comfortable distance to the herd centre
+                        let comfortable = herd_field.get(this_pos.pos).map_or(false, |distance| distance <= 6);
+
+                        if let (false, Some(next_pos)) = (comfortable, herd_field.downhill(this_pos.pos)) {
+                            let move_in_direction = self.move_in_direction_goal.insert(MoveInDirectionGoal {
+                                direction: Direction::from_positions(next_pos, this_pos.pos),
+                                attempted: false,
+                            });
+                            move_in_direction.resolve(parent_entity, data)
                         } else {
-                            println!("Entity attempting to group an ally that does not have a position!");
-                            Self::failure()
+                            println!("Entity is already close enough to allies");
+                            Self::success()
                         }
                     } else {
-                        println!("Entity attempting to group with allies has no visible allies to group with!");
+                        println!("Entity attempting to group with allies has no herd to follow!");
                         Self::failure()
                     }
                 } else {
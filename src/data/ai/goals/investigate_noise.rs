@@ -0,0 +1,49 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct InvestigateNoiseGoal {
+    //Child goals and data here
+    pub noise_pos: IPosition,
+    pub travel_to_position_goal: Option<TravelToPositionGoal>,
+}
+
+impl AIGoalTrait for InvestigateNoiseGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        format!("Investigate noise at {:?}", self.noise_pos)
+    }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.travel_to_position_goal.as_ref()?.get_path()
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        if let Some(this_pos) = data.position.get(parent_entity) {
+            if this_pos.pos.is_adjacent_or_same(self.noise_pos) {
+                println!("Entity found the source of the noise");
+                return Self::success();
+            }
+
+            //Stop looking once whatever made the noise comes into view
+            if let Some(per) = data.perception.get(parent_entity) {
+                if !per.threats.is_empty() {
+                    println!("Entity spotted something while investigating noise");
+                    return Self::success();
+                }
+            }
+
+            let noise_pos = self.noise_pos;
+
+            self.travel_to_position_goal
+                .get_or_insert_with(|| TravelToPositionGoal {
+                    target_pos: noise_pos,
+                    travel_path: None,
+                })
+                .resolve(parent_entity, data)
+        } else {
+            println!("Entity attempting to investigate noise without a position!");
+            Self::failure()
+        }
+    }
+}
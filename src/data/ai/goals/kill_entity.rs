@@ -14,6 +14,10 @@ impl AIGoalTrait for KillEntityGoal {
         format!("Kill {}", data.name.get(self.target).unwrap().name)
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.attack_entity_goal.as_ref()?.get_path()
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
         //If the entity has a health greater than 0
         //Create child goal "AttackEntity" with target
@@ -22,14 +26,24 @@ impl AIGoalTrait for KillEntityGoal {
             if target_hpc.value > 0 {
                 let target = self.target;
 
-                self.attack_entity_goal
+                let attacked = self
+                    .attack_entity_goal
                     .get_or_insert_with(|| AttackEntityGoal {
                         //Child goals and data here
                         target,
                         move_to_entity_goal: None,
                         attack_in_direction_goal: None,
                     })
-                    .resolve(parent_entity, data)
+                    .resolve(parent_entity, data)?;
+
+                //Keep attacking until the target is dead
+                self.attack_entity_goal = None;
+
+                if attacked {
+                    self.resolve(parent_entity, data)
+                } else {
+                    Self::failure()
+                }
             } else {
                 println!("Entity attempting to attack another entity that is already dead!");
                 Self::success()
@@ -32,10 +32,8 @@ impl AIGoalTrait for MoveInDirectionGoal {
                         for entity in chunk_tile.entities.iter() {
                             //TODO: Check if hostile
                             if *entity != parent_entity && data.health.get(*entity).is_some() {
-                                return AttackInDirectionGoal {
-                                    direction: self.direction,
-                                }
-                                .resolve(parent_entity, data);
+                                return AttackInDirectionGoal::new(self.direction)
+                                    .resolve(parent_entity, data);
                             }
                         }
                     }
@@ -14,6 +14,10 @@ impl AIGoalTrait for MoveToEntityGoal {
         format!("Move to {}", data.name.get(self.target).unwrap().name)
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.travel_to_position_goal.as_ref()?.get_path()
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
         //TODO: change to move to closest adjacent tile to entity
         let target_pos = data.position.get(self.target).unwrap().pos;
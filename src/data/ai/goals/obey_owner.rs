@@ -0,0 +1,117 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+//How far a following pet lets its owner get before catching up
+const FOLLOW_DISTANCE: i32 = 2;
+
+#[derive(Debug, Clone)]
+pub struct ObeyOwnerGoal {
+    //Child goals and data here
+    pub travel_to_position_goal: Option<TravelToPositionGoal>,
+}
+
+impl AIGoalTrait for ObeyOwnerGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        String::from("Obey owner")
+    }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.travel_to_position_goal.as_ref()?.get_path()
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        let tame = if let Some(tame) = data.tame.get(parent_entity).filter(|tame| tame.tamed) {
+            tame
+        } else {
+            println!("Entity has no owner to obey");
+            return Self::failure();
+        };
+
+        let pos = data.position.get(parent_entity).unwrap().pos;
+
+        let target_pos = match tame.order {
+            PetOrder::Follow => {
+                let owner = tame.find_owner(&data.entities, &data.id);
+
+                let owner_pos =
+                    if let Some(owner_pos) = owner.and_then(|owner| data.position.get(owner)) {
+                        owner_pos.pos
+                    } else {
+                        println!("Entity cannot find its owner to follow");
+                        return Self::failure();
+                    };
+
+                let diff = owner_pos - pos;
+
+                if diff.x.abs() <= FOLLOW_DISTANCE && diff.y.abs() <= FOLLOW_DISTANCE {
+                    return Self::success();
+                }
+
+                //The player's pets share the field leading to them instead of each planning a route
+                if let Some(step) = owner
+                    .filter(|owner| data.input.get(*owner).is_some())
+                    .and_then(|_| data.flow_fields.get(FlowFieldTarget::Player))
+                    .and_then(|field| field.downhill(pos))
+                {
+                    self.travel_to_position_goal = None;
+
+                    return MoveInDirectionGoal {
+                        direction: Direction::from_positions(step, pos),
+                        attempted: false,
+                    }
+                    .resolve(parent_entity, data);
+                }
+
+                let size = data
+                    .species
+                    .get(parent_entity)
+                    .map(|species| species.species.get_size())
+                    .unwrap_or_default();
+
+                if let Some(standing_pos) = data.tile_world.get_standing_position(
+                    owner_pos,
+                    pos,
+                    size,
+                    &data.collider,
+                    &data.movement_cost,
+                ) {
+                    standing_pos
+                } else {
+                    println!("Entity cannot get close to its owner");
+                    return Self::failure();
+                }
+            }
+            PetOrder::Stay => return Self::success(),
+            PetOrder::GoTo { pos: destination } => {
+                if pos == destination {
+                    return Self::success();
+                }
+
+                destination
+            }
+        };
+
+        if self
+            .travel_to_position_goal
+            .as_ref()
+            .map_or(true, |travel| travel.target_pos != target_pos)
+        {
+            self.travel_to_position_goal = Some(TravelToPositionGoal {
+                target_pos,
+                travel_path: None,
+            });
+        }
+
+        if self
+            .travel_to_position_goal
+            .as_mut()
+            .unwrap()
+            .resolve(parent_entity, data)?
+        {
+            Self::success()
+        } else {
+            Self::failure()
+        }
+    }
+}
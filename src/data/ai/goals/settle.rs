@@ -0,0 +1,109 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct SettleGoal {
+    //Child goals and data here
+    pub craft_goal: Option<CraftGoal>,
+    pub build_shelter_goal: Option<BuildShelterGoal>,
+}
+
+impl SettleGoal {
+    pub fn new() -> Self {
+        Self {
+            craft_goal: None,
+            build_shelter_goal: None,
+        }
+    }
+}
+
+impl AIGoalTrait for SettleGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        String::from("Settle down")
+    }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        if let Some(craft_goal) = &self.craft_goal {
+            craft_goal.get_path()
+        } else {
+            self.build_shelter_goal.as_ref()?.get_path()
+        }
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        let pos = data.position.get(parent_entity).unwrap().pos;
+
+        //A tool comes first, nothing else can be done without one
+        let armed = data
+            .manipulator
+            .get(parent_entity)
+            .and_then(|man| man.held_item)
+            .map_or(false, |held| data.attack.get(held).is_some());
+
+        if !armed {
+            let carried_tool = data.inventory.get(parent_entity).and_then(|inv| {
+                inv.items
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .find(|item| data.attack.get(*item).is_some())
+            });
+
+            if let Some(tool) = carried_tool {
+                return HoldItemGoal { item: tool }.resolve(parent_entity, data);
+            }
+
+            //Freshly crafted tools are left on the ground at our feet
+            let dropped_tool = data.tile_world.get(pos).and_then(|chunk_tile| {
+                chunk_tile
+                    .entities
+                    .iter()
+                    .copied()
+                    .find(|entity| {
+                        data.item.get(*entity).is_some() && data.attack.get(*entity).is_some()
+                    })
+            });
+
+            if let Some(tool) = dropped_tool {
+                return StowItemGoal { item: tool }.resolve(parent_entity, data);
+            }
+
+            if self.craft_goal.is_none() {
+                if let Some(recipe) = data.crafting_data.recipes.get(SETTLER_TOOL_RECIPE) {
+                    self.craft_goal = Some(CraftGoal::new(recipe.clone()));
+                } else {
+                    println!("Entity knows of no way to make itself a tool");
+                    return Self::failure();
+                }
+            }
+
+            return if self
+                .craft_goal
+                .as_mut()
+                .unwrap()
+                .resolve(parent_entity, data)?
+            {
+                self.craft_goal = None;
+                self.resolve(parent_entity, data)
+            } else {
+                println!("Entity could not make itself a tool");
+                self.craft_goal = None;
+                Self::failure()
+            };
+        }
+
+        if let Some(build_shelter_goal) = &mut self.build_shelter_goal {
+            return build_shelter_goal.resolve(parent_entity, data);
+        }
+
+        if data.tile_world.is_sheltered(pos) {
+            println!("Entity is settled");
+            return Self::success();
+        }
+
+        self.build_shelter_goal
+            .insert(BuildShelterGoal::new())
+            .resolve(parent_entity, data)
+    }
+}
@@ -0,0 +1,65 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct SleepGoal {
+    //Child goals and data here
+    pub wake_condition: WakeCondition,
+    pub attempted: bool,
+}
+
+impl SleepGoal {
+    pub fn new(wake_condition: WakeCondition) -> Self {
+        Self {
+            wake_condition,
+            attempted: false,
+        }
+    }
+}
+
+impl AIGoalTrait for SleepGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        format!("Sleep {}", self.wake_condition.get_name())
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        if self.attempted {
+            return Self::success();
+        }
+
+        if data.fatigue.get(parent_entity).is_none() {
+            println!("Entity attempting to sleep despite never getting tired!");
+            return Self::failure();
+        }
+
+        let is_player = data.input.get(parent_entity).is_some();
+
+        //No point lying down if we'd wake straight back up
+        let already_met = match self.wake_condition {
+            WakeCondition::Rested => data
+                .fatigue
+                .get(parent_entity)
+                .map_or(true, |ftg| ftg.fatigue == 0),
+            WakeCondition::Healed => data
+                .health
+                .get(parent_entity)
+                .map_or(true, |hpc| hpc.value >= hpc.max_value),
+            WakeCondition::Morning => data.clock.is_day(),
+        };
+
+        if already_met {
+            println!("Entity attempting to sleep with nothing to sleep off");
+            if is_player {
+                data.message_log.push("You can't get to sleep.");
+            }
+            return Self::failure();
+        }
+
+        self.attempted = true;
+
+        Self::action(AIAction::Sleep {
+            wake_condition: self.wake_condition,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct ThrowGoal {
+    //Child goals and data here
+    pub target: IPosition,
+    pub attempted: bool,
+}
+
+impl ThrowGoal {
+    pub fn new(target: IPosition) -> Self {
+        Self {
+            target,
+            attempted: false,
+        }
+    }
+}
+
+impl AIGoalTrait for ThrowGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        format!("Throw at {}", self.target)
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        if self.attempted {
+            return Self::success();
+        }
+
+        let item = if let Some(item) = data
+            .manipulator
+            .get(parent_entity)
+            .and_then(|man| man.held_item)
+        {
+            item
+        } else {
+            println!("Entity attempting to throw without holding anything");
+            if data.input.get(parent_entity).is_some() {
+                data.message_log
+                    .push("You aren't holding anything to throw.");
+            }
+            return Self::failure();
+        };
+
+        if data
+            .position
+            .get(parent_entity)
+            .map_or(true, |pos| pos.pos == self.target)
+        {
+            println!("Entity attempting to throw at its own feet");
+            return Self::failure();
+        }
+
+        self.attempted = true;
+
+        Self::action(AIAction::Throw {
+            item,
+            target: self.target,
+        })
+    }
+}
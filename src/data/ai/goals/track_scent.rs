@@ -0,0 +1,69 @@
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct TrackScentGoal {
+    //Child goals and data here
+    pub prey: Species,
+    pub kill_entity_goal: Option<KillEntityGoal>,
+}
+
+impl AIGoalTrait for TrackScentGoal {
+    fn get_textual_representation(&self, _data: &RenderData) -> String {
+        format!("Track the scent of a {}", self.prey.get_name())
+    }
+
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.kill_entity_goal.as_ref()?.get_path()
+    }
+
+    fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
+        if let Some(kill_entity_goal) = &mut self.kill_entity_goal {
+            return kill_entity_goal.resolve(parent_entity, data);
+        }
+
+        let pos = data.position.get(parent_entity).unwrap().pos;
+
+        if let Some(per) = data.perception.get(parent_entity) {
+            //The trail has led to the prey itself
+            let prey = self.prey;
+            let visible_prey = per.all.iter().copied().find(|entity| {
+                data.species
+                    .get(*entity)
+                    .map_or(false, |species| species.species == prey)
+            });
+
+            if let Some(target) = visible_prey {
+                return self
+                    .kill_entity_goal
+                    .insert(KillEntityGoal {
+                        target,
+                        attack_entity_goal: None,
+                    })
+                    .resolve(parent_entity, data);
+            }
+        }
+
+        let here = data
+            .tile_world
+            .get(pos)
+            .map_or(0, |chunk_tile| chunk_tile.get_scent(self.prey));
+
+        //Fresher scent is stronger, so climbing the gradient follows the trail towards the prey
+        match data
+            .tile_world
+            .get_strongest_scent(pos, |species| species == self.prey)
+        {
+            Some((scent_pos, scent)) if scent.strength > here => MoveInDirectionGoal {
+                direction: Direction::from_positions(scent_pos, pos),
+                attempted: false,
+            }
+            .resolve(parent_entity, data),
+            _ => {
+                println!("Entity lost the scent of its prey");
+                Self::failure()
+            }
+        }
+    }
+}
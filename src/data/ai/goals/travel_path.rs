@@ -26,6 +26,10 @@ impl AIGoalTrait for TravelPathGoal {
         }
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        Some(&self.path)
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
         let pos = data.position.get(parent_entity).unwrap().pos;
 
@@ -13,6 +13,10 @@ impl AIGoalTrait for TravelToPositionGoal {
         format!("Travel to {:?}", self.target_pos)
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.travel_path.as_ref()?.get_path()
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
         let pos = data.position.get(parent_entity).unwrap().pos;
 
@@ -23,14 +27,40 @@ impl AIGoalTrait for TravelToPositionGoal {
 
         if let Some(travel_path) = &mut self.travel_path {
             if travel_path.resolve(parent_entity, data)? {
-                println!("Entity succeeded in traveling path");
-                return Self::success();
+                let pos = data.position.get(parent_entity).unwrap().pos;
+
+                if pos == self.target_pos {
+                    println!("Entity succeeded in traveling path");
+                    return Self::success();
+                }
+
+                //Long paths only lead to the edge of the loaded chunks, so plan the next leg
+                println!("Entity finished a leg of its route");
             }
         }
 
-        // If the previously computed path failed or we don't have one, compute a new one
+        // If the previously computed path failed, ended early or we don't have one, compute a new one
         if let Some(pathing) = data.pathing.get_mut(parent_entity) {
-            if let Some(path) = pathing.pathfind(&data.tile_world, pos, self.target_pos, &data.collider) {
+            let size = data
+                .species
+                .get(parent_entity)
+                .map(|species| species.species.get_size())
+                .unwrap_or_default();
+
+            if let Some(path) = pathing.pathfind(
+                &data.tile_world,
+                pos,
+                self.target_pos,
+                size,
+                &data.collider,
+                &data.movement_cost,
+            ) {
+                //The route leads on into unloaded chunks, with no getting closer until they load
+                if path.iter().all(|step| *step == pos) {
+                    println!("Entity cannot get any closer to {:?}", self.target_pos);
+                    return Self::failure();
+                }
+
                 println!("Entity recomputing path");
                 let travel_path = self.travel_path.insert(TravelPathGoal::new(path));
                 travel_path.resolve(parent_entity, data)
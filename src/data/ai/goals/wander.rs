@@ -14,6 +14,10 @@ impl AIGoalTrait for WanderGoal {
         String::from("Wander")
     }
 
+    fn get_path(&self) -> Option<&[IPosition]> {
+        self.travel_to_position_goal.as_ref()?.get_path()
+    }
+
     fn resolve(&mut self, parent_entity: Entity, data: &mut GoalData) -> AIGoalResult {
         if let Some(this_pos) = data.position.get(parent_entity) {
             if let Some(travel_goal) = &mut self.travel_to_position_goal {
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PetOrder {
+    Follow,
+    Stay,
+    GoTo { pos: IPosition },
+}
+
+impl PetOrder {
+    pub fn get_name(&self) -> String {
+        match self {
+            Self::Follow => String::from("follow me"),
+            Self::Stay => String::from("stay"),
+            Self::GoTo { pos } => format!("go to {}", pos),
+        }
+    }
+}
@@ -31,16 +31,25 @@ impl Shadowcast {
     }
 
     pub fn shadowcast<T>(&mut self, callbacks: &mut T)
+    where
+        T: ShadowcastCallbacks,
+    {
+        self.shadowcast_within(self.radius, callbacks);
+    }
+
+    //Only sees as far as sight, which can be less than the full radius
+    pub fn shadowcast_within<T>(&mut self, sight: u32, callbacks: &mut T)
     where
         T: ShadowcastCallbacks,
     {
         let radius = self.radius;
         let sradius = usize::try_from(radius).unwrap();
+        let sight = sight.min(radius);
 
         self.fov.fill(false);
         self.fov[[sradius, sradius]] = true;
 
-        if radius == 0 {
+        if sight == 0 {
             return;
         }
 
@@ -48,7 +57,7 @@ impl Shadowcast {
         top_left.invert_axis(Axis(0));
         top_left.invert_axis(Axis(1));
         shadowcast_quadrant(
-            radius,
+            sight,
             top_left,
             &mut self.frontier,
             &mut WrapCallbacks(callbacks, |pos: UPosition| {
@@ -59,7 +68,7 @@ impl Shadowcast {
         let mut top_right = self.fov.slice_mut(s![sradius.., ..=sradius]);
         top_right.invert_axis(Axis(1));
         shadowcast_quadrant(
-            radius,
+            sight,
             top_right,
             &mut self.frontier,
             &mut WrapCallbacks(callbacks, |pos: UPosition| {
@@ -70,7 +79,7 @@ impl Shadowcast {
         let mut bottom_left = self.fov.slice_mut(s![..=sradius, sradius..]);
         bottom_left.invert_axis(Axis(0));
         shadowcast_quadrant(
-            radius,
+            sight,
             bottom_left,
             &mut self.frontier,
             &mut WrapCallbacks(callbacks, |pos: UPosition| {
@@ -80,7 +89,7 @@ impl Shadowcast {
 
         let bottom_right = self.fov.slice_mut(s![sradius.., sradius..]);
         shadowcast_quadrant(
-            radius,
+            sight,
             bottom_right,
             &mut self.frontier,
             &mut WrapCallbacks(callbacks, |pos: UPosition| {
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WakeCondition {
+    Rested,
+    Healed,
+    Morning,
+}
+
+impl WakeCondition {
+    pub fn get_name(&self) -> String {
+        String::from(match self {
+            Self::Rested => "until rested",
+            Self::Healed => "until healed",
+            Self::Morning => "until morning",
+        })
+    }
+}
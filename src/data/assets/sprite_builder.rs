@@ -16,6 +16,7 @@ pub enum SpriteBuilder {
     Ground {
         fertility: u8,
     },
+    Water,
     Wall {
         material: Material,
     },
@@ -28,6 +29,9 @@ pub enum SpriteBuilder {
     Humanoid {
         species: Species,
     },
+    Child {
+        species: Species,
+    },
     Grass,
     Tree,
     //TODO: Make single tile sprites into something more generic
@@ -36,12 +40,21 @@ pub enum SpriteBuilder {
     Stone,
     BerryBush,
     Berry,
+    Meat,
+    Hide,
+    Bone,
+    Corpse {
+        species: Species,
+    },
+    Waterskin,
     CampFire,
     Spear,
     Pick,
     Axe,
     Knife,
+    Shield,
     Deer,
+    Fawn,
 }
 
 impl SpriteBuilder {
@@ -78,6 +91,11 @@ impl SpriteBuilder {
                     [SymbolBuilder::GroundEdge{}.get_symbol(seed)]
                 ],
             },
+            Self::Water => Sprite {
+                origin_x: 0,
+                origin_y: 0,
+                contents: array![[SymbolBuilder::Water.get_symbol(seed)]],
+            },
             Self::Wall { material } => {
                 let mat_color = material.get_color();
                 Sprite {
@@ -207,6 +225,20 @@ impl SpriteBuilder {
                     }]
                 ],
             },
+            //Only comes up to an adult's waist
+            Self::Child { species } => Sprite {
+                origin_x: 0,
+                origin_y: 0,
+                contents: array![[Symbol {
+                    draw_chars: vec![GgBunnyChar {
+                        index: 0x00C,
+                        foreground: species.get_color(),
+                        background: None,
+                        rotation: CharRotation::None,
+                        mirror: CharMirror::None,
+                    }]
+                }]],
+            },
             Self::Grass => Sprite {
                 origin_x: 0,
                 origin_y: 0,
@@ -467,6 +499,31 @@ impl SpriteBuilder {
                 origin_y: 0,
                 contents: array![[SymbolBuilder::Berry.get_symbol(seed)]],
             },
+            Self::Meat => Sprite {
+                origin_x: 0,
+                origin_y: 0,
+                contents: array![[SymbolBuilder::Meat.get_symbol(seed)]],
+            },
+            Self::Hide => Sprite {
+                origin_x: 0,
+                origin_y: 0,
+                contents: array![[SymbolBuilder::Hide.get_symbol(seed)]],
+            },
+            Self::Bone => Sprite {
+                origin_x: 0,
+                origin_y: 0,
+                contents: array![[SymbolBuilder::Bone.get_symbol(seed)]],
+            },
+            Self::Corpse { species } => Sprite {
+                origin_x: 0,
+                origin_y: 0,
+                contents: array![[SymbolBuilder::Corpse { species: *species }.get_symbol(seed)]],
+            },
+            Self::Waterskin => Sprite {
+                origin_x: 0,
+                origin_y: 0,
+                contents: array![[SymbolBuilder::Waterskin.get_symbol(seed)]],
+            },
             Self::CampFire => Sprite {
                 origin_x: 0,
                 origin_y: 0,
@@ -492,11 +549,21 @@ impl SpriteBuilder {
                 origin_y: 0,
                 contents: array![[SymbolBuilder::Knife.get_symbol(seed)]],
             },
+            Self::Shield => Sprite {
+                origin_x: 0,
+                origin_y: 0,
+                contents: array![[SymbolBuilder::Shield.get_symbol(seed)]],
+            },
             Self::Deer => Sprite {
                 origin_x: 0,
                 origin_y: 0,
                 contents: array![[SymbolBuilder::Deer.get_symbol(seed)]],
             },
+            Self::Fawn => Sprite {
+                origin_x: 0,
+                origin_y: 0,
+                contents: array![[SymbolBuilder::Fawn.get_symbol(seed)]],
+            },
         }
     }
 }
@@ -13,6 +13,7 @@ pub enum SymbolBuilder {
     Ground {
         fertility: u8,
     },
+    Water,
     Wall {
         material: Material,
     },
@@ -25,6 +26,9 @@ pub enum SymbolBuilder {
     Humanoid {
         species: Species,
     },
+    Child {
+        species: Species,
+    },
     Tree,
     Stick,
     Log,
@@ -32,12 +36,21 @@ pub enum SymbolBuilder {
     Grass,
     BerryBush,
     Berry,
+    Meat,
+    Hide,
+    Bone,
+    Corpse {
+        species: Species,
+    },
+    Waterskin,
     CampFire,
     Spear,
     Pick,
     Axe,
     Knife,
+    Shield,
     Deer,
+    Fawn,
 }
 
 impl SymbolBuilder {
@@ -106,6 +119,19 @@ impl SymbolBuilder {
                     }],
                 }
             }
+            Self::Water => {
+                let (_, mirror) = get_random_transforms_from_seed(seed);
+
+                Symbol {
+                    draw_chars: vec![GgBunnyChar {
+                        index: 0x07E,
+                        foreground: Color::new(0.4, 0.5, 0.9, 1.0),
+                        background: Some(Color::new(0.1, 0.15, 0.4, 1.0)),
+                        rotation: CharRotation::None,
+                        mirror,
+                    }],
+                }
+            }
             Self::Wall { material, .. } => Symbol {
                 draw_chars: vec![GgBunnyChar {
                     index: 0x321,
@@ -147,6 +173,15 @@ impl SymbolBuilder {
                     mirror: CharMirror::None,
                 }],
             },
+            Self::Child { species } => Symbol {
+                draw_chars: vec![GgBunnyChar {
+                    index: species.get_symbol(),
+                    foreground: Color::new(1.0, 0.5, 0.5, 1.0),
+                    background: None,
+                    rotation: CharRotation::None,
+                    mirror: CharMirror::None,
+                }],
+            },
             Self::Tree => Symbol {
                 draw_chars: vec![GgBunnyChar {
                     index: 0x005,
@@ -311,6 +346,52 @@ impl SymbolBuilder {
                     mirror: CharMirror::None,
                 }],
             },
+            Self::Meat => Symbol {
+                draw_chars: vec![GgBunnyChar {
+                    index: 0x025,
+                    foreground: Color::new(0.75, 0.25, 0.25, 1.0),
+                    background: None,
+                    rotation: CharRotation::None,
+                    mirror: CharMirror::None,
+                }],
+            },
+            Self::Hide => Symbol {
+                draw_chars: vec![GgBunnyChar {
+                    index: MaterialShape::Pelt.get_item_char_index(),
+                    foreground: Material::Hide.get_color(),
+                    background: None,
+                    rotation: CharRotation::None,
+                    mirror: CharMirror::None,
+                }],
+            },
+            Self::Bone => Symbol {
+                draw_chars: vec![GgBunnyChar {
+                    index: MaterialShape::Shard.get_item_char_index(),
+                    foreground: Material::Bone.get_color(),
+                    background: None,
+                    rotation: CharRotation::None,
+                    mirror: CharMirror::None,
+                }],
+            },
+            //Whatever it was, lying on its back
+            Self::Corpse { species } => Symbol {
+                draw_chars: vec![GgBunnyChar {
+                    index: species.get_symbol(),
+                    foreground: Color::new(0.5, 0.2, 0.2, 1.0),
+                    background: None,
+                    rotation: CharRotation::Rotation180,
+                    mirror: CharMirror::None,
+                }],
+            },
+            Self::Waterskin => Symbol {
+                draw_chars: vec![GgBunnyChar {
+                    index: 0x021,
+                    foreground: Color::new(0.6, 0.45, 0.3, 1.0),
+                    background: None,
+                    rotation: CharRotation::None,
+                    mirror: CharMirror::None,
+                }],
+            },
             Self::CampFire => Symbol {
                 draw_chars: vec![
                     GgBunnyChar {
@@ -402,6 +483,15 @@ impl SymbolBuilder {
                     },
                 ],
             },
+            Self::Shield => Symbol {
+                draw_chars: vec![GgBunnyChar {
+                    index: 0x05B,
+                    foreground: Color::new(0.5, 0.35, 0.1, 1.0),
+                    background: None,
+                    rotation: CharRotation::None,
+                    mirror: CharMirror::None,
+                }],
+            },
             Self::Deer => Symbol {
                 draw_chars: vec![
                     //Head
@@ -438,6 +528,27 @@ impl SymbolBuilder {
                     },
                 ],
             },
+            //Smaller and paler than its parents, and without the tail
+            Self::Fawn => Symbol {
+                draw_chars: vec![
+                    //Head
+                    GgBunnyChar {
+                        index: 0x2C9,
+                        foreground: Color::new(0.85, 0.65, 0.3, 1.0),
+                        background: None,
+                        rotation: CharRotation::Rotation180,
+                        mirror: CharMirror::None,
+                    },
+                    //Legs
+                    GgBunnyChar {
+                        index: 0x1E0,
+                        foreground: Color::new(0.85, 0.65, 0.3, 1.0),
+                        background: None,
+                        rotation: CharRotation::None,
+                        mirror: CharMirror::MirrorX,
+                    },
+                ],
+            },
         }
     }
 }
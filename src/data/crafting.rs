@@ -1,5 +1,9 @@
+pub mod ingredient_role;
 pub mod material;
 pub mod material_shape;
+pub mod quality;
 pub mod recipe;
 pub mod recipe_ingredient;
+pub mod recipe_output;
 pub mod recipe_requirement;
+pub mod workstation;
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+/// Whether an ingredient goes into what's made, or only has to be held while making it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum IngredientRole {
+    Consumed,
+    //Has to be in the crafter's hand, and stays there afterwards, ex: a rock to knap with
+    Tool,
+}
+
+impl Default for IngredientRole {
+    fn default() -> Self {
+        Self::Consumed
+    }
+}
@@ -2,11 +2,15 @@ use ggez::graphics::Color;
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
+use crate::prelude::*;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
 pub enum Material {
     Stone,
     Wood,
     Dirt,
+    Hide,
+    Bone,
 }
 
 impl Material {
@@ -15,6 +19,19 @@ impl Material {
             Self::Stone => String::from("rock"),
             Self::Wood => String::from("wood"),
             Self::Dirt => String::from("dirt"),
+            Self::Hide => String::from("hide"),
+            Self::Bone => String::from("bone"),
+        }
+    }
+
+    //For describing what something is made of, ex: a wooden stick
+    pub fn get_adjective(&self) -> String {
+        match self {
+            Self::Stone => String::from("stone"),
+            Self::Wood => String::from("wooden"),
+            Self::Dirt => String::from("earthen"),
+            Self::Hide => String::from("hide"),
+            Self::Bone => String::from("bone"),
         }
     }
 
@@ -23,6 +40,83 @@ impl Material {
             Self::Stone => Color::new(0.4, 0.4, 0.4, 1.0),
             Self::Wood => Color::new(0.2, 0.15, 0.0, 1.0),
             Self::Dirt => Color::new(0.3, 0.3, 0.1, 1.0),
+            Self::Hide => Color::new(0.6, 0.45, 0.3, 1.0),
+            Self::Bone => Color::new(0.9, 0.88, 0.75, 1.0),
+        }
+    }
+
+    //Harder materials cut through softer ones more easily
+    pub fn get_hardness(&self) -> u32 {
+        match self {
+            Self::Stone => 3,
+            Self::Wood | Self::Bone => 2,
+            Self::Hide => 1,
+            Self::Dirt => 0,
+        }
+    }
+
+    //How many uses each unit of this material is good for in a tool
+    pub fn get_durability(&self) -> u32 {
+        match self {
+            Self::Stone => 8,
+            Self::Bone => 6,
+            Self::Wood => 4,
+            Self::Hide => 3,
+            Self::Dirt => 1,
+        }
+    }
+
+    //Anything that puts up no resistance to fire will burn
+    pub fn is_flammable(&self) -> bool {
+        self.get_resistance(DamageType::Fire) == 0
+    }
+
+    pub fn holds_edge(&self) -> bool {
+        match self {
+            Self::Stone => true,
+            Self::Wood | Self::Dirt | Self::Hide | Self::Bone => false,
+        }
+    }
+
+    pub fn holds_point(&self) -> bool {
+        match self {
+            Self::Stone | Self::Wood | Self::Bone => true,
+            Self::Dirt | Self::Hide => false,
+        }
+    }
+
+    //How much of each kind of damage something made of this shrugs off per blow
+    pub fn get_resistance(&self, damage_type: DamageType) -> u32 {
+        match (self, damage_type) {
+            (Self::Stone, DamageType::Pierce) => 3,
+            (Self::Stone, DamageType::Slash) => 3,
+            (Self::Stone, DamageType::Blunt) => 1,
+            (Self::Stone, DamageType::Fire) => 4,
+            (Self::Stone, DamageType::Cold) => 2,
+
+            (Self::Wood, DamageType::Pierce) => 2,
+            (Self::Wood, DamageType::Slash) => 1,
+            (Self::Wood, DamageType::Blunt) => 2,
+            (Self::Wood, DamageType::Fire) => 0,
+            (Self::Wood, DamageType::Cold) => 1,
+
+            (Self::Dirt, DamageType::Pierce) => 1,
+            (Self::Dirt, DamageType::Slash) => 1,
+            (Self::Dirt, DamageType::Blunt) => 2,
+            (Self::Dirt, DamageType::Fire) => 3,
+            (Self::Dirt, DamageType::Cold) => 1,
+
+            (Self::Hide, DamageType::Pierce) => 1,
+            (Self::Hide, DamageType::Slash) => 2,
+            (Self::Hide, DamageType::Blunt) => 0,
+            (Self::Hide, DamageType::Fire) => 0,
+            (Self::Hide, DamageType::Cold) => 3,
+
+            (Self::Bone, DamageType::Pierce) => 2,
+            (Self::Bone, DamageType::Slash) => 2,
+            (Self::Bone, DamageType::Blunt) => 1,
+            (Self::Bone, DamageType::Fire) => 2,
+            (Self::Bone, DamageType::Cold) => 1,
         }
     }
 }
@@ -7,6 +7,8 @@ pub enum MaterialShape {
     Brick,
     Rock,
     Stick,
+    Pelt,
+    Shard,
 }
 
 impl MaterialShape {
@@ -17,6 +19,21 @@ impl MaterialShape {
             MaterialShape::Brick => String::from("brick"),
             MaterialShape::Rock => String::from("rock"),
             MaterialShape::Stick => String::from("stick"),
+            MaterialShape::Pelt => String::from("pelt"),
+            MaterialShape::Shard => String::from("shard"),
+        }
+    }
+
+    pub fn get_plural_name(&self) -> String {
+        format!("{}s", self.get_name())
+    }
+
+    //Splinters make for a keener point or edge than lumps do, and flat or unwieldy shapes worse
+    pub fn get_sharpness(&self) -> i32 {
+        match self {
+            MaterialShape::Shard => 1,
+            MaterialShape::Rock | MaterialShape::Stick | MaterialShape::Plank => 0,
+            MaterialShape::Brick | MaterialShape::Log | MaterialShape::Pelt => -1,
         }
     }
 
@@ -27,6 +44,8 @@ impl MaterialShape {
             MaterialShape::Brick => 0x335,
             MaterialShape::Rock => 0x33E,
             MaterialShape::Stick => 0x2D7,
+            MaterialShape::Pelt => 0x0B0,
+            MaterialShape::Shard => 0x33E,
         }
     }
 
@@ -37,6 +56,8 @@ impl MaterialShape {
             MaterialShape::Brick => 0x368,
             MaterialShape::Rock => 0x2A7,
             MaterialShape::Stick => 0x222,
+            MaterialShape::Pelt => 0x07E,
+            MaterialShape::Shard => 0x02F,
         }
     }
 }
@@ -0,0 +1,78 @@
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+
+//Crafting rolls a d20, plus the crafter's skill twice over and their knack for it
+const CRUDE_ROLL: i32 = 6;
+const FINE_ROLL: i32 = 16;
+const MASTERWORK_ROLL: i32 = 22;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Quality {
+    Crude,
+    Standard,
+    Fine,
+    Masterwork,
+}
+
+impl Quality {
+    pub fn roll(crafting_level: u32, attribute_modifier: i32) -> Self {
+        Self::from_roll(
+            thread_rng().gen_range(1..=20) + crafting_level as i32 * 2 + attribute_modifier,
+        )
+    }
+
+    pub fn from_roll(total: i32) -> Self {
+        if total >= MASTERWORK_ROLL {
+            Self::Masterwork
+        } else if total >= FINE_ROLL {
+            Self::Fine
+        } else if total < CRUDE_ROLL {
+            Self::Crude
+        } else {
+            Self::Standard
+        }
+    }
+
+    //Goes in front of an item's name, and ordinary work isn't worth mentioning
+    pub fn get_prefix(&self) -> &str {
+        match self {
+            Self::Crude => "crude ",
+            Self::Standard => "",
+            Self::Fine => "fine ",
+            Self::Masterwork => "masterwork ",
+        }
+    }
+
+    //As a percentage of what the materials would last on their own
+    pub fn get_durability_multiplier(&self) -> u32 {
+        match self {
+            Self::Crude => 50,
+            Self::Standard => 100,
+            Self::Fine => 150,
+            Self::Masterwork => 200,
+        }
+    }
+
+    //Added to the sides of a weapon's damage dice
+    pub fn get_damage_modifier(&self) -> i32 {
+        match self {
+            Self::Crude => -1,
+            Self::Standard => 0,
+            Self::Fine => 1,
+            Self::Masterwork => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_practice_shows_in_the_work() {
+        assert_eq!(Quality::from_roll(1), Quality::Crude);
+        assert_eq!(Quality::from_roll(10), Quality::Standard);
+        assert_eq!(Quality::from_roll(17), Quality::Fine);
+        assert_eq!(Quality::from_roll(25), Quality::Masterwork);
+    }
+}
@@ -1,156 +1,47 @@
+use serde::Deserialize;
 use specs::prelude::*;
-use strum::EnumIter;
 
 use crate::prelude::*;
 
-#[derive(Clone, Copy, Debug, EnumIter, PartialEq)]
-pub enum Recipe {
-    CampFire,
-    Spear,
-    Pick,
-    Axe,
-    Knife,
+/// A recipe as loaded from the recipes data file
+#[derive(Debug, Clone, Deserialize)]
+pub struct Recipe {
+    pub name: String,
+    //Everything that gets used up making the result, along with anything held while making it
+    pub ingredients: Vec<RecipeIngredient>,
+    #[serde(default)]
+    pub workstation: Option<Workstation>,
+    pub output: RecipeOutput,
 }
 
 impl Recipe {
-    pub fn get_resulting_object_name(&self) -> &'static str {
-        match self {
-            Self::CampFire => "camp fire",
-            Self::Spear => "spear",
-            Self::Pick => "pick",
-            Self::Axe => "axe",
-            Self::Knife => "knife",
-        }
+    //One slot per item that goes in, so a part wanting three rocks takes up three slots
+    pub fn get_slots(&self) -> Vec<&RecipeIngredient> {
+        self.ingredients
+            .iter()
+            .filter(|ingredient| ingredient.role == IngredientRole::Consumed)
+            .flat_map(|ingredient| std::iter::repeat(ingredient).take(ingredient.count))
+            .collect()
     }
 
-    pub fn get_ingredient_requirements(&self) -> &'static [RecipeIngredient] {
-        match self {
-            Self::CampFire => &[
-                RecipeIngredient {
-                    part_name: "fuel",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Wood,
-                        },
-                        b: &RecipeRequirement::Shape {
-                            shape: MaterialShape::Log,
-                        },
-                    },
-                },
-                RecipeIngredient {
-                    part_name: "ring",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Stone,
-                        },
-                        b: &RecipeRequirement::Or {
-                            a: &RecipeRequirement::Shape {
-                                shape: MaterialShape::Rock,
-                            },
-                            b: &RecipeRequirement::Shape {
-                                shape: MaterialShape::Brick,
-                            },
-                        },
-                    },
-                },
-            ],
-            Self::Spear => &[
-                RecipeIngredient {
-                    part_name: "head",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Stone,
-                        },
-                        b: &RecipeRequirement::Shape {
-                            shape: MaterialShape::Rock,
-                        },
-                    },
-                },
-                RecipeIngredient {
-                    part_name: "handle",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Wood,
-                        },
-                        b: &RecipeRequirement::Shape {
-                            shape: MaterialShape::Stick,
-                        },
-                    },
-                },
-            ],
-            Self::Pick => &[
-                RecipeIngredient {
-                    part_name: "head",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Stone,
-                        },
-                        b: &RecipeRequirement::Shape {
-                            shape: MaterialShape::Rock,
-                        },
-                    },
-                },
-                RecipeIngredient {
-                    part_name: "handle",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Wood,
-                        },
-                        b: &RecipeRequirement::Shape {
-                            shape: MaterialShape::Stick,
-                        },
-                    },
-                },
-            ],
-            Self::Axe => &[
-                RecipeIngredient {
-                    part_name: "head",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Stone,
-                        },
-                        b: &RecipeRequirement::Shape {
-                            shape: MaterialShape::Rock,
-                        },
-                    },
-                },
-                RecipeIngredient {
-                    part_name: "handle",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Wood,
-                        },
-                        b: &RecipeRequirement::Shape {
-                            shape: MaterialShape::Stick,
-                        },
-                    },
-                },
-            ],
-            Self::Knife => &[
-                RecipeIngredient {
-                    part_name: "blade",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Stone,
-                        },
-                        b: &RecipeRequirement::Shape {
-                            shape: MaterialShape::Rock,
-                        },
-                    },
-                },
-                RecipeIngredient {
-                    part_name: "handle",
-                    requirement: RecipeRequirement::And {
-                        a: &RecipeRequirement::Material {
-                            material: Material::Wood,
-                        },
-                        b: &RecipeRequirement::Shape {
-                            shape: MaterialShape::Stick,
-                        },
-                    },
-                },
-            ],
-        }
+    //There's only the one hand to hold a tool in
+    pub fn get_tool(&self) -> Option<&RecipeIngredient> {
+        self.ingredients
+            .iter()
+            .find(|ingredient| ingredient.role == IngredientRole::Tool)
+    }
+
+    //What's left to go into the recipe, with the hand kept free for the tool if it needs one
+    pub fn get_ingredient_candidates(
+        &self,
+        available: &[Entity],
+        held: Option<Entity>,
+    ) -> Vec<Entity> {
+        available
+            .iter()
+            .copied()
+            .filter(|item| self.get_tool().is_none() || Some(*item) != held)
+            .collect()
     }
 
     pub fn fulfillable_with_inventory_contents(
@@ -158,68 +49,243 @@ impl Recipe {
         inv: &InventoryComponent,
         crafting_data: &CraftingData,
     ) -> bool {
-        self.get_ingredient_requirements().iter().all(|ingredient| {
-            inv.items
-                .iter()
-                .filter_map(|slot| slot.as_ref())
-                .any(|item| {
-                    ingredient
-                        .requirement
-                        .requirement_fulfilled(*item, crafting_data)
-                })
+        let carried: Vec<_> = inv.items.iter().flatten().copied().collect();
+
+        self.get_missing_ingredient(&[], &carried, crafting_data)
+            .is_none()
+    }
+
+    //The first ingredient nothing left over can stand in for, once the chosen ones are spoken for
+    //Each item can only go into one part, so a single rock can't be both the head and the ring
+    pub fn get_missing_ingredient(
+        &self,
+        chosen: &[Entity],
+        available: &[Entity],
+        crafting_data: &CraftingData,
+    ) -> Option<&RecipeIngredient> {
+        let slots = self.get_slots();
+        let remaining = &slots[chosen.len().min(slots.len())..];
+        let filled = fill_slots(
+            remaining,
+            available,
+            &mut chosen.to_vec(),
+            &Self::fits(crafting_data),
+        );
+
+        remaining.get(filled).copied()
+    }
+
+    //A different item for each slot, or the first ingredient there's no way to fill
+    pub fn choose_ingredients(
+        &self,
+        available: &[Entity],
+        crafting_data: &CraftingData,
+    ) -> Result<Vec<Entity>, &RecipeIngredient> {
+        let slots = self.get_slots();
+        let mut chosen = Vec::new();
+        let filled = fill_slots(&slots, available, &mut chosen, &Self::fits(crafting_data));
+
+        match slots.get(filled) {
+            Some(missing) => Err(*missing),
+            None => Ok(chosen),
+        }
+    }
+
+    fn fits<'a>(
+        crafting_data: &'a CraftingData,
+    ) -> impl Fn(&RecipeIngredient, Entity) -> bool + 'a {
+        move |ingredient, item| {
+            ingredient
+                .requirement
+                .requirement_fulfilled(item, crafting_data)
+        }
+    }
+
+    //The tool has to be in hand, and can't be going into the recipe as well
+    pub fn get_missing_tool(
+        &self,
+        held: Option<Entity>,
+        ingredients: &[Entity],
+        crafting_data: &CraftingData,
+    ) -> Option<&RecipeIngredient> {
+        self.get_tool().filter(|tool| {
+            !held.map_or(false, |item| {
+                !ingredients.contains(&item)
+                    && tool.requirement.requirement_fulfilled(item, crafting_data)
+            })
         })
     }
 
     pub fn craft(
         &self,
         ingredients: &[Entity],
+        quality: Quality,
         lazy: &LazyUpdate,
         entities: &Entities,
         data: &CraftingData,
     ) -> Result<Entity, String> {
-        let requirements = self.get_ingredient_requirements();
+        let slots = self.get_slots();
 
-        if requirements.len() != ingredients.len() {
+        if slots.len() != ingredients.len() {
             return Err(String::from(
                 "Incorrect amount of ingredients passed to crafting recipe!",
             ));
         }
 
-        for (requirement, ingredient) in requirements.iter().zip(ingredients.iter()) {
-            if !requirement
-                .requirement
-                .requirement_fulfilled(*ingredient, data)
-            {
+        for (slot, ingredient) in slots.iter().zip(ingredients.iter()) {
+            if !slot.requirement.requirement_fulfilled(*ingredient, data) {
                 return Err(String::from(
                     "Ingredients passed to crafting recipe do not fulfill requirements",
                 ));
             }
         }
 
-        let entity = match self {
-            Self::CampFire => FurnitureBuilder::CampFire.build(lazy, entities),
-            Self::Spear => EquipmentBuilder::Spear {
-                head_material: Material::Stone,
-                handle_material: Material::Wood,
-            }
-            .build(lazy, entities),
-            Self::Pick => EquipmentBuilder::Pick {
-                head_material: Material::Stone,
-                handle_material: Material::Wood,
-            }
-            .build(lazy, entities),
-            Self::Axe => EquipmentBuilder::Axe {
-                head_material: Material::Stone,
-                handle_material: Material::Wood,
+        //A part takes after the first item that went into it, so that has to be made of something
+        let mut materials = Vec::new();
+
+        for part_name in self.output.get_part_names() {
+            let material = slots
+                .iter()
+                .position(|slot| slot.part_name == part_name)
+                .and_then(|index| data.material.get(ingredients[index]));
+
+            match material {
+                Some(material) => materials.push((part_name, material)),
+                None => {
+                    return Err(format!(
+                        "Nothing that went into the {} has a material to lend it",
+                        part_name
+                    ))
+                }
             }
-            .build(lazy, entities),
-            Self::Knife => EquipmentBuilder::Knife {
-                blade_material: Material::Stone,
-                handle_material: Material::Wood,
+        }
+
+        let part = |part_name: &str| {
+            materials
+                .iter()
+                .find(|(name, _)| *name == part_name)
+                .unwrap()
+                .1
+        };
+
+        let equipment = match self.output.get_equipment_builder(part) {
+            Some(equipment) => equipment,
+            None => {
+                //Burned up, so there's nothing to keep hold of
+                for ingredient in ingredients {
+                    entities.delete(*ingredient).unwrap();
+                }
+
+                return Ok(self
+                    .output
+                    .get_furniture_builder()
+                    .unwrap()
+                    .build(lazy, entities));
             }
-            .build(lazy, entities),
         };
 
+        //Tools last as long as what went into them, and hang on to it for when they break
+        let durability = ingredients
+            .iter()
+            .filter_map(|ingredient| data.material.get(*ingredient))
+            .map(|mat| mat.amount as u32 * mat.material.get_durability())
+            .sum::<u32>()
+            * quality.get_durability_multiplier()
+            / 100;
+
+        let entity = equipment.build(quality, lazy, entities);
+
+        lazy.insert(
+            entity,
+            DurabilityComponent::new(durability.max(1), quality, ingredients.to_vec()),
+        );
+
         return Ok(entity);
     }
 }
+
+//Gives as many of the slots as it can a different item each, in order, adding them to used
+//Every way of sharing out the items is tried, as a slot's first fit may be all a later one can take
+fn fill_slots<T, F>(
+    slots: &[&RecipeIngredient],
+    available: &[T],
+    used: &mut Vec<T>,
+    fits: &F,
+) -> usize
+where
+    T: Copy + PartialEq,
+    F: Fn(&RecipeIngredient, T) -> bool,
+{
+    let slot = match slots.first() {
+        Some(slot) => *slot,
+        None => return 0,
+    };
+
+    let mut best = 0;
+
+    for item in available.iter().copied() {
+        if used.contains(&item) || !fits(slot, item) {
+            continue;
+        }
+
+        used.push(item);
+        let filled = 1 + fill_slots(&slots[1..], available, used, fits);
+
+        if filled == slots.len() {
+            return filled;
+        }
+
+        used.pop();
+        best = best.max(filled);
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(part_name: &str) -> RecipeIngredient {
+        RecipeIngredient {
+            part_name: String::from(part_name),
+            requirement: RecipeRequirement::Flammable,
+            count: 1,
+            role: IngredientRole::Consumed,
+        }
+    }
+
+    #[test]
+    fn test_items_are_shared_out_to_fill_every_slot() {
+        //The shard can go in either slot, but the stick only fits the first
+        const SHARD: u32 = 0;
+        const STICK: u32 = 1;
+
+        let either = slot("either");
+        let shard_only = slot("shard only");
+        let fits = |ingredient: &RecipeIngredient, item: u32| {
+            ingredient.part_name == "either" || item == SHARD
+        };
+
+        //Handing the shard to the first slot, as it comes first, would leave nothing for the second
+        let mut used = Vec::new();
+        assert_eq!(
+            fill_slots(&[&either, &shard_only], &[SHARD, STICK], &mut used, &fits),
+            2
+        );
+        assert_eq!(used, vec![STICK, SHARD]);
+
+        let mut used = Vec::new();
+        assert_eq!(
+            fill_slots(&[&either, &shard_only], &[STICK], &mut used, &fits),
+            1
+        );
+        assert!(used.is_empty());
+
+        //Nothing already spoken for gets used twice
+        assert_eq!(
+            fill_slots(&[&shard_only], &[SHARD, STICK], &mut vec![SHARD], &fits),
+            0
+        );
+    }
+}
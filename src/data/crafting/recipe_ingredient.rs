@@ -1,8 +1,32 @@
+use serde::Deserialize;
+
 use crate::prelude::*;
 
+#[derive(Debug, Clone, Deserialize)]
 pub struct RecipeIngredient {
     //What is this part named? ex: is it a tool head, tool handle?
-    pub part_name: &'static str,
+    pub part_name: String,
     //What is required for this particular item?
     pub requirement: RecipeRequirement,
+    //How many separate items go into this part, ex: three rocks for a fire ring
+    #[serde(default = "RecipeIngredient::default_count")]
+    pub count: usize,
+    //Is it used up, or just held while crafting?
+    #[serde(default)]
+    pub role: IngredientRole,
+}
+
+impl RecipeIngredient {
+    fn default_count() -> usize {
+        1
+    }
+
+    //ex: "a wooden stick", or "3 wooden sticks" for three of them
+    pub fn describe(&self) -> String {
+        if self.count > 1 {
+            format!("{} {}", self.count, self.requirement.describe_many())
+        } else {
+            self.requirement.describe_one()
+        }
+    }
 }
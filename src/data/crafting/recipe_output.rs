@@ -0,0 +1,68 @@
+use serde::Deserialize;
+
+use crate::prelude::*;
+
+/// What a recipe makes, and which of its parts each piece of the result takes its material from
+#[derive(Debug, Clone, Deserialize)]
+pub enum RecipeOutput {
+    CampFire,
+    Spear { head: String, handle: String },
+    Pick { head: String, handle: String },
+    Axe { head: String, handle: String },
+    Knife { blade: String, handle: String },
+    Shield { board: String },
+}
+
+impl RecipeOutput {
+    //Every part name the output expects the recipe to have
+    pub fn get_part_names(&self) -> Vec<&str> {
+        match self {
+            Self::CampFire => Vec::new(),
+            Self::Spear { head, handle }
+            | Self::Pick { head, handle }
+            | Self::Axe { head, handle } => vec![head, handle],
+            Self::Knife { blade, handle } => vec![blade, handle],
+            Self::Shield { board } => vec![board],
+        }
+    }
+
+    //Furniture is built on the spot, while equipment is handed back to be finished off
+    pub fn get_equipment_builder<'a, F>(&self, part: F) -> Option<EquipmentBuilder>
+    where
+        F: Fn(&str) -> &'a MaterialComponent,
+    {
+        match self {
+            Self::CampFire => None,
+            Self::Spear { head, handle } => Some(EquipmentBuilder::Spear {
+                head_material: part(head).material,
+                head_shape: part(head).shape,
+                handle_material: part(handle).material,
+            }),
+            Self::Pick { head, handle } => Some(EquipmentBuilder::Pick {
+                head_material: part(head).material,
+                head_shape: part(head).shape,
+                handle_material: part(handle).material,
+            }),
+            Self::Axe { head, handle } => Some(EquipmentBuilder::Axe {
+                head_material: part(head).material,
+                head_shape: part(head).shape,
+                handle_material: part(handle).material,
+            }),
+            Self::Knife { blade, handle } => Some(EquipmentBuilder::Knife {
+                blade_material: part(blade).material,
+                blade_shape: part(blade).shape,
+                handle_material: part(handle).material,
+            }),
+            Self::Shield { board } => Some(EquipmentBuilder::Shield {
+                board_material: part(board).material,
+            }),
+        }
+    }
+
+    pub fn get_furniture_builder(&self) -> Option<FurnitureBuilder> {
+        match self {
+            Self::CampFire => Some(FurnitureBuilder::CampFire),
+            _ => None,
+        }
+    }
+}
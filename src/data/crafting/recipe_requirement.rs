@@ -1,48 +1,328 @@
 use crate::prelude::*;
+use serde::Deserialize;
 use specs::Entity;
 
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub enum RecipeRequirement {
-    Material {
-        material: Material,
-    },
-    Shape {
-        shape: MaterialShape,
-    },
-    //Couples two requirements together into one requirement, ex:
+    Material { material: Material },
+    Shape { shape: MaterialShape },
+    //Comes to a point or an edge that'll hold, ex: a bone shard
+    Sharp,
+    //Made of something at least as hard as the given material, ex: hard enough to knap stone with
+    Hardness { at_least: Material },
+    //Will catch light, ex: kindling for a fire
+    Flammable,
+    //Turns a requirement around, ex: any stick that isn't wooden
+    Not(Box<RecipeRequirement>),
+    //Needs every one of the requirements to be met, ex:
     //-Needs to have a pointy shape, and be made of metal - Therefore is spear head
-    And {
-        a: &'static RecipeRequirement,
-        b: &'static RecipeRequirement,
-    },
-    //Allows for multiple requirements to be grouped into one recipe requirement, ex:
-    //-Needs to have the shape of a rock, or a brick, or a hammer head, and be made of stone or metal, then it can be a hammer head
-    Or {
-        a: &'static RecipeRequirement,
-        b: &'static RecipeRequirement,
-    },
+    All(Vec<RecipeRequirement>),
+    //Needs any one of the requirements to be met, ex:
+    //-Needs to have the shape of a rock, or a brick, or a hammer head, then it can be a hammer head
+    Any(Vec<RecipeRequirement>),
 }
 
 impl RecipeRequirement {
     pub fn requirement_fulfilled(&self, item: Entity, data: &CraftingData) -> bool {
+        self.fulfilled_by(data.material.get(item))
+    }
+
+    //Everything asked of an item is down to what it's made of, if it's made of anything
+    pub fn fulfilled_by(&self, mat: Option<&MaterialComponent>) -> bool {
+        match self {
+            Self::Material { material } => mat.map_or(false, |mat| mat.material == *material),
+            Self::Shape { shape } => mat.map_or(false, |mat| mat.shape == *shape),
+            Self::Sharp => mat.map_or(false, |mat| {
+                mat.shape.get_sharpness() > 0 && mat.material.holds_point()
+            }),
+            Self::Hardness { at_least } => mat.map_or(false, |mat| {
+                mat.material.get_hardness() >= at_least.get_hardness()
+            }),
+            Self::Flammable => mat.map_or(false, |mat| mat.material.is_flammable()),
+            Self::Not(requirement) => !requirement.fulfilled_by(mat),
+            Self::All(requirements) => requirements
+                .iter()
+                .all(|requirement| requirement.fulfilled_by(mat)),
+            Self::Any(requirements) => requirements
+                .iter()
+                .any(|requirement| requirement.fulfilled_by(mat)),
+        }
+    }
+
+    //Whether only an item made of something can meet it, ex: not flammable is met by meat too
+    pub fn requires_material(&self) -> bool {
+        match self {
+            Self::Material { .. }
+            | Self::Shape { .. }
+            | Self::Sharp
+            | Self::Hardness { .. }
+            | Self::Flammable => true,
+            Self::Not(_) => false,
+            Self::All(requirements) => requirements
+                .iter()
+                .any(|requirement| requirement.requires_material()),
+            Self::Any(requirements) => requirements
+                .iter()
+                .all(|requirement| requirement.requires_material()),
+        }
+    }
+
+    //Reads as the item wanted, ex: "sharp bone shard" or "something flammable that isn't a log"
+    pub fn describe(&self) -> String {
+        self.describe_phrase(false, false)
+    }
+
+    //The same, with an article in front where it needs one, ex: "a sharp bone shard"
+    pub fn describe_one(&self) -> String {
+        self.describe_phrase(true, false)
+    }
+
+    //The same for several of them, ex: "sharp bone shards"
+    pub fn describe_many(&self) -> String {
+        self.describe_phrase(false, true)
+    }
+
+    fn describe_phrase(&self, article: bool, plural: bool) -> String {
+        let (adjectives, nouns, clauses) = self.get_phrase_parts(plural);
+
+        //Without anything to name it by, it's described after the fact
+        if nouns.is_empty() && !plural {
+            return std::iter::once(String::from("something"))
+                .chain(adjectives)
+                .chain(clauses)
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+
+        //Or as a handful of things, ex: "flammable things that aren't logs"
+        let nouns = if nouns.is_empty() {
+            vec![String::from("things")]
+        } else {
+            nouns
+        };
+
+        let phrase = adjectives
+            .into_iter()
+            .chain(nouns)
+            .chain(clauses)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        match phrase.chars().next() {
+            _ if !article => phrase,
+            Some('a') | Some('e') | Some('i') | Some('o') | Some('u') => format!("an {}", phrase),
+            _ => format!("a {}", phrase),
+        }
+    }
+
+    //What goes before the noun, the noun, and what follows it
+    fn get_phrase_parts(&self, plural: bool) -> (Vec<String>, Vec<String>, Vec<String>) {
         match self {
-            Self::Material { material } => {
-                if let Some(mat) = data.material.get(item) {
-                    return mat.material == *material;
+            Self::Material { material } => (vec![material.get_adjective()], vec![], vec![]),
+            Self::Shape { shape } if plural => (vec![], vec![shape.get_plural_name()], vec![]),
+            Self::Shape { shape } => (vec![], vec![shape.get_name()], vec![]),
+            Self::Sharp => (vec![String::from("sharp")], vec![], vec![]),
+            Self::Flammable => (vec![String::from("flammable")], vec![], vec![]),
+            Self::Hardness { .. } => (vec![], vec![], vec![self.describe_trait(plural)]),
+            Self::Not(requirement) => (
+                vec![],
+                vec![],
+                vec![format!(
+                    "that {} {}",
+                    if plural { "aren't" } else { "isn't" },
+                    requirement.describe_trait(plural)
+                )],
+            ),
+            Self::All(requirements) => {
+                let mut parts = (Vec::new(), Vec::new(), Vec::new());
+
+                for (adjectives, nouns, clauses) in requirements
+                    .iter()
+                    .map(|requirement| requirement.get_phrase_parts(plural))
+                {
+                    parts.0.extend(adjectives);
+                    parts.1.extend(nouns);
+                    parts.2.extend(clauses);
                 }
-                false
+
+                parts
             }
-            Self::Shape { shape } => {
-                if let Some(mat) = data.material.get(item) {
-                    return mat.shape == *shape;
+            Self::Any(requirements) => {
+                let alternatives: Vec<_> = requirements
+                    .iter()
+                    .map(|requirement| requirement.describe_phrase(false, plural))
+                    .collect();
+
+                //Bracketed, as it may end up with more said about it, ex: "stone (rock or brick)"
+                if alternatives.len() > 1 {
+                    (
+                        vec![],
+                        vec![format!("({})", alternatives.join(" or "))],
+                        vec![],
+                    )
+                } else {
+                    (vec![], alternatives, vec![])
                 }
-                false
-            }
-            Self::And { a, b } => {
-                a.requirement_fulfilled(item, data) && b.requirement_fulfilled(item, data)
-            }
-            Self::Or { a, b } => {
-                a.requirement_fulfilled(item, data) || b.requirement_fulfilled(item, data)
             }
         }
     }
+
+    //What the item has to be, ex: "a log", "flammable" or "as hard as rock"
+    fn describe_trait(&self, plural: bool) -> String {
+        match self {
+            Self::Material { material } => material.get_adjective(),
+            Self::Shape { shape } if plural => shape.get_plural_name(),
+            Self::Shape { shape } => format!("a {}", shape.get_name()),
+            Self::Sharp => String::from("sharp"),
+            Self::Flammable => String::from("flammable"),
+            Self::Hardness { at_least } => format!("as hard as {}", at_least.get_name()),
+            Self::Not(requirement) => format!("not {}", requirement.describe_trait(plural)),
+            Self::All(requirements) => requirements
+                .iter()
+                .map(|requirement| requirement.describe_trait(plural))
+                .collect::<Vec<_>>()
+                .join(" and "),
+            Self::Any(requirements) => requirements
+                .iter()
+                .map(|requirement| requirement.describe_trait(plural))
+                .collect::<Vec<_>>()
+                .join(" or "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat(material: Material, shape: MaterialShape) -> MaterialComponent {
+        MaterialComponent {
+            material,
+            shape,
+            amount: 1,
+        }
+    }
+
+    #[test]
+    fn test_describes_nested_requirements() {
+        let ring = RecipeRequirement::All(vec![
+            RecipeRequirement::Material {
+                material: Material::Stone,
+            },
+            RecipeRequirement::Any(vec![
+                RecipeRequirement::Shape {
+                    shape: MaterialShape::Rock,
+                },
+                RecipeRequirement::Shape {
+                    shape: MaterialShape::Brick,
+                },
+            ]),
+        ]);
+
+        assert_eq!(ring.describe(), "stone (rock or brick)");
+        assert_eq!(ring.describe_one(), "a stone (rock or brick)");
+        assert_eq!(ring.describe_many(), "stone (rocks or bricks)");
+
+        let kindling = RecipeRequirement::All(vec![
+            RecipeRequirement::Flammable,
+            RecipeRequirement::Not(Box::new(RecipeRequirement::Shape {
+                shape: MaterialShape::Log,
+            })),
+        ]);
+
+        assert_eq!(kindling.describe(), "something flammable that isn't a log");
+        assert_eq!(
+            kindling.describe_one(),
+            "something flammable that isn't a log"
+        );
+        assert_eq!(
+            kindling.describe_many(),
+            "flammable things that aren't logs"
+        );
+
+        let scraper = RecipeRequirement::Hardness {
+            at_least: Material::Stone,
+        };
+
+        assert_eq!(scraper.describe_one(), "something as hard as rock");
+
+        let blade = RecipeRequirement::All(vec![
+            RecipeRequirement::Sharp,
+            RecipeRequirement::Material {
+                material: Material::Bone,
+            },
+            RecipeRequirement::Shape {
+                shape: MaterialShape::Shard,
+            },
+        ]);
+
+        assert_eq!(blade.describe_one(), "a sharp bone shard");
+
+        let sticks = RecipeIngredient {
+            part_name: String::from("handle"),
+            requirement: RecipeRequirement::All(vec![
+                RecipeRequirement::Material {
+                    material: Material::Wood,
+                },
+                RecipeRequirement::Shape {
+                    shape: MaterialShape::Stick,
+                },
+            ]),
+            count: 3,
+            role: IngredientRole::Consumed,
+        };
+
+        assert_eq!(sticks.describe(), "3 wooden sticks");
+    }
+
+    #[test]
+    fn test_negated_requirements_match_anything_else() {
+        let not_flammable = RecipeRequirement::Not(Box::new(RecipeRequirement::Flammable));
+
+        assert!(!not_flammable.fulfilled_by(Some(&mat(Material::Wood, MaterialShape::Stick))));
+        assert!(not_flammable.fulfilled_by(Some(&mat(Material::Stone, MaterialShape::Rock))));
+        //Meat isn't made of anything, so it isn't flammable either
+        assert!(not_flammable.fulfilled_by(None));
+        assert!(!not_flammable.requires_material());
+    }
+
+    #[test]
+    fn test_sharp_needs_a_point_that_holds() {
+        assert!(
+            RecipeRequirement::Sharp.fulfilled_by(Some(&mat(Material::Bone, MaterialShape::Shard)))
+        );
+        assert!(!RecipeRequirement::Sharp
+            .fulfilled_by(Some(&mat(Material::Bone, MaterialShape::Stick))));
+        assert!(!RecipeRequirement::Sharp
+            .fulfilled_by(Some(&mat(Material::Hide, MaterialShape::Shard))));
+        assert!(!RecipeRequirement::Sharp.fulfilled_by(None));
+    }
+
+    #[test]
+    fn test_hardness_is_a_minimum() {
+        let hard_as_wood = RecipeRequirement::Hardness {
+            at_least: Material::Wood,
+        };
+
+        assert!(hard_as_wood.fulfilled_by(Some(&mat(Material::Stone, MaterialShape::Rock))));
+        assert!(hard_as_wood.fulfilled_by(Some(&mat(Material::Bone, MaterialShape::Shard))));
+        assert!(!hard_as_wood.fulfilled_by(Some(&mat(Material::Hide, MaterialShape::Pelt))));
+    }
+
+    #[test]
+    fn test_any_takes_one_of_several() {
+        let rock_or_brick = RecipeRequirement::Any(vec![
+            RecipeRequirement::Shape {
+                shape: MaterialShape::Rock,
+            },
+            RecipeRequirement::Shape {
+                shape: MaterialShape::Brick,
+            },
+        ]);
+
+        assert!(rock_or_brick.fulfilled_by(Some(&mat(Material::Stone, MaterialShape::Rock))));
+        assert!(rock_or_brick.fulfilled_by(Some(&mat(Material::Dirt, MaterialShape::Brick))));
+        assert!(!rock_or_brick.fulfilled_by(Some(&mat(Material::Wood, MaterialShape::Log))));
+        assert!(rock_or_brick.requires_material());
+    }
 }
@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use specs::Entity;
+use strum::IntoEnumIterator;
+
+use crate::prelude::*;
+
+/// Something a recipe has to be made next to, rather than anything that goes into it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Workstation {
+    Fire,
+}
+
+impl Workstation {
+    pub fn get_name(&self) -> &str {
+        match self {
+            Self::Fire => "fire",
+        }
+    }
+
+    pub fn is_workstation(&self, entity: Entity, data: &CraftingData) -> bool {
+        match self {
+            Self::Fire => data.warmth.get(entity).is_some(),
+        }
+    }
+
+    //Has to be underfoot or right next to the crafter
+    pub fn is_nearby(&self, pos: IPosition, twld: &TileWorldResource, data: &CraftingData) -> bool {
+        Direction::iter().any(|direction| {
+            twld.get(pos + direction.get_offset())
+                .map_or(false, |chunk_tile| {
+                    chunk_tile
+                        .entities
+                        .iter()
+                        .any(|entity| self.is_workstation(*entity, data))
+                })
+        })
+    }
+}
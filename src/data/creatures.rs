@@ -1 +1,10 @@
+pub mod activity_schedule;
+pub mod attribute;
+pub mod body_part;
+pub mod body_part_type;
+pub mod body_plan;
+pub mod creature_size;
+pub mod hunger_stage;
+pub mod metabolism;
+pub mod skill;
 pub mod species;
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActivitySchedule {
+    Diurnal,
+    Nocturnal,
+}
+
+impl ActivitySchedule {
+    pub fn is_active(&self, is_day: bool) -> bool {
+        match self {
+            Self::Diurnal => is_day,
+            Self::Nocturnal => !is_day,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+//Ten is an unremarkable score, and every two points either side is worth one in a modifier
+const AVERAGE_SCORE: i32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
+pub enum Attribute {
+    Strength,
+    Agility,
+    Perception,
+}
+
+impl Attribute {
+    pub fn get_name(&self) -> &str {
+        match self {
+            Self::Strength => "strength",
+            Self::Agility => "agility",
+            Self::Perception => "perception",
+        }
+    }
+
+    pub fn get_modifier(score: i32) -> i32 {
+        (score - AVERAGE_SCORE).div_euclid(2)
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BodyPart {
+    pub name: String,
+    pub part_type: BodyPartType,
+    pub wound: u32,
+    pub max_wound: u32,
+    //Open wounds on this part, each losing blood until it clots
+    pub bleeding: u32,
+}
+
+impl BodyPart {
+    pub fn new(name: &str, part_type: BodyPartType, max_health: u32) -> Self {
+        Self {
+            name: String::from(name),
+            part_type,
+            wound: 0,
+            max_wound: Self::get_max_wound(part_type, max_health),
+            bleeding: 0,
+        }
+    }
+
+    //Each part can take its share of the creature's health before it stops working
+    fn get_max_wound(part_type: BodyPartType, max_health: u32) -> u32 {
+        (max_health * part_type.get_health_share() / 100).max(1)
+    }
+
+    //Keeps any wounds it already has, so growing up doesn't heal them
+    pub fn grow(&mut self, max_health: u32) {
+        self.max_wound = Self::get_max_wound(self.part_type, max_health);
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.wound >= self.max_wound
+    }
+
+    //Returns whether this blow is the one that disabled the part
+    pub fn wound(&mut self, damage: &Damage) -> bool {
+        let was_disabled = self.is_disabled();
+
+        self.wound = (self.wound + damage.amount).min(self.max_wound);
+
+        if self.part_type.bleeds() && damage.damage_type.causes_bleeding() {
+            self.bleeding += 1;
+        }
+
+        !was_disabled && self.is_disabled()
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BodyPartType {
+    Head,
+    Torso,
+    Arm,
+    Leg,
+    Trunk,
+    Branches,
+}
+
+impl BodyPartType {
+    //Losing one of these is fatal
+    pub fn is_vital(&self) -> bool {
+        match self {
+            Self::Head | Self::Torso | Self::Trunk => true,
+            Self::Arm | Self::Leg | Self::Branches => false,
+        }
+    }
+
+    pub fn bleeds(&self) -> bool {
+        match self {
+            Self::Head | Self::Torso | Self::Arm | Self::Leg => true,
+            Self::Trunk | Self::Branches => false,
+        }
+    }
+
+    //How likely a blow is to land on this part, relative to the others
+    pub fn get_hit_weight(&self) -> u32 {
+        match self {
+            Self::Head => 1,
+            Self::Torso => 4,
+            Self::Arm => 2,
+            Self::Leg => 2,
+            Self::Trunk => 3,
+            Self::Branches => 1,
+        }
+    }
+
+    //How much punishment this part takes before it's disabled, as a percentage of max health
+    pub fn get_health_share(&self) -> u32 {
+        match self {
+            Self::Head => 20,
+            Self::Torso => 50,
+            Self::Arm => 25,
+            Self::Leg => 30,
+            Self::Trunk => 100,
+            Self::Branches => 25,
+        }
+    }
+}
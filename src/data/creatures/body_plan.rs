@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BodyPlan {
+    Humanoid,
+    Quadruped,
+    Tree,
+}
+
+impl BodyPlan {
+    pub fn get_parts(&self) -> Vec<(&'static str, BodyPartType)> {
+        match self {
+            Self::Humanoid => vec![
+                ("head", BodyPartType::Head),
+                ("torso", BodyPartType::Torso),
+                ("left arm", BodyPartType::Arm),
+                ("right arm", BodyPartType::Arm),
+                ("left leg", BodyPartType::Leg),
+                ("right leg", BodyPartType::Leg),
+            ],
+            Self::Quadruped => vec![
+                ("head", BodyPartType::Head),
+                ("body", BodyPartType::Torso),
+                ("front left leg", BodyPartType::Leg),
+                ("front right leg", BodyPartType::Leg),
+                ("hind left leg", BodyPartType::Leg),
+                ("hind right leg", BodyPartType::Leg),
+            ],
+            Self::Tree => vec![
+                ("trunk", BodyPartType::Trunk),
+                ("branches", BodyPartType::Branches),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_plan_can_be_killed() {
+        for plan in [BodyPlan::Humanoid, BodyPlan::Quadruped, BodyPlan::Tree].iter() {
+            assert!(plan
+                .get_parts()
+                .iter()
+                .any(|(_, part_type)| part_type.is_vital()));
+        }
+    }
+}
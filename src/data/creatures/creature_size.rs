@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CreatureSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl CreatureSize {
+    //Undergrowth and other obstructions hinder small creatures more than large ones
+    //Halving rounds up, so nothing in the way ever stops costing a large creature anything
+    pub fn scale_obstruction(&self, cost: u32) -> u32 {
+        match self {
+            Self::Small => cost * 2,
+            Self::Medium => cost,
+            Self::Large => (cost + 1) / 2,
+        }
+    }
+
+    //Bigger creatures hold more but burn through it faster
+    pub fn get_metabolism(&self) -> Metabolism {
+        match self {
+            Self::Small => Metabolism {
+                stomach_capacity: 200,
+                burn_interval: 3,
+                hunger_threshold: 60,
+                sated_threshold: 150,
+                healing_cost: 5,
+                hydration_capacity: 200,
+                thirst_interval: 5,
+                thirst_threshold: 60,
+            },
+            Self::Medium => Metabolism {
+                stomach_capacity: 400,
+                burn_interval: 2,
+                hunger_threshold: 100,
+                sated_threshold: 300,
+                healing_cost: 10,
+                hydration_capacity: 400,
+                thirst_interval: 4,
+                thirst_threshold: 120,
+            },
+            Self::Large => Metabolism {
+                stomach_capacity: 800,
+                burn_interval: 1,
+                hunger_threshold: 200,
+                sated_threshold: 600,
+                healing_cost: 20,
+                hydration_capacity: 800,
+                thirst_interval: 3,
+                thirst_threshold: 240,
+            },
+        }
+    }
+}
+
+impl Default for CreatureSize {
+    fn default() -> CreatureSize {
+        CreatureSize::Medium
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_creatures_are_still_slowed() {
+        assert_eq!(CreatureSize::Large.scale_obstruction(0), 0);
+        assert_eq!(CreatureSize::Large.scale_obstruction(1), 1);
+        assert_eq!(CreatureSize::Large.scale_obstruction(3), 2);
+        assert_eq!(CreatureSize::Small.scale_obstruction(3), 6);
+    }
+}
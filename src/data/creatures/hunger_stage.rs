@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+//Ordered from most to least fed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum HungerStage {
+    Overfull,
+    Sated,
+    Fed,
+    Hungry,
+    Starving,
+    Dying,
+}
+
+impl HungerStage {
+    pub fn get_name(&self) -> String {
+        String::from(match self {
+            Self::Overfull => "overfull",
+            Self::Sated => "sated",
+            Self::Fed => "fed",
+            Self::Hungry => "hungry",
+            Self::Starving => "starving",
+            Self::Dying => "dying of hunger",
+        })
+    }
+
+    //Each turn there is a 1 in n chance of starvation doing a point of damage
+    pub fn get_damage_chance(&self) -> Option<u32> {
+        match self {
+            Self::Starving => Some(50),
+            Self::Dying => Some(10),
+            _ => None,
+        }
+    }
+}
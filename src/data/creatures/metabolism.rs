@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+//How many turns with an empty stomach until starvation sets in
+const STARVING_TURNS: usize = 500;
+const DYING_TURNS: usize = 2000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Metabolism {
+    //Eating past this makes creatures throw up
+    pub stomach_capacity: usize,
+    //Each turn there is a 1 in n chance of digesting a point of nutrition
+    pub burn_interval: u32,
+    //Creatures go looking for food below this
+    pub hunger_threshold: usize,
+    //Creatures stop eating once they have this much in their stomachs
+    pub sated_threshold: usize,
+    //How much has to be digested to heal a point of damage
+    pub healing_cost: usize,
+    pub hydration_capacity: usize,
+    //Each turn there is a 1 in n chance of drying out by a point, more often in the heat
+    pub thirst_interval: u32,
+    //Creatures go looking for water below this
+    pub thirst_threshold: usize,
+}
+
+impl Metabolism {
+    pub fn get_hunger_stage(&self, nutrition: usize, starvation: usize) -> HungerStage {
+        if nutrition > self.stomach_capacity {
+            HungerStage::Overfull
+        } else if nutrition >= self.sated_threshold {
+            HungerStage::Sated
+        } else if nutrition >= self.hunger_threshold {
+            HungerStage::Fed
+        } else if nutrition > 0 || starvation < STARVING_TURNS {
+            HungerStage::Hungry
+        } else if starvation < DYING_TURNS {
+            HungerStage::Starving
+        } else {
+            HungerStage::Dying
+        }
+    }
+
+    pub fn can_fit(&self, nutrition: usize, extra: usize) -> bool {
+        nutrition + extra <= self.stomach_capacity
+    }
+
+    pub fn is_thirsty(&self, hydration: usize) -> bool {
+        hydration < self.thirst_threshold
+    }
+}
+
+impl Default for Metabolism {
+    fn default() -> Self {
+        CreatureSize::default().get_metabolism()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hunger_stages_follow_nutrition() {
+        let metabolism = CreatureSize::Medium.get_metabolism();
+
+        assert_eq!(
+            metabolism.get_hunger_stage(metabolism.stomach_capacity + 1, 0),
+            HungerStage::Overfull
+        );
+        assert_eq!(
+            metabolism.get_hunger_stage(metabolism.sated_threshold, 0),
+            HungerStage::Sated
+        );
+        assert_eq!(
+            metabolism.get_hunger_stage(metabolism.hunger_threshold, 0),
+            HungerStage::Fed
+        );
+        assert_eq!(
+            metabolism.get_hunger_stage(metabolism.hunger_threshold - 1, 0),
+            HungerStage::Hungry
+        );
+    }
+
+    #[test]
+    fn test_starvation_worsens_over_time() {
+        let metabolism = CreatureSize::Small.get_metabolism();
+
+        assert_eq!(metabolism.get_hunger_stage(0, 0), HungerStage::Hungry);
+        assert_eq!(
+            metabolism.get_hunger_stage(0, STARVING_TURNS),
+            HungerStage::Starving
+        );
+        assert_eq!(
+            metabolism.get_hunger_stage(0, DYING_TURNS),
+            HungerStage::Dying
+        );
+        //Anything in the stomach holds starvation off
+        assert_eq!(
+            metabolism.get_hunger_stage(1, DYING_TURNS),
+            HungerStage::Hungry
+        );
+    }
+
+    #[test]
+    fn test_larger_creatures_eat_more() {
+        let small = CreatureSize::Small.get_metabolism();
+        let large = CreatureSize::Large.get_metabolism();
+
+        assert!(large.stomach_capacity > small.stomach_capacity);
+        assert!(large.hunger_threshold > small.hunger_threshold);
+        assert!(large.can_fit(small.stomach_capacity, 1));
+        assert!(!small.can_fit(small.stomach_capacity, 1));
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+use crate::prelude::*;
+
+//Experience needed for the first level, each one after costs this much more than the last
+const EXPERIENCE_PER_LEVEL: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
+pub enum Skill {
+    Melee,
+    Throwing,
+    Crafting,
+    Building,
+    Digging,
+    Foraging,
+}
+
+impl Skill {
+    pub fn get_name(&self) -> &str {
+        match self {
+            Self::Melee => "melee",
+            Self::Throwing => "throwing",
+            Self::Crafting => "crafting",
+            Self::Building => "building",
+            Self::Digging => "digging",
+            Self::Foraging => "foraging",
+        }
+    }
+
+    //The attribute that makes up for a lack of practice
+    pub fn get_attribute(&self) -> Attribute {
+        match self {
+            Self::Melee | Self::Building | Self::Digging => Attribute::Strength,
+            Self::Throwing | Self::Crafting => Attribute::Agility,
+            Self::Foraging => Attribute::Perception,
+        }
+    }
+
+    //Total experience needed to reach a level: 10, 30, 60, 100...
+    pub fn get_experience_for_level(level: u32) -> u32 {
+        EXPERIENCE_PER_LEVEL * level * (level + 1) / 2
+    }
+
+    //Practised hands and a good knack both shave turns off a job, but nothing is instant
+    pub fn get_duration(base_turns: u32, level: u32, attribute_modifier: i32) -> u32 {
+        (base_turns as i32 - level as i32 - attribute_modifier).max(1) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_get_harder_to_reach() {
+        let first = Skill::get_experience_for_level(1);
+        let second = Skill::get_experience_for_level(2) - first;
+
+        assert_eq!(first, 10);
+        assert!(second > first);
+    }
+
+    #[test]
+    fn test_jobs_always_take_a_turn() {
+        assert_eq!(Skill::get_duration(6, 0, 0), 6);
+        assert_eq!(Skill::get_duration(6, 2, 1), 3);
+        assert_eq!(Skill::get_duration(6, 10, 3), 1);
+        assert_eq!(Skill::get_duration(6, 0, -2), 8);
+    }
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 
-#[derive(Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum Species {
     //Sapient
     Human,
@@ -56,6 +56,79 @@ impl Species {
         }
     }
 
+    pub fn get_size(&self) -> CreatureSize {
+        match self {
+            Self::Human => CreatureSize::Medium,
+            Self::Elf => CreatureSize::Medium,
+            Self::Kobold => CreatureSize::Small,
+            Self::Goblin => CreatureSize::Small,
+
+            Self::Deer => CreatureSize::Large,
+        }
+    }
+
+    pub fn get_activity_schedule(&self) -> ActivitySchedule {
+        match self {
+            Self::Human => ActivitySchedule::Diurnal,
+            Self::Elf => ActivitySchedule::Diurnal,
+            Self::Kobold => ActivitySchedule::Nocturnal,
+            Self::Goblin => ActivitySchedule::Nocturnal,
+
+            Self::Deer => ActivitySchedule::Diurnal,
+        }
+    }
+
+    pub fn get_body_plan(&self) -> BodyPlan {
+        match self {
+            Self::Human => BodyPlan::Humanoid,
+            Self::Elf => BodyPlan::Humanoid,
+            Self::Kobold => BodyPlan::Humanoid,
+            Self::Goblin => BodyPlan::Humanoid,
+
+            Self::Deer => BodyPlan::Quadruped,
+        }
+    }
+
+    //What a creature fights with when it has nothing in hand
+    pub fn get_natural_attacks(&self) -> Vec<Attack> {
+        let (name, attack_type, dice, damage_type) = match self {
+            Self::Human => ("punch", AttackType::Stab, "1d2", DamageType::Blunt),
+            Self::Elf => ("punch", AttackType::Stab, "1d2", DamageType::Blunt),
+            Self::Kobold => ("bite", AttackType::Stab, "1d3", DamageType::Pierce),
+            Self::Goblin => ("claw", AttackType::Slash, "1d2", DamageType::Slash),
+
+            Self::Deer => ("kick", AttackType::Stab, "1d3", DamageType::Blunt),
+        };
+
+        vec![Attack {
+            name: String::from(name),
+            attack_type,
+            attack_dice: DiceRoll::new_from_string(dice),
+            damage_type,
+            material: None,
+        }]
+    }
+
+    //What's left to cut from a carcass
+    pub fn get_butchering_yield(&self) -> Vec<ItemBuilder> {
+        match self {
+            Self::Human | Self::Elf | Self::Kobold | Self::Goblin => {
+                vec![ItemBuilder::Meat, ItemBuilder::Bone]
+            }
+
+            Self::Deer => vec![
+                ItemBuilder::Meat,
+                ItemBuilder::Meat,
+                ItemBuilder::Hide,
+                ItemBuilder::Bone,
+            ],
+        }
+    }
+
+    pub fn get_metabolism(&self) -> Metabolism {
+        self.get_size().get_metabolism()
+    }
+
     pub fn get_disposition(&self) -> Disposition {
         match self {
             Self::Human => Disposition::Neutral,
@@ -66,4 +139,139 @@ impl Species {
             Self::Deer => Disposition::Timid,
         }
     }
+
+    pub fn is_sapient(&self) -> bool {
+        match self {
+            Self::Human | Self::Elf | Self::Kobold | Self::Goblin => true,
+
+            Self::Deer => false,
+        }
+    }
+
+    pub fn get_max_health(&self) -> u32 {
+        match self {
+            Self::Human | Self::Elf | Self::Kobold | Self::Goblin => 100,
+
+            Self::Deer => 10,
+        }
+    }
+
+    //What a typical adult of the species is like, before any individual variation
+    pub fn get_attributes(&self) -> AttributesComponent {
+        let (strength, agility, perception) = match self {
+            Self::Human => (10, 10, 10),
+            Self::Elf => (9, 12, 12),
+            Self::Kobold => (8, 12, 10),
+            Self::Goblin => (9, 11, 11),
+
+            Self::Deer => (8, 14, 14),
+        };
+
+        AttributesComponent {
+            strength,
+            agility,
+            perception,
+        }
+    }
+
+    //Ages are counted in turns
+    pub fn get_maturity_age(&self) -> u32 {
+        match self {
+            Self::Human => 20000,
+            Self::Elf => 40000,
+            Self::Kobold => 10000,
+            Self::Goblin => 10000,
+
+            Self::Deer => 6000,
+        }
+    }
+
+    pub fn get_lifespan(&self) -> u32 {
+        match self {
+            Self::Human => 200000,
+            Self::Elf => 600000,
+            Self::Kobold => 100000,
+            Self::Goblin => 100000,
+
+            Self::Deer => 60000,
+        }
+    }
+
+    //How long parents wait before having more young
+    pub fn get_breeding_interval(&self) -> u32 {
+        match self {
+            Self::Human | Self::Elf => 10000,
+            Self::Kobold | Self::Goblin => 5000,
+
+            Self::Deer => 3000,
+        }
+    }
+
+    //How many of this species a single chunk can support before they stop breeding
+    pub fn get_population_cap(&self) -> usize {
+        match self {
+            Self::Human | Self::Elf | Self::Kobold | Self::Goblin => 6,
+
+            Self::Deer => 8,
+        }
+    }
+
+    pub fn get_sprite_builder(&self, juvenile: bool) -> SpriteBuilder {
+        match (self, juvenile) {
+            (Self::Deer, false) => SpriteBuilder::Deer,
+            (Self::Deer, true) => SpriteBuilder::Fawn,
+            (_, false) => SpriteBuilder::Humanoid { species: *self },
+            (_, true) => SpriteBuilder::Child { species: *self },
+        }
+    }
+
+    pub fn get_symbol_builder(&self, juvenile: bool) -> SymbolBuilder {
+        match (self, juvenile) {
+            (Self::Deer, false) => SymbolBuilder::Deer,
+            (Self::Deer, true) => SymbolBuilder::Fawn,
+            (_, false) => SymbolBuilder::Humanoid { species: *self },
+            (_, true) => SymbolBuilder::Child { species: *self },
+        }
+    }
+
+    //How many meals from the same hand it takes before a wild creature trusts its feeder
+    pub fn get_feedings_to_tame(&self) -> Option<u32> {
+        match self {
+            Self::Human | Self::Elf | Self::Kobold | Self::Goblin => None,
+
+            Self::Deer => Some(3),
+        }
+    }
+
+    //TODO: Change to a more complex hostility check
+    pub fn is_threatened_by(&self, other: &Species) -> bool {
+        self != other
+            && match (self.get_disposition(), other.get_disposition()) {
+                (_, Disposition::Agressive) => true,
+                (Disposition::Timid, _) => other.is_sapient(),
+                _ => false,
+            }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_deer_give_hide() {
+        let gives_hide = |species: Species| {
+            species
+                .get_butchering_yield()
+                .iter()
+                .any(|item| matches!(item, ItemBuilder::Hide))
+        };
+
+        assert!(gives_hide(Species::Deer));
+        assert!(!gives_hide(Species::Human));
+        assert!(Species::Goblin
+            .get_butchering_yield()
+            .iter()
+            .any(|item| matches!(item, ItemBuilder::Meat)));
+    }
 }
@@ -1 +1,7 @@
+pub mod heard_sound;
 pub mod particle_type;
+pub mod sound;
+pub mod sound_type;
+pub mod stat_modifiers;
+pub mod status_effect;
+pub mod status_effect_type;
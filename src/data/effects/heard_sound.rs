@@ -0,0 +1,7 @@
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+pub struct HeardSound {
+    pub sound: Sound,
+    pub volume: u32,
+}
@@ -41,6 +41,9 @@ pub enum ParticleType {
         direction_from_player: Direction,
         rotation_direction: RotationDirection,
     },
+    Projectile {
+        drawn: bool,
+    },
 }
 
 impl ParticleType {
@@ -81,6 +84,7 @@ impl ParticleType {
             } => (pos + IPosition::new(*x_vel, *y_vel), z + z_vel),
             Self::Thrust { .. } => (pos, z),
             Self::Swing { .. } => (pos, z),
+            Self::Projectile { .. } => (pos, z),
             _ => todo!(),
         }
     }
@@ -195,6 +199,13 @@ impl ParticleType {
                     }
                 }
             }
+            Self::Projectile { drawn } => {
+                if *drawn {
+                    ParticleType::Finished
+                } else {
+                    Self::Projectile { drawn: true }
+                }
+            }
             _ => *self,
         }
     }
@@ -326,6 +337,13 @@ impl ParticleType {
                     mirror: CharMirror::None,
                 }
             }
+            Self::Projectile { .. } => GgBunnyChar {
+                index: 0x007,
+                foreground: Color::new(0.75, 0.75, 0.75, 1.0),
+                background: None,
+                rotation: CharRotation::None,
+                mirror: CharMirror::None,
+            },
             _ => todo!("{:?}", self),
         }
     }
@@ -0,0 +1,89 @@
+use std::collections::BinaryHeap;
+
+use fxhash::FxHashMap;
+use specs::Entity;
+
+use crate::prelude::*;
+
+//Sound still carries through walls, but much less of it
+const WALL_DAMPENING: u32 = 4;
+
+#[derive(Debug, Clone)]
+pub struct Sound {
+    pub pos: IPosition,
+    pub sound_type: SoundType,
+    pub loudness: u32,
+    pub emitter: Option<Entity>,
+}
+
+impl Sound {
+    /// The volume the sound is heard at on every tile it reaches
+    /// tile_fn returns whether a tile collides, or None if it is unloaded and sound cannot travel through it
+    pub fn propagate(&self, tile_fn: impl Fn(IPosition) -> Option<bool>) -> FxHashMap<IPosition, u32> {
+        let mut volumes = FxHashMap::default();
+        let mut frontier = BinaryHeap::new();
+
+        volumes.insert(self.pos, self.loudness);
+        frontier.push((self.loudness, self.pos.x, self.pos.y));
+
+        while let Some((volume, x, y)) = frontier.pop() {
+            let pos = IPosition::new(x, y);
+
+            if volumes.get(&pos).map_or(false, |heard| volume < *heard) {
+                //Already reached this tile more loudly
+                continue;
+            }
+
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    let neighbour = pos + IPosition::new(dx, dy);
+
+                    if let Some(collides) = tile_fn(neighbour) {
+                        let dampening = if collides { 1 + WALL_DAMPENING } else { 1 };
+                        let new_volume = volume.saturating_sub(dampening);
+
+                        if new_volume > 0 && volumes.get(&neighbour).map_or(true, |heard| new_volume > *heard) {
+                            volumes.insert(neighbour, new_volume);
+                            frontier.push((new_volume, neighbour.x, neighbour.y));
+                        }
+                    }
+                }
+            }
+        }
+
+        volumes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_sound(loudness: u32) -> Sound {
+        Sound {
+            pos: IPosition::ZERO,
+            sound_type: SoundType::Combat,
+            loudness,
+            emitter: None,
+        }
+    }
+
+    #[test]
+    fn test_sound_fades_with_distance() {
+        let volumes = test_sound(5).propagate(|_| Some(false));
+
+        assert_eq!(volumes[&IPosition::ZERO], 5);
+        assert_eq!(volumes[&IPosition::new(2, -2)], 3);
+        assert!(volumes.get(&IPosition::new(5, 0)).is_none());
+    }
+
+    #[test]
+    fn test_walls_muffle_sound() {
+        //A wall along x = 1
+        let volumes = test_sound(8).propagate(|pos| (pos.y.abs() < 20).then(|| pos.x == 1));
+
+        assert_eq!(volumes[&IPosition::new(1, 0)], 3);
+        assert_eq!(volumes[&IPosition::new(2, 0)], 2);
+        assert_eq!(volumes[&IPosition::new(-2, 0)], 6);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SoundType {
+    Combat,
+    Death,
+    Building,
+    Footsteps,
+}
+
+impl SoundType {
+    //Loudness is the number of open tiles a sound carries across
+    pub fn get_loudness(&self, size: CreatureSize) -> u32 {
+        let base = match self {
+            Self::Combat => 10,
+            Self::Death => 10,
+            Self::Building => 8,
+            Self::Footsteps => 1,
+        };
+
+        let size_bonus = match size {
+            CreatureSize::Small => 0,
+            CreatureSize::Medium => 1,
+            CreatureSize::Large => 2,
+        };
+
+        base + size_bonus
+    }
+
+    //Whether timid creatures should run from this sound without needing to see what made it
+    pub fn is_alarming(&self) -> bool {
+        match self {
+            Self::Combat | Self::Death => true,
+            Self::Building | Self::Footsteps => false,
+        }
+    }
+}
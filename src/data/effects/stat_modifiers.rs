@@ -0,0 +1,21 @@
+use std::ops::Add;
+
+/// Adjustments to a creature's abilities, from whatever it's currently suffering
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatModifiers {
+    pub accuracy: i32,
+    pub evasion: i32,
+    pub movement_delay: u32,
+}
+
+impl Add for StatModifiers {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            accuracy: self.accuracy + other.accuracy,
+            evasion: self.evasion + other.evasion,
+            movement_delay: self.movement_delay + other.movement_delay,
+        }
+    }
+}
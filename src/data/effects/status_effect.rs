@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub effect_type: StatusEffectType,
+    //Turns left before it wears off, or None if something else decides when it ends
+    pub remaining: Option<u32>,
+}
@@ -0,0 +1,106 @@
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use tui::style::Color;
+
+use crate::prelude::*;
+
+//Each turn a poisoned creature has a 1 in n chance of taking a point of damage
+const POISON_INTERVAL: u32 = 5;
+//Each turn a well fed creature has a 1 in n chance of healing a point
+const WELL_FED_HEAL_CHANCE: u32 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatusEffectType {
+    Bleeding,
+    Stunned,
+    Poisoned,
+    Wet,
+    Burning,
+    WellFed,
+    Exhausted,
+}
+
+impl StatusEffectType {
+    pub fn get_name(&self) -> &str {
+        match self {
+            Self::Bleeding => "bleeding",
+            Self::Stunned => "stunned",
+            Self::Poisoned => "poisoned",
+            Self::Wet => "wet",
+            Self::Burning => "burning",
+            Self::WellFed => "well fed",
+            Self::Exhausted => "exhausted",
+        }
+    }
+
+    pub fn get_color(&self) -> Color {
+        match self {
+            Self::Bleeding | Self::Burning => Color::Red,
+            Self::Stunned | Self::Exhausted => Color::Magenta,
+            Self::Poisoned => Color::Green,
+            Self::Wet => Color::Blue,
+            Self::WellFed => Color::Yellow,
+        }
+    }
+
+    pub fn get_modifiers(&self) -> StatModifiers {
+        match self {
+            Self::Stunned => StatModifiers {
+                accuracy: -4,
+                evasion: -4,
+                movement_delay: 1,
+            },
+            Self::Exhausted => StatModifiers {
+                accuracy: -2,
+                evasion: -2,
+                movement_delay: 1,
+            },
+            Self::Poisoned => StatModifiers {
+                accuracy: -1,
+                ..StatModifiers::default()
+            },
+            //A slippery grip and sodden clothes
+            Self::Wet => StatModifiers {
+                accuracy: -1,
+                ..StatModifiers::default()
+            },
+            Self::Bleeding | Self::Burning | Self::WellFed => StatModifiers::default(),
+        }
+    }
+
+    //What the effect does to its bearer each turn it lasts
+    pub fn on_tick(&self, hpc: &mut HealthComponent) {
+        match self {
+            Self::Burning => hpc.turn_damage += 1,
+            Self::Poisoned => {
+                if thread_rng().gen_range(0..POISON_INTERVAL) == 0 {
+                    hpc.turn_damage += 1;
+                }
+            }
+            Self::WellFed => {
+                if hpc.value < hpc.max_value && thread_rng().gen_range(0..WELL_FED_HEAL_CHANCE) == 0
+                {
+                    hpc.value += 1;
+                }
+            }
+            //Blood loss is worked out per wound by the health system
+            Self::Bleeding | Self::Stunned | Self::Wet | Self::Exhausted => (),
+        }
+    }
+
+    //Effects that can't be on a creature at the same time, where the newcomer wins
+    pub fn get_cancelled(&self) -> Option<StatusEffectType> {
+        match self {
+            Self::Wet => Some(Self::Burning),
+            _ => None,
+        }
+    }
+
+    //Effects that stop this one taking hold at all
+    pub fn get_prevented_by(&self) -> Option<StatusEffectType> {
+        match self {
+            Self::Burning => Some(Self::Wet),
+            _ => None,
+        }
+    }
+}
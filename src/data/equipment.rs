@@ -1,2 +1,6 @@
 pub mod attack;
+pub mod attack_outcome;
 pub mod attack_type;
+pub mod combat_stats;
+pub mod damage;
+pub mod damage_type;
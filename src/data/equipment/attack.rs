@@ -1,9 +1,79 @@
 use crate::prelude::*;
 use serde::{Deserialize, Serialize};
+use specs::Entity;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Attack {
     pub name: String,
     pub attack_type: AttackType,
     pub attack_dice: DiceRoll,
+    pub damage_type: DamageType,
+    //What the business end is made of, if it isn't flesh and bone
+    pub material: Option<Material>,
+}
+
+impl Attack {
+    pub fn get_name(&self) -> String {
+        format!(
+            "{} ({} {})",
+            self.name,
+            self.attack_dice.to_string(),
+            self.damage_type.get_name()
+        )
+    }
+
+    //Cutting up a carcass takes a blade to stab with or an edge to swing,
+    //teeth, claws and spear points just tear at it
+    pub fn cuts(&self) -> bool {
+        self.material.is_some()
+            && self.damage_type != DamageType::Blunt
+            && (matches!(self.attack_type, AttackType::Stab) || self.attack_type.is_rotational())
+    }
+
+    pub fn roll_damage(&self, attacker: Entity) -> Damage {
+        Damage {
+            amount: self.attack_dice.roll(),
+            damage_type: self.damage_type,
+            material: self.material,
+            attacker: Some(attacker),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attack(
+        attack_type: AttackType,
+        damage_type: DamageType,
+        material: Option<Material>,
+    ) -> Attack {
+        Attack {
+            name: String::from("test"),
+            attack_type,
+            attack_dice: DiceRoll::new_from_string("1d4"),
+            damage_type,
+            material,
+        }
+    }
+
+    #[test]
+    fn test_only_blades_and_edges_cut() {
+        let bone_knife = attack(AttackType::Stab, DamageType::Pierce, Some(Material::Bone));
+        let stone_axe = attack(AttackType::Swing, DamageType::Slash, Some(Material::Stone));
+        let stone_spear = attack(
+            AttackType::Thrust,
+            DamageType::Pierce,
+            Some(Material::Stone),
+        );
+        let bite = attack(AttackType::Stab, DamageType::Pierce, None);
+        let claw = attack(AttackType::Slash, DamageType::Slash, None);
+
+        assert!(bone_knife.cuts());
+        assert!(stone_axe.cuts());
+        assert!(!stone_spear.cuts());
+        assert!(!bite.cuts());
+        assert!(!claw.cuts());
+    }
 }
@@ -0,0 +1,53 @@
+use crate::prelude::*;
+
+/// How a single attack roll turned out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttackOutcome {
+    Miss,
+    Dodged,
+    Blocked,
+    Hit,
+    Critical,
+}
+
+impl AttackOutcome {
+    pub fn lands(&self) -> bool {
+        matches!(self, Self::Hit | Self::Critical)
+    }
+
+    //Critical hits roll damage as usual and then double it
+    pub fn apply(&self, damage: Damage) -> Option<Damage> {
+        match self {
+            Self::Hit => Some(damage),
+            Self::Critical => Some(Damage {
+                amount: damage.amount * 2,
+                ..damage
+            }),
+            Self::Miss | Self::Dodged | Self::Blocked => None,
+        }
+    }
+
+    //Ordinary hits are reported by the health system once the wound is known
+    pub fn get_attacker_message(&self, target: &str, attack: &str) -> Option<String> {
+        match self {
+            Self::Miss => Some(format!("You miss the {}.", target)),
+            Self::Dodged => Some(format!("The {} dodges your {}.", target, attack)),
+            Self::Blocked => Some(format!("The {} blocks your {}.", target, attack)),
+            Self::Critical => Some(format!("Your {} strikes the {} squarely!", attack, target)),
+            Self::Hit => None,
+        }
+    }
+
+    pub fn get_defender_message(&self, attacker: &str, attack: &str) -> Option<String> {
+        match self {
+            Self::Miss => Some(format!("The {}'s {} misses you.", attacker, attack)),
+            Self::Dodged => Some(format!("You dodge the {}'s {}.", attacker, attack)),
+            Self::Blocked => Some(format!("You block the {}'s {}.", attacker, attack)),
+            Self::Critical => Some(format!(
+                "The {}'s {} strikes you squarely!",
+                attacker, attack
+            )),
+            Self::Hit => None,
+        }
+    }
+}
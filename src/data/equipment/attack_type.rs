@@ -24,6 +24,25 @@ pub enum AttackType {
 }
 
 impl AttackType {
+    //Whether the attack sweeps around its user, and so needs a direction to swing in
+    pub fn is_rotational(&self) -> bool {
+        match self {
+            Self::Slash | Self::Swing | Self::Twirl | Self::RotationalAttack { .. } => true,
+            Self::Stab | Self::Thrust | Self::LinearAttack { .. } => false,
+        }
+    }
+
+    //Quick, direct attacks are easier to land than big sweeping ones
+    pub fn get_accuracy(&self) -> i32 {
+        match self {
+            Self::Stab => 2,
+            Self::Thrust | Self::LinearAttack { .. } => 1,
+            Self::Slash => 0,
+            Self::Swing | Self::RotationalAttack { .. } => -1,
+            Self::Twirl => -2,
+        }
+    }
+
     pub fn get_offsets(
         &self,
         attack_direction: &Direction,
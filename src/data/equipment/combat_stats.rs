@@ -0,0 +1,245 @@
+use rand::prelude::*;
+
+use crate::prelude::*;
+
+//Attacks are rolled on a d20, and have to beat this before evasion comes into it
+const BASE_DEFENCE: i32 = 10;
+const CRITICAL_ROLL: i32 = 20;
+const FUMBLE_ROLL: i32 = 1;
+
+/// How good a creature is at landing blows and avoiding them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CombatStats {
+    pub accuracy: i32,
+    pub evasion: i32,
+    pub block: i32,
+}
+
+impl CombatStats {
+    //Big creatures are easy to hit but hard to miss with, small ones the other way round
+    pub fn new(size: CreatureSize) -> Self {
+        let (accuracy, evasion) = match size {
+            CreatureSize::Small => (0, 4),
+            CreatureSize::Medium => (1, 2),
+            CreatureSize::Large => (2, 0),
+        };
+
+        Self {
+            accuracy,
+            evasion,
+            block: 0,
+        }
+    }
+
+    //Things that can't get out of the way, like trees or sleepers, only escape on a fumble
+    pub fn helpless() -> Self {
+        Self {
+            accuracy: 0,
+            evasion: -BASE_DEFENCE,
+            block: 0,
+        }
+    }
+
+    //A shield catches blows outright, and harder ones catch more
+    pub fn with_shield(self, material: Material) -> Self {
+        Self {
+            block: 4 + material.get_hardness() as i32,
+            ..self
+        }
+    }
+
+    //Limping creatures can't dodge as well
+    pub fn hampered(self, penalty: u32) -> Self {
+        Self {
+            evasion: self.evasion - penalty as i32,
+            ..self
+        }
+    }
+
+    pub fn modified_by(self, modifiers: StatModifiers) -> Self {
+        Self {
+            accuracy: self.accuracy + modifiers.accuracy,
+            evasion: self.evasion + modifiers.evasion,
+            ..self
+        }
+    }
+
+    pub fn resolve_attack(&self, attack: &Attack, defender: &CombatStats) -> AttackOutcome {
+        self.resolve_attack_with(attack, defender, &mut thread_rng())
+    }
+
+    //Takes the rng so that the odds can be tested with a seeded one
+    pub fn resolve_attack_with<R: Rng>(
+        &self,
+        attack: &Attack,
+        defender: &CombatStats,
+        rng: &mut R,
+    ) -> AttackOutcome {
+        let to_hit_roll = rng.gen_range(1..=20);
+        let block_roll = rng.gen_range(1..=20);
+
+        self.resolve_rolls(
+            attack.attack_type.get_accuracy(),
+            defender,
+            to_hit_roll,
+            block_roll,
+        )
+    }
+
+    pub fn resolve_rolls(
+        &self,
+        weapon_accuracy: i32,
+        defender: &CombatStats,
+        to_hit_roll: i32,
+        block_roll: i32,
+    ) -> AttackOutcome {
+        //A natural 20 always lands and gets past any shield, a natural 1 always misses
+        if to_hit_roll >= CRITICAL_ROLL {
+            return AttackOutcome::Critical;
+        }
+
+        if to_hit_roll <= FUMBLE_ROLL {
+            return AttackOutcome::Miss;
+        }
+
+        let total = to_hit_roll + self.accuracy + weapon_accuracy;
+
+        //Anything that can't dodge at all is easier to hit in the first place
+        if total < BASE_DEFENCE + defender.evasion.min(0) {
+            AttackOutcome::Miss
+        } else if total < BASE_DEFENCE + defender.evasion {
+            AttackOutcome::Dodged
+        } else if block_roll <= defender.block {
+            AttackOutcome::Blocked
+        } else {
+            AttackOutcome::Hit
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    #[test]
+    fn test_natural_rolls_ignore_modifiers() {
+        let clumsy = CombatStats {
+            accuracy: -20,
+            ..CombatStats::default()
+        };
+        let deadly = CombatStats {
+            accuracy: 20,
+            ..CombatStats::default()
+        };
+        let shielded = CombatStats::new(CreatureSize::Small).with_shield(Material::Stone);
+
+        assert_eq!(
+            clumsy.resolve_rolls(0, &shielded, 20, 1),
+            AttackOutcome::Critical
+        );
+        assert_eq!(
+            deadly.resolve_rolls(0, &shielded, 1, 20),
+            AttackOutcome::Miss
+        );
+    }
+
+    #[test]
+    fn test_evasion_turns_hits_into_dodges() {
+        let attacker = CombatStats::default();
+        let nimble = CombatStats {
+            evasion: 4,
+            ..CombatStats::default()
+        };
+
+        assert_eq!(
+            attacker.resolve_rolls(0, &nimble, 9, 20),
+            AttackOutcome::Miss
+        );
+        assert_eq!(
+            attacker.resolve_rolls(0, &nimble, 12, 20),
+            AttackOutcome::Dodged
+        );
+        assert_eq!(
+            attacker.resolve_rolls(0, &nimble, 14, 20),
+            AttackOutcome::Hit
+        );
+        assert_eq!(
+            attacker.resolve_rolls(2, &nimble, 12, 20),
+            AttackOutcome::Hit
+        );
+    }
+
+    #[test]
+    fn test_shields_block_on_a_low_roll() {
+        let attacker = CombatStats::default();
+        let shielded = CombatStats::default().with_shield(Material::Wood);
+
+        assert_eq!(
+            attacker.resolve_rolls(0, &shielded, 15, 6),
+            AttackOutcome::Blocked
+        );
+        assert_eq!(
+            attacker.resolve_rolls(0, &shielded, 15, 7),
+            AttackOutcome::Hit
+        );
+    }
+
+    #[test]
+    fn test_helpless_targets_are_only_missed_on_a_fumble() {
+        let attacker = CombatStats::default();
+        let helpless = CombatStats::helpless();
+
+        assert_eq!(
+            attacker.resolve_rolls(0, &helpless, 1, 20),
+            AttackOutcome::Miss
+        );
+        assert!(attacker.resolve_rolls(0, &helpless, 2, 20).lands());
+    }
+
+    #[test]
+    fn test_seeded_rolls_are_repeatable() {
+        let attack = Attack {
+            name: String::from("punch"),
+            attack_type: AttackType::Stab,
+            attack_dice: DiceRoll::new_from_string("1d2"),
+            damage_type: DamageType::Blunt,
+            material: None,
+        };
+        let attacker = CombatStats::new(CreatureSize::Medium);
+        let defender = CombatStats::new(CreatureSize::Small);
+
+        let roll_all = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            (0..50)
+                .map(|_| attacker.resolve_attack_with(&attack, &defender, &mut rng))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(roll_all(7), roll_all(7));
+        assert!(roll_all(7).iter().any(|outcome| outcome.lands()));
+        assert!(roll_all(7).iter().any(|outcome| !outcome.lands()));
+    }
+
+    #[test]
+    fn test_criticals_double_damage() {
+        let damage = Damage {
+            amount: 3,
+            damage_type: DamageType::Slash,
+            material: None,
+            attacker: None,
+        };
+
+        assert_eq!(
+            AttackOutcome::Critical
+                .apply(damage.clone())
+                .unwrap()
+                .amount,
+            6
+        );
+        assert_eq!(AttackOutcome::Hit.apply(damage.clone()).unwrap().amount, 3);
+        assert!(AttackOutcome::Dodged.apply(damage).is_none());
+    }
+}
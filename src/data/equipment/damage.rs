@@ -0,0 +1,84 @@
+use specs::Entity;
+
+use crate::prelude::*;
+
+/// A single blow, waiting to be resolved against whatever it hit
+#[derive(Debug, Clone)]
+pub struct Damage {
+    pub amount: u32,
+    pub damage_type: DamageType,
+    //What the blow was struck with, if anything
+    pub material: Option<Material>,
+    pub attacker: Option<Entity>,
+}
+
+impl Damage {
+    //Armour shrugs off part of every blow, but only half as much against anything harder than it
+    pub fn reduced_by(&self, armour: Material) -> Self {
+        let mut resistance = armour.get_resistance(self.damage_type);
+
+        if self
+            .material
+            .map_or(false, |material| material.get_hardness() > armour.get_hardness())
+        {
+            resistance /= 2;
+        }
+
+        Self {
+            amount: self.amount.saturating_sub(resistance),
+            ..self.clone()
+        }
+    }
+
+    //Strength puts weight behind a blow, but even the feeblest one still lands
+    pub fn boosted_by(self, bonus: i32) -> Self {
+        Self {
+            amount: (self.amount as i32 + bonus).max(1) as u32,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blow(damage_type: DamageType, material: Option<Material>) -> Damage {
+        Damage {
+            amount: 4,
+            damage_type,
+            material,
+            attacker: None,
+        }
+    }
+
+    #[test]
+    fn test_harder_weapons_bite_deeper() {
+        let wood_on_wood = blow(DamageType::Slash, Some(Material::Wood)).reduced_by(Material::Wood);
+        let stone_on_wood =
+            blow(DamageType::Slash, Some(Material::Stone)).reduced_by(Material::Wood);
+
+        assert!(stone_on_wood.amount > wood_on_wood.amount);
+    }
+
+    #[test]
+    fn test_resistances_depend_on_damage_type() {
+        let knife_on_shield =
+            blow(DamageType::Pierce, Some(Material::Stone)).reduced_by(Material::Wood);
+        let axe_on_tree = blow(DamageType::Slash, Some(Material::Stone)).reduced_by(Material::Wood);
+
+        assert_ne!(knife_on_shield.amount, axe_on_tree.amount);
+    }
+
+    #[test]
+    fn test_armour_never_heals() {
+        let punch = Damage {
+            amount: 1,
+            damage_type: DamageType::Blunt,
+            material: None,
+            attacker: None,
+        };
+
+        assert_eq!(punch.reduced_by(Material::Stone).amount, 0);
+    }
+}
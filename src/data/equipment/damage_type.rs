@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DamageType {
+    Pierce,
+    Slash,
+    Blunt,
+    Fire,
+    Cold,
+}
+
+impl DamageType {
+    pub fn get_name(&self) -> String {
+        String::from(match self {
+            Self::Pierce => "pierce",
+            Self::Slash => "slash",
+            Self::Blunt => "blunt",
+            Self::Fire => "fire",
+            Self::Cold => "cold",
+        })
+    }
+
+    pub fn causes_bleeding(&self) -> bool {
+        match self {
+            Self::Pierce | Self::Slash => true,
+            Self::Blunt | Self::Fire | Self::Cold => false,
+        }
+    }
+
+    //Thrusts need something that holds a point and swings something that holds an edge,
+    //anything else just bludgeons
+    pub fn from_attack(attack_type: AttackType, material: Material) -> Self {
+        if attack_type.is_rotational() {
+            if material.holds_edge() {
+                Self::Slash
+            } else {
+                Self::Blunt
+            }
+        } else if material.holds_point() {
+            Self::Pierce
+        } else {
+            Self::Blunt
+        }
+    }
+}
@@ -206,11 +206,39 @@ impl IPosition {
         chunk_pos * CHUNK_SIZE as i32 + IPosition::try_from(local_pos).unwrap()
     }
 
+    //Deltas are absolute, so a tile up and to the left is as far away as one down and to the right
     pub fn chebyshev_distance(&self, other: &IPosition) -> i32 {
-        let (x_delta, y_delta) = (self.x - other.x, self.y - other.y);
+        let (x_delta, y_delta) = ((self.x - other.x).abs(), (self.y - other.y).abs());
         x_delta.max(y_delta)
     }
 
+    //Every tile on a straight line out to other, not counting the one we start on
+    pub fn line_to(self, other: Self) -> Vec<Self> {
+        let (x_delta, y_delta) = ((other.x - self.x).abs(), -(other.y - self.y).abs());
+        let (x_step, y_step) = ((other.x - self.x).signum(), (other.y - self.y).signum());
+        let mut error = x_delta + y_delta;
+        let mut current = self;
+        let mut line = Vec::new();
+
+        while current != other {
+            let doubled_error = error * 2;
+
+            if doubled_error >= y_delta {
+                error += y_delta;
+                current.x += x_step;
+            }
+
+            if doubled_error <= x_delta {
+                error += x_delta;
+                current.y += y_step;
+            }
+
+            line.push(current);
+        }
+
+        line
+    }
+
     pub fn left(self) -> Self {
         Self {
             x: self.x - 1,
@@ -256,3 +284,40 @@ impl UPosition {
 
     pub const ZERO: Self = Self { x: 0, y: 0 };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_reaches_target() {
+        let start = IPosition::new(2, 3);
+
+        for target in [IPosition::new(9, 5), IPosition::new(-4, -1), IPosition::new(2, -6)].iter() {
+            let line = start.line_to(*target);
+
+            assert_eq!(line.last(), Some(target));
+            assert_eq!(line.len() as i32, start.chebyshev_distance(target));
+        }
+    }
+
+    #[test]
+    fn test_chebyshev_distance_is_the_same_in_every_direction() {
+        let centre = IPosition::new(3, -2);
+
+        for dx in -4..=4 {
+            for dy in -4..=4 {
+                let other = centre + IPosition::new(dx, dy);
+                let expected = dx.abs().max(dy.abs());
+
+                assert_eq!(centre.chebyshev_distance(&other), expected);
+                assert_eq!(other.chebyshev_distance(&centre), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_line_to_self_is_empty() {
+        assert!(IPosition::ZERO.line_to(IPosition::ZERO).is_empty());
+    }
+}
@@ -15,4 +15,11 @@ impl RotationDirection {
             RotationDirection::CounterClockwise
         }
     }
+
+    pub fn get_name(&self) -> String {
+        String::from(match self {
+            Self::Clockwise => "Clockwise",
+            Self::CounterClockwise => "Counter-clockwise",
+        })
+    }
 }
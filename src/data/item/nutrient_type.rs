@@ -1,9 +1,16 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NutrientType {
     Plant,         //Plants
     Fungi,         //Fungi
     Meat,          //Meat
     AnimalProduct, //Anything that comes from an animal that isn't meat
 }
+
+//Edible things saved before nutrient types existed were all plants
+impl Default for NutrientType {
+    fn default() -> NutrientType {
+        NutrientType::Plant
+    }
+}
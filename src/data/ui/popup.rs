@@ -2,7 +2,7 @@ use ggez::event::{KeyCode, KeyMods};
 use tui::{
     layout::Rect,
     style::{Color, Style},
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
@@ -37,6 +37,45 @@ impl Popup {
         }
     }
 
+    pub fn submenu(heading: String, options: Vec<(String, Popup)>) -> Self {
+        Self {
+            heading,
+            popup_type: PopupType::Submenu { options },
+            state: PopupState::Waiting,
+        }
+    }
+
+    pub fn target<F>(heading: String, range: u32, f: F) -> Self
+    where
+        F: FnOnce(IPosition) -> AIGoal + Send + Sync + 'static,
+    {
+        Self {
+            heading,
+            popup_type: PopupType::Target {
+                cursor: IPosition::default(),
+                range,
+                f: Some(Box::new(f)),
+            },
+            state: PopupState::Waiting,
+        }
+    }
+
+    pub fn info(heading: String, lines: Vec<String>) -> Self {
+        Self {
+            heading,
+            popup_type: PopupType::Info { lines },
+            state: PopupState::Waiting,
+        }
+    }
+
+    //Where the target cursor sits relative to the player, if this is a target popup
+    pub fn get_target_cursor(&self) -> Option<IPosition> {
+        match &self.popup_type {
+            PopupType::Target { cursor, .. } => Some(*cursor),
+            _ => None,
+        }
+    }
+
     pub fn handle_input(&mut self, keycode: KeyCode, keymods: KeyMods) {
         if keycode == KeyCode::Escape {
             self.state = PopupState::Canceling;
@@ -60,6 +99,30 @@ impl Popup {
                     }
                 }
             }
+
+            PopupType::Submenu { options } => {
+                if let Some(key_index) = key_to_index(keycode, keymods) {
+                    if key_index < options.len() {
+                        *self = options.swap_remove(key_index).1;
+                    }
+                }
+            }
+
+            PopupType::Target { cursor, range, f } => {
+                if keycode == KeyCode::Return || keycode == KeyCode::T {
+                    if *cursor != IPosition::default() {
+                        self.state = PopupState::Returning(f.take().unwrap()(*cursor));
+                    }
+                } else if let Some(direction) = Direction::from_keycode(keycode) {
+                    let moved = *cursor + direction.get_offset();
+
+                    if moved.chebyshev_distance(&IPosition::default()) <= *range as i32 {
+                        *cursor = moved;
+                    }
+                }
+            }
+
+            PopupType::Info { .. } => {}
         }
     }
 
@@ -93,6 +156,44 @@ impl Popup {
             PopupType::Directions { directions, .. } => {
                 frame.render_widget(DirectionsWidget::new(*directions).block(block), size);
             }
+
+            PopupType::Submenu { options } => {
+                let list = List::new(
+                    options
+                        .iter()
+                        .enumerate()
+                        .map(|(index, (text, _))| {
+                            let c = index_to_letter(index).unwrap();
+
+                            ListItem::new(format!("{}) {}", c, text))
+                        })
+                        .collect::<Vec<_>>(),
+                );
+
+                frame.render_widget(list.block(block), size);
+            }
+
+            //Only a strip along the top, so the map stays visible to aim at
+            PopupType::Target { .. } => {
+                let strip = Rect {
+                    height: size.height.min(3),
+                    ..size
+                };
+                let hint = Paragraph::new("Direction keys to aim, Enter to confirm");
+
+                frame.render_widget(hint.block(block), strip);
+            }
+
+            PopupType::Info { lines } => {
+                let list = List::new(
+                    lines
+                        .iter()
+                        .map(|line| ListItem::new(line.as_str()))
+                        .collect::<Vec<_>>(),
+                );
+
+                frame.render_widget(list.block(block), size);
+            }
         }
     }
 }
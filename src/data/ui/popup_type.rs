@@ -8,4 +8,18 @@ pub enum PopupType {
         directions: Directions,
         f: Option<Box<dyn FnOnce(Direction) -> AIGoal + Send + Sync + 'static>>,
     },
+    //Each option opens another popup in place of this one
+    Submenu {
+        options: Vec<(String, Popup)>,
+    },
+    //The cursor is an offset from whoever opened it, and can't leave the range
+    Target {
+        cursor: IPosition,
+        range: u32,
+        f: Option<Box<dyn FnOnce(IPosition) -> AIGoal + Send + Sync + 'static>>,
+    },
+    //Nothing to choose, just something to read until it's dismissed
+    Info {
+        lines: Vec<String>,
+    },
 }
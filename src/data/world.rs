@@ -1,6 +1,7 @@
 pub mod chunk;
 pub mod chunk_tile;
 pub mod saved_chunk;
+pub mod scent;
 pub mod tile;
 pub mod tile_layout;
 pub mod tile_type;
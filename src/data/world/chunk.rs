@@ -7,6 +7,11 @@ use specs::{Entity, WriteStorage};
 
 use crate::prelude::*;
 
+//Low lying ground floods into ponds
+const WATER_LEVEL: f64 = -0.4;
+//One chunk in this many has a settler living off the land in it
+const SETTLER_CHANCE: u32 = 4;
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Chunk {
     pub tiles: Array2<ChunkTile>,
@@ -29,18 +34,24 @@ impl Chunk {
                 .fertility_noise
                 .get([pos.x as f64 * 0.01, pos.y as f64 * 0.01])
                 .abs();
+            let elevation = gen_package
+                .elevation_noise
+                .get([pos.x as f64 * 0.025, pos.y as f64 * 0.025]);
 
             chunk_tile.tile = Tile {
                 seed: thread_rng().gen::<usize>(),
-                fertility: (fertility * 256.0) as u8,
-                tile_type: if gen_package
-                    .elevation_noise
-                    .get([pos.x as f64 * 0.025, pos.y as f64 * 0.025])
-                    > 0.25
-                {
+                //Nothing grows in water
+                fertility: if elevation < WATER_LEVEL {
+                    0
+                } else {
+                    (fertility * 256.0) as u8
+                },
+                tile_type: if elevation > 0.25 {
                     TileType::Wall {
                         material: Material::Stone,
                     }
+                } else if elevation < WATER_LEVEL {
+                    TileType::Water
                 } else {
                     if fertility > thread_rng().gen_range(0.0..=2.0) {
                         vegetation_local_positions.push(local_pos);
@@ -82,9 +93,10 @@ impl Chunk {
         for local_pos in vegetation_local_positions {
             if !self.tiles[local_pos.to_idx().unwrap()].tile.tile_type.collides() {
                 self.spawn_entity(
-                    match thread_rng().gen_range(0..=5) {
+                    match thread_rng().gen_range(0..=6) {
                         0 => ItemBuilder::Stick.build(lazy, entities),
                         1 => ItemBuilder::Log.build(lazy, entities),
+                        6 => ItemBuilder::Stone.build(lazy, entities),
                         2 => VegetationBuilder::Grass.build(lazy, entities),
                         3 => VegetationBuilder::BerryBush.build(lazy, entities),
                         4 => VegetationBuilder::Tree.build(lazy, entities),
@@ -114,6 +126,18 @@ impl Chunk {
                 &mut world_data.position,
             );
         }
+
+        if thread_rng().gen_range(0..SETTLER_CHANCE) == 0 {
+            let species = *[Species::Human, Species::Elf, Species::Kobold, Species::Goblin]
+                .choose(&mut thread_rng())
+                .unwrap();
+
+            self.spawn_somewhere_free(
+                || CreatureBuilder::Humanoid { species }.build(lazy, entities),
+                chunk_pos,
+                &mut world_data.position,
+            );
+        }
     }
 
     fn spawn_entity(
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use specs::Entity;
+use specs::{Entity, ReadStorage};
 
 use crate::prelude::*;
 
@@ -7,7 +7,92 @@ use crate::prelude::*;
 pub struct ChunkTile {
     pub tile: Tile,
 
+    //Left behind by creatures walking over the tile, fading over time
+    #[serde(default)]
+    pub scents: Vec<Scent>,
+
     // TODO move this out of the serialized data to avoid re-allocating vecs for loaded chunks
     #[serde(skip)]
     pub entities: Vec<Entity>,
 }
+
+impl ChunkTile {
+    //Shared by pathfinding and movement, so that creatures plan paths using the speed they actually move at
+    pub fn get_movement_cost(
+        &self,
+        size: CreatureSize,
+        collider: &ReadStorage<ColliderComponent>,
+        movement_cost: &ReadStorage<MovementCostComponent>,
+    ) -> Option<u32> {
+        let mut cost = self.tile.tile_type.get_movement_cost()?;
+
+        for entity in self.entities.iter() {
+            if collider.get(*entity).is_some() {
+                return None;
+            }
+
+            if let Some(entity_cost) = movement_cost.get(*entity) {
+                cost += size.scale_obstruction(entity_cost.cost);
+            }
+        }
+
+        Some(cost)
+    }
+
+    pub fn get_scent(&self, species: Species) -> u8 {
+        self.scents
+            .iter()
+            .find(|scent| scent.species == species)
+            .map_or(0, |scent| scent.strength)
+    }
+
+    //Fresh scent replaces what is left of the old
+    pub fn add_scent(&mut self, species: Species, strength: u8) {
+        if let Some(scent) = self.scents.iter_mut().find(|scent| scent.species == species) {
+            scent.strength = scent.strength.max(strength);
+        } else {
+            self.scents.push(Scent { species, strength });
+        }
+    }
+
+    pub fn decay_scents(&mut self, amount: u8) {
+        for scent in self.scents.iter_mut() {
+            scent.strength = scent.strength.saturating_sub(amount);
+        }
+
+        self.scents.retain(|scent| scent.strength > 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scents_decay_to_nothing() {
+        let mut chunk_tile = ChunkTile::default();
+
+        chunk_tile.add_scent(Species::Deer, 5);
+        chunk_tile.decay_scents(2);
+        assert_eq!(chunk_tile.get_scent(Species::Deer), 3);
+
+        chunk_tile.decay_scents(4);
+        assert_eq!(chunk_tile.get_scent(Species::Deer), 0);
+        assert!(chunk_tile.scents.is_empty());
+    }
+
+    #[test]
+    fn test_stronger_scents_last_longer() {
+        let mut chunk_tile = ChunkTile::default();
+
+        chunk_tile.add_scent(Species::Deer, 3);
+        chunk_tile.add_scent(Species::Human, 8);
+
+        for _ in 0..3 {
+            chunk_tile.decay_scents(1);
+        }
+
+        assert_eq!(chunk_tile.get_scent(Species::Deer), 0);
+        assert_eq!(chunk_tile.get_scent(Species::Human), 5);
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Scent {
+    pub species: Species,
+    pub strength: u8,
+}
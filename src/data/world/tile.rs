@@ -17,6 +17,7 @@ impl Tile {
             TileType::Ground => SpriteBuilder::Ground {
                 fertility: self.fertility,
             },
+            TileType::Water => SpriteBuilder::Water,
             TileType::Wall { material } => SpriteBuilder::Wall { material },
             TileType::ConstructedWall {
                 material,
@@ -35,6 +36,7 @@ impl Tile {
             TileType::Ground => SymbolBuilder::Ground {
                 fertility: self.fertility,
             },
+            TileType::Water => SymbolBuilder::Water,
             TileType::Wall { material } => SymbolBuilder::Wall { material },
             TileType::ConstructedWall {
                 material,
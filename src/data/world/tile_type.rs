@@ -5,6 +5,7 @@ use crate::prelude::*;
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TileType {
     Ground,
+    Water,
     Wall {
         material: Material,
     },
@@ -17,17 +18,25 @@ pub enum TileType {
 
 impl TileType {
     pub fn collides(&self) -> bool {
+        self.get_movement_cost().is_none()
+    }
+
+    //The cost of stepping onto this tile, or None if it can't be entered at all
+    pub fn get_movement_cost(&self) -> Option<u32> {
         match self {
-            TileType::Ground { .. } => false,
-            TileType::Wall { .. } => true,
+            TileType::Ground { .. } => Some(1),
+            //Wading is slow going
+            TileType::Water => Some(3),
+            TileType::Wall { .. } => None,
             TileType::ConstructedWall { wall_feature, .. } => {
                 if let Some(wall_feature) = wall_feature {
                     match wall_feature {
-                        WallFeature::Doorway => false,
-                        _ => true,
+                        //Squeezing through a doorway is slower than open ground
+                        WallFeature::Doorway => Some(2),
+                        _ => None,
                     }
                 } else {
-                    true
+                    None
                 }
             }
         }
@@ -36,6 +45,7 @@ impl TileType {
     pub fn get_name(&self) -> String {
         match self {
             TileType::Ground { .. } => String::from("ground"),
+            TileType::Water => String::from("water"),
             TileType::Wall { material } => format!("{} wall", material.get_name()),
             TileType::ConstructedWall {
                 material,
@@ -57,6 +67,7 @@ impl TileType {
     pub fn get_build_requirements(&self) -> (Option<Material>, Option<MaterialShape>) {
         match self {
             TileType::Ground { .. } => (Some(Material::Dirt), None),
+            TileType::Water => (None, None),
             TileType::Wall { material } => (Some(*material), None),
             TileType::ConstructedWall {
                 material,
@@ -84,14 +95,21 @@ impl TileType {
                     })
                     .collect()
             }
+            TileType::Water => Vec::new(),
             TileType::Wall { .. } => Vec::new(),
             TileType::ConstructedWall { .. } => Vec::new(),
         }
     }
 
+    //Only bare rock can be dug out, walls that were built have to be knocked down
+    pub fn can_dig(&self) -> bool {
+        matches!(self, TileType::Wall { .. })
+    }
+
     pub fn connects(&self) -> bool {
         match self {
             TileType::Ground { .. } => false,
+            TileType::Water => false,
             TileType::Wall { .. } => true,
             TileType::ConstructedWall { .. } => true,
         }
@@ -5,6 +5,18 @@ pub enum WeatherStatus {
     Sandstorm,
 }
 
+impl WeatherStatus {
+    //Creatures dry out this many times faster
+    pub fn get_heat(&self) -> u32 {
+        match self {
+            Self::Clear => 2,
+            Self::Raining => 1,
+            Self::Snowing => 1,
+            Self::Sandstorm => 3,
+        }
+    }
+}
+
 impl Default for WeatherStatus {
     fn default() -> WeatherStatus {
         WeatherStatus::Clear
@@ -2,30 +2,47 @@ pub mod ai_action;
 pub mod ai_goal;
 pub mod ai_perception;
 pub mod ai_personality;
+pub mod anatomy;
+pub mod armour;
 pub mod attack;
+pub mod attributes;
 pub mod butcherable;
 pub mod collider;
 pub mod collision;
 pub mod death;
 pub mod digestion;
+pub mod digger;
 pub mod draw;
+pub mod durability;
 pub mod edible;
+pub mod fatigue;
 pub mod field_of_view;
 pub mod health;
+pub mod hydration;
 pub mod id;
 pub mod input;
 pub mod intended_movement;
 pub mod inventory;
 pub mod item;
+pub mod life_cycle;
 pub mod manipulator;
 pub mod material;
+pub mod movement_cost;
 pub mod name;
 pub mod particle;
 pub mod particle_emitter;
 pub mod pathing;
 pub mod position;
+pub mod projectile;
+pub mod rot;
 pub mod save_marker;
+pub mod skills;
 pub mod species;
+pub mod status_effect;
+pub mod tame;
+pub mod throwable;
 pub mod to_save;
 pub mod veg_propagation;
 pub mod velocity;
+pub mod warmth;
+pub mod water_container;
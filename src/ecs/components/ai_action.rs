@@ -8,4 +8,7 @@ use crate::prelude::*;
 pub struct AIActionComponent {
     #[serde(skip)]
     pub current_action: Option<AIAction>,
+    //How many turns have gone into the current action, for those that take a while
+    #[serde(skip)]
+    pub work_done: u32,
 }
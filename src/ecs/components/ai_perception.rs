@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use specs::{Component, Entity, VecStorage};
 
+use crate::prelude::*;
+
 #[derive(Clone, Default, Component, Serialize, Deserialize)]
 #[storage(VecStorage)]
 pub struct AIPerceptionComponent {
@@ -12,4 +14,8 @@ pub struct AIPerceptionComponent {
     pub threats: Vec<Entity>,
     #[serde(skip)]
     pub food: Vec<Entity>,
+    #[serde(skip)]
+    pub heard: Vec<HeardSound>,
+    #[serde(skip)]
+    pub water: Vec<IPosition>,
 }
@@ -12,9 +12,12 @@ pub struct AIPersonalityComponent {
 }
 
 impl AIPersonalityComponent {
-    pub fn get_default_goal(&self, under_player_control: bool) -> Option<AIGoal> {
+    pub fn get_default_goal(&self, under_player_control: bool, settler: bool) -> Option<AIGoal> {
         if under_player_control {
             None
+        } else if settler && thread_rng().gen_range(0..4) == 0 {
+            //Settlers spend some of their idle time gathering, crafting and building
+            Some(AIGoal::Settle(SettleGoal::new()))
         } else {
             if thread_rng().gen::<bool>() {
                 Some(AIGoal::GroupWithAllies(GroupWithAlliesGoal {move_in_direction_goal: None}))
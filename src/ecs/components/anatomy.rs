@@ -0,0 +1,91 @@
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+use crate::prelude::*;
+
+//How many turns a crippled leg adds to every step
+const CRIPPLED_LEG_DELAY: u32 = 2;
+
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct AnatomyComponent {
+    pub parts: Vec<BodyPart>,
+}
+
+impl AnatomyComponent {
+    pub fn new(body_plan: BodyPlan, max_health: u32) -> Self {
+        Self {
+            parts: body_plan
+                .get_parts()
+                .into_iter()
+                .map(|(name, part_type)| BodyPart::new(name, part_type, max_health))
+                .collect(),
+        }
+    }
+
+    //Young creatures' parts toughen up as they grow into their full health
+    pub fn grow(&mut self, max_health: u32) {
+        for part in self.parts.iter_mut() {
+            part.grow(max_health);
+        }
+    }
+
+    //Bigger parts are easier to hit
+    pub fn choose_part(&mut self) -> Option<&mut BodyPart> {
+        self.parts
+            .choose_weighted_mut(&mut thread_rng(), |part| part.part_type.get_hit_weight())
+            .ok()
+    }
+
+    pub fn is_fatally_wounded(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|part| part.part_type.is_vital() && part.is_disabled())
+    }
+
+    pub fn get_bleeding(&self) -> u32 {
+        self.parts.iter().map(|part| part.bleeding).sum()
+    }
+
+    pub fn get_movement_penalty(&self) -> u32 {
+        self.parts
+            .iter()
+            .filter(|part| part.part_type == BodyPartType::Leg && part.is_disabled())
+            .count() as u32
+            * CRIPPLED_LEG_DELAY
+    }
+
+    //Creatures with arms need one that works to hold anything
+    pub fn can_grasp(&self) -> bool {
+        let mut arms = self
+            .parts
+            .iter()
+            .filter(|part| part.part_type == BodyPartType::Arm)
+            .peekable();
+
+        arms.peek().is_none() || arms.any(|arm| !arm.is_disabled())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grown_parts_match_adults() {
+        let species = Species::Deer;
+        let adult = AnatomyComponent::new(species.get_body_plan(), species.get_max_health());
+        let mut young =
+            AnatomyComponent::new(species.get_body_plan(), species.get_max_health() / 2);
+
+        young.parts[0].wound = 1;
+        young.grow(species.get_max_health());
+
+        for (young_part, adult_part) in young.parts.iter().zip(adult.parts.iter()) {
+            assert_eq!(young_part.max_wound, adult_part.max_wound);
+        }
+
+        assert_eq!(young.parts[0].wound, 1);
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+use crate::prelude::*;
+
+/// Worn on a creature as its hide, or on an item held up as a shield
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct ArmourComponent {
+    pub material: Material,
+}
@@ -1,5 +1,7 @@
+use std::ops::Deref;
+
 use serde::{Deserialize, Serialize};
-use specs::{Component, VecStorage};
+use specs::{storage::MaskedStorage, Component, Entity, Storage, VecStorage};
 
 use crate::prelude::*;
 
@@ -8,3 +10,23 @@ use crate::prelude::*;
 pub struct AttackComponent {
     pub available_attacks: Vec<Attack>,
 }
+
+impl AttackComponent {
+    //A held weapon's attacks take over from whatever a creature can do with its bare hands
+    pub fn get_attacks<'b, A, M>(
+        entity: Entity,
+        atk: &'b Storage<AttackComponent, A>,
+        man: &Storage<ManipulatorComponent, M>,
+    ) -> &'b [Attack]
+    where
+        A: Deref<Target = MaskedStorage<AttackComponent>>,
+        M: Deref<Target = MaskedStorage<ManipulatorComponent>>,
+    {
+        man.get(entity)
+            .and_then(|man| man.held_item)
+            .and_then(|item| atk.get(item))
+            .or_else(|| atk.get(entity))
+            .map(|atk| atk.available_attacks.as_slice())
+            .unwrap_or_default()
+    }
+}
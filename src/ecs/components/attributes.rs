@@ -0,0 +1,74 @@
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+use crate::prelude::*;
+
+/// What a creature was born with, which practice can't change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct AttributesComponent {
+    pub strength: i32,
+    pub agility: i32,
+    pub perception: i32,
+}
+
+impl AttributesComponent {
+    //No two creatures of a species are quite alike
+    pub fn roll(species: Species) -> Self {
+        let base = species.get_attributes();
+        let mut rng = thread_rng();
+
+        Self {
+            strength: base.strength + rng.gen_range(-1..=1),
+            agility: base.agility + rng.gen_range(-1..=1),
+            perception: base.perception + rng.gen_range(-1..=1),
+        }
+    }
+
+    pub fn get(&self, attribute: Attribute) -> i32 {
+        match attribute {
+            Attribute::Strength => self.strength,
+            Attribute::Agility => self.agility,
+            Attribute::Perception => self.perception,
+        }
+    }
+
+    pub fn get_modifier(&self, attribute: Attribute) -> i32 {
+        Attribute::get_modifier(self.get(attribute))
+    }
+
+    //Keen eyes see further, but everyone can at least see what's next to them
+    pub fn get_sight_radius(&self, base_radius: u32) -> u32 {
+        (base_radius as i32 + self.get_modifier(Attribute::Perception)).max(1) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modifiers_round_down() {
+        let attributes = AttributesComponent {
+            strength: 14,
+            agility: 9,
+            perception: 10,
+        };
+
+        assert_eq!(attributes.get_modifier(Attribute::Strength), 2);
+        assert_eq!(attributes.get_modifier(Attribute::Agility), -1);
+        assert_eq!(attributes.get_modifier(Attribute::Perception), 0);
+    }
+
+    #[test]
+    fn test_perception_changes_sight() {
+        let attributes = AttributesComponent {
+            strength: 10,
+            agility: 10,
+            perception: 16,
+        };
+
+        assert_eq!(attributes.get_sight_radius(8), 11);
+    }
+}
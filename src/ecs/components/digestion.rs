@@ -1,7 +1,9 @@
-use std::convert::Infallible;
+use std::{convert::Infallible, ops::Deref};
 
 use serde::{Deserialize, Serialize};
-use specs::{saveload::ConvertSaveload, Component, Entity, ReadStorage, VecStorage};
+use specs::{
+    saveload::ConvertSaveload, storage::MaskedStorage, Component, Entity, Storage, VecStorage,
+};
 
 use crate::prelude::*;
 
@@ -9,10 +11,17 @@ use crate::prelude::*;
 #[storage(VecStorage)]
 pub struct DigestionComponent {
     pub contents: Vec<Entity>,
+    //Turns spent with an empty stomach
+    pub starvation: usize,
+    //Nutrition digested towards healing the next point of damage
+    pub healing_progress: usize,
 }
 
 impl DigestionComponent {
-    pub fn get_total_nutrition(&self, edc: &ReadStorage<EdibleComponent>) -> usize {
+    pub fn get_total_nutrition<D>(&self, edc: &Storage<EdibleComponent, D>) -> usize
+    where
+        D: Deref<Target = MaskedStorage<EdibleComponent>>,
+    {
         self.contents
             .iter()
             .map(|item| {
@@ -25,6 +34,17 @@ impl DigestionComponent {
             .sum()
     }
 
+    pub fn get_hunger_stage<D>(
+        &self,
+        edc: &Storage<EdibleComponent, D>,
+        metabolism: &Metabolism,
+    ) -> HungerStage
+    where
+        D: Deref<Target = MaskedStorage<EdibleComponent>>,
+    {
+        metabolism.get_hunger_stage(self.get_total_nutrition(edc), self.starvation)
+    }
+
     pub fn insert(&mut self, item: Entity) -> bool {
         self.contents.push(item);
 
@@ -47,6 +67,8 @@ impl DigestionComponent {
 #[derive(Serialize, Deserialize)]
 pub struct DigestionComponentData {
     pub contents: Vec<SaveMarkerComponent>,
+    pub starvation: usize,
+    pub healing_progress: usize,
 }
 
 impl ConvertSaveload<SaveMarkerComponent> for DigestionComponent {
@@ -59,6 +81,8 @@ impl ConvertSaveload<SaveMarkerComponent> for DigestionComponent {
     {
         Ok(DigestionComponentData {
             contents: self.contents.iter().map(|e| ids(*e).unwrap()).collect(),
+            starvation: self.starvation,
+            healing_progress: self.healing_progress,
         })
     }
 
@@ -68,6 +92,8 @@ impl ConvertSaveload<SaveMarkerComponent> for DigestionComponent {
     {
         Ok(Self {
             contents: data.contents.into_iter().map(|m| ids(m).unwrap()).collect(),
+            starvation: data.starvation,
+            healing_progress: data.healing_progress,
         })
     }
 }
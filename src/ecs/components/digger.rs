@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, NullStorage};
+
+//A marker component for tools that can break through rock, like picks
+#[derive(Clone, Default, Component, Serialize, Deserialize)]
+#[storage(NullStorage)]
+pub struct DiggerComponent;
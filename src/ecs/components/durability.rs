@@ -0,0 +1,103 @@
+use std::convert::Infallible;
+
+use serde::{Deserialize, Serialize};
+use specs::{saveload::ConvertSaveload, Component, Entity, VecStorage};
+
+use crate::prelude::*;
+
+/// Wears down with every use, and falls back apart into what it was made from once it's gone
+#[derive(Component)]
+#[storage(VecStorage)]
+pub struct DurabilityComponent {
+    pub value: u32,
+    pub max_value: u32,
+    pub quality: Quality,
+    pub parts: Vec<Entity>,
+}
+
+impl DurabilityComponent {
+    pub fn new(max_value: u32, quality: Quality, parts: Vec<Entity>) -> Self {
+        Self {
+            value: max_value,
+            max_value,
+            quality,
+            parts,
+        }
+    }
+
+    //Gives back true once there's nothing left to wear down
+    pub fn wear(&mut self, amount: u32) -> bool {
+        self.value = self.value.saturating_sub(amount);
+
+        self.is_broken()
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.value == 0
+    }
+
+    pub fn get_condition(&self) -> &str {
+        match self.value * 4 / self.max_value.max(1) {
+            _ if self.is_broken() => "broken",
+            0 => "nearly broken",
+            1 => "damaged",
+            2 => "worn",
+            _ => "good",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DurabilityComponentData {
+    pub value: u32,
+    pub max_value: u32,
+    pub quality: Quality,
+    pub parts: Vec<SaveMarkerComponent>,
+}
+
+impl ConvertSaveload<SaveMarkerComponent> for DurabilityComponent {
+    type Data = DurabilityComponentData;
+    type Error = Infallible;
+
+    fn convert_into<F>(&self, mut ids: F) -> Result<Self::Data, Self::Error>
+    where
+        F: FnMut(Entity) -> Option<SaveMarkerComponent>,
+    {
+        Ok(DurabilityComponentData {
+            value: self.value,
+            max_value: self.max_value,
+            quality: self.quality,
+            parts: self.parts.iter().map(|e| ids(*e).unwrap()).collect(),
+        })
+    }
+
+    fn convert_from<F>(data: Self::Data, mut ids: F) -> Result<Self, Self::Error>
+    where
+        F: FnMut(SaveMarkerComponent) -> Option<Entity>,
+    {
+        Ok(Self {
+            value: data.value,
+            max_value: data.max_value,
+            quality: data.quality,
+            parts: data.parts.into_iter().map(|m| ids(m).unwrap()).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wears_down_to_nothing() {
+        let mut dur = DurabilityComponent::new(8, Quality::Standard, Vec::new());
+
+        assert_eq!(dur.get_condition(), "good");
+        assert!(!dur.wear(3));
+        assert_eq!(dur.get_condition(), "worn");
+        assert!(!dur.wear(4));
+        assert_eq!(dur.get_condition(), "nearly broken");
+        assert!(dur.wear(4));
+        assert_eq!(dur.get_condition(), "broken");
+    }
+}
@@ -1,8 +1,18 @@
 use serde::{Deserialize, Serialize};
 use specs::{Component, VecStorage};
 
+use crate::prelude::*;
+
 #[derive(Clone, Component, Serialize, Deserialize)]
 #[storage(VecStorage)]
 pub struct EdibleComponent {
     pub nutrient_value: usize,
+    #[serde(default)]
+    pub nutrient_type: NutrientType,
+}
+
+impl EdibleComponent {
+    pub fn is_edible_by(&self, diet: &Diet) -> bool {
+        diet.can_consume(&self.nutrient_type)
+    }
 }
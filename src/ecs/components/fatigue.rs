@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+use crate::prelude::*;
+
+//How long a creature can stay up before it starts to feel it
+const DROWSY_FATIGUE: usize = 4 * TURNS_PER_HOUR as usize;
+const TIRED_FATIGUE: usize = 16 * TURNS_PER_HOUR as usize;
+const EXHAUSTED_FATIGUE: usize = 24 * TURNS_PER_HOUR as usize;
+
+#[derive(Clone, Default, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct FatigueComponent {
+    pub fatigue: usize,
+    //What will wake us, if we're asleep
+    pub sleep: Option<WakeCondition>,
+}
+
+impl FatigueComponent {
+    pub fn is_asleep(&self) -> bool {
+        self.sleep.is_some()
+    }
+
+    pub fn is_tired(&self) -> bool {
+        self.fatigue >= TIRED_FATIGUE
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.fatigue >= EXHAUSTED_FATIGUE
+    }
+
+    //Creatures outside their waking hours turn in as soon as they're a little drowsy
+    pub fn wants_sleep(&self, resting_hours: bool) -> bool {
+        self.is_tired() || (resting_hours && self.fatigue >= DROWSY_FATIGUE)
+    }
+
+    pub fn get_max_fatigue() -> usize {
+        EXHAUSTED_FATIGUE
+    }
+}
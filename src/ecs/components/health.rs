@@ -8,6 +8,9 @@ use crate::prelude::*;
 pub struct HealthComponent {
     pub hit_particle: Option<ParticleBuilder>,
     pub turn_damage: u32,
+    //Blows landed this turn, before armour has had its say
+    #[serde(skip)]
+    pub pending_hits: Vec<Damage>,
     pub value: u32,
     pub max_value: u32,
 }
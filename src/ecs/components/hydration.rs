@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+//How much a creature drinks in one go
+const DRINK_AMOUNT: usize = 50;
+
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct HydrationComponent {
+    pub value: usize,
+    pub max_value: usize,
+}
+
+impl HydrationComponent {
+    //Creatures stop drinking once they're nearly full, rather than wasting a last mouthful
+    pub fn is_quenched(&self) -> bool {
+        self.value * 8 >= self.max_value * 7
+    }
+
+    //Takes a mouthful from at most the available water, returning how much was drunk
+    pub fn drink(&mut self, available: usize) -> usize {
+        let drunk = self
+            .max_value
+            .saturating_sub(self.value)
+            .min(DRINK_AMOUNT)
+            .min(available);
+
+        self.value += drunk;
+
+        drunk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quenched_at_seven_eighths() {
+        let mut hyd = HydrationComponent {
+            value: 699,
+            max_value: 800,
+        };
+        assert!(!hyd.is_quenched());
+
+        hyd.value = 700;
+        assert!(hyd.is_quenched());
+    }
+
+    #[test]
+    fn test_drinking_takes_a_mouthful() {
+        let mut hyd = HydrationComponent {
+            value: 0,
+            max_value: 200,
+        };
+
+        assert_eq!(hyd.drink(usize::MAX), DRINK_AMOUNT);
+        assert_eq!(hyd.value, DRINK_AMOUNT);
+
+        assert_eq!(hyd.drink(10), 10);
+        assert_eq!(hyd.value, DRINK_AMOUNT + 10);
+
+        hyd.value = 190;
+        assert_eq!(hyd.drink(usize::MAX), 10);
+        assert_eq!(hyd.value, 200);
+    }
+}
@@ -8,4 +8,6 @@ use crate::prelude::*;
 pub struct IntendedMovementComponent {
     pub delta: IPosition,
     pub controlled: bool,
+    //Turns left before the entity has recovered from its last step
+    pub movement_delay: u32,
 }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+use crate::prelude::*;
+
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct LifeCycleComponent {
+    //Age in turns
+    pub age: u32,
+    //Turns left before this creature can have young again
+    pub breeding_cooldown: u32,
+}
+
+impl LifeCycleComponent {
+    pub fn is_juvenile(&self, species: Species) -> bool {
+        self.age < species.get_maturity_age()
+    }
+}
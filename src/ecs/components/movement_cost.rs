@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+//Marks an entity as passable, but slow to move through, ex. dense grass
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct MovementCostComponent {
+    pub cost: u32,
+}
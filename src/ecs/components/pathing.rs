@@ -1,4 +1,4 @@
-use std::{convert::TryFrom, ops::Not};
+use std::convert::TryFrom;
 
 use specs::{Component, ReadStorage, VecStorage};
 
@@ -18,12 +18,90 @@ impl PathingComponent {
     }
 
     ///Paths are returned backwards and should be treated as a stack
+    ///If the end is not loaded, the path only leads as far along the chunk route as is currently loaded
     pub fn pathfind(
         &mut self,
         tile_world: &TileWorldResource,
         start: IPosition,
         end: IPosition,
+        size: CreatureSize,
         collider: &ReadStorage<ColliderComponent>,
+        movement_cost: &ReadStorage<MovementCostComponent>,
+    ) -> Option<Vec<IPosition>> {
+        //Plan a coarse route first, this rules out unreachable ends without searching the whole buffer
+        let route = tile_world.chunk_graph.route(start, end)?;
+
+        let loaded_len = route
+            .iter()
+            .take_while(|node| tile_world.is_chunk_loaded(node.chunk_pos))
+            .count();
+
+        if loaded_len == 0 {
+            return None;
+        }
+
+        let goal = if loaded_len == route.len() {
+            end
+        } else {
+            //Refine up to the crossing into the first unloaded chunk on the route
+            tile_world
+                .chunk_graph
+                .crossings(route[loaded_len - 1], route[loaded_len])
+                .into_iter()
+                .filter(|pos| {
+                    tile_world.get(*pos).map_or(false, |chunk_tile| {
+                        !chunk_tile
+                            .entities
+                            .iter()
+                            .any(|entity| collider.get(*entity).is_some())
+                    })
+                })
+                .min_by_key(|pos| {
+                    let diff = end - *pos;
+                    diff.x * diff.x + diff.y * diff.y
+                })?
+        };
+
+        let corridor: Vec<_> = route[..loaded_len]
+            .iter()
+            .map(|node| node.chunk_pos)
+            .collect();
+
+        let path = self.pathfind_loaded(
+            tile_world,
+            start,
+            goal,
+            size,
+            collider,
+            movement_cost,
+            Some(&corridor),
+        );
+
+        if path.is_some() {
+            path
+        } else {
+            //Entities may block the corridor even though the tiles do not
+            self.pathfind_loaded(
+                tile_world,
+                start,
+                goal,
+                size,
+                collider,
+                movement_cost,
+                None,
+            )
+        }
+    }
+
+    fn pathfind_loaded(
+        &mut self,
+        tile_world: &TileWorldResource,
+        start: IPosition,
+        end: IPosition,
+        size: CreatureSize,
+        collider: &ReadStorage<ColliderComponent>,
+        movement_cost: &ReadStorage<MovementCostComponent>,
+        corridor: Option<&[IPosition]>,
     ) -> Option<Vec<IPosition>> {
         let loaded_offset = tile_world.offset * CHUNK_SIZE as i32;
 
@@ -52,14 +130,21 @@ impl PathingComponent {
 
                     let buffer_pos = loaded / u32::try_from(CHUNK_SIZE).unwrap();
                     let local_pos = loaded % u32::try_from(CHUNK_SIZE).unwrap();
-                    
+
+                    if let Some(corridor) = corridor {
+                        let chunk_pos = IPosition::try_from(buffer_pos).unwrap() + tile_world.offset;
+
+                        if !corridor.contains(&chunk_pos) {
+                            return None;
+                        }
+                    }
+
                     let chunk_tile = &buffer[TileWorldResource::buffer_idx(buffer_pos).unwrap()].tiles
                     [local_pos.to_idx().unwrap()];
-                    
-                    (chunk_tile.tile.tile_type.collides() || 
-                    chunk_tile.entities.iter().any(|entity| collider.get(*entity).is_some()))
-                    .not()
-                    .then(|| 1 + diff.x.abs() as u32 + diff.y.abs() as u32)
+
+                    chunk_tile
+                        .get_movement_cost(size, collider, movement_cost)
+                        .map(|cost| (1 + diff.x.abs() as u32 + diff.y.abs() as u32) * cost)
                 },
             )
             .map(|path| {
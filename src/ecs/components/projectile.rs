@@ -0,0 +1,26 @@
+use std::collections::VecDeque;
+
+use specs::{Component, Entity, VecStorage};
+
+use crate::prelude::*;
+
+/// An item in flight, which isn't anywhere in the tile world until it lands
+#[derive(Component)]
+#[storage(VecStorage)]
+pub struct ProjectileComponent {
+    pub pos: IPosition,
+    pub path: VecDeque<IPosition>,
+    pub flight_length: usize,
+    pub damage: Damage,
+}
+
+impl ProjectileComponent {
+    //Projectiles arc up and back down again over the course of their flight
+    pub fn get_height(&self) -> i32 {
+        let travelled = (self.flight_length - self.path.len()) as i32;
+        let remaining = self.path.len() as i32;
+        let length = self.flight_length.max(1) as i32;
+
+        (travelled * remaining * 8 / (length * length)).min(MAX_PARTICLE_HEIGHT)
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+/// Something that will go off and eventually disappear if left lying around
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct RotComponent {
+    pub remaining: u32,
+    pub rot_time: u32,
+}
+
+impl RotComponent {
+    pub fn new(rot_time: u32) -> Self {
+        Self {
+            remaining: rot_time,
+            rot_time,
+        }
+    }
+
+    //Past halfway it's too far gone to eat
+    pub fn is_rotten(&self) -> bool {
+        self.remaining < self.rot_time / 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotten_past_halfway() {
+        let mut rot = RotComponent::new(10);
+
+        assert!(!rot.is_rotten());
+
+        rot.remaining = 5;
+        assert!(!rot.is_rotten());
+
+        rot.remaining = 4;
+        assert!(rot.is_rotten());
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+use crate::prelude::*;
+
+/// Everything a creature has learned by doing it
+#[derive(Debug, Clone, Default, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct SkillsComponent {
+    pub experience: HashMap<Skill, u32>,
+}
+
+impl SkillsComponent {
+    pub fn get_experience(&self, skill: Skill) -> u32 {
+        self.experience.get(&skill).copied().unwrap_or_default()
+    }
+
+    pub fn get_level(&self, skill: Skill) -> u32 {
+        let experience = self.get_experience(skill);
+
+        (0..)
+            .take_while(|level| Skill::get_experience_for_level(level + 1) <= experience)
+            .count() as u32
+    }
+
+    //Gives back the new level if that was enough to reach one
+    pub fn practise(&mut self, skill: Skill, amount: u32) -> Option<u32> {
+        let before = self.get_level(skill);
+
+        *self.experience.entry(skill).or_default() += amount;
+
+        let after = self.get_level(skill);
+
+        if after > before {
+            Some(after)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_practice_makes_perfect() {
+        let mut skills = SkillsComponent::default();
+
+        assert_eq!(skills.get_level(Skill::Melee), 0);
+        assert_eq!(skills.practise(Skill::Melee, 9), None);
+        assert_eq!(skills.practise(Skill::Melee, 1), Some(1));
+        assert_eq!(skills.practise(Skill::Melee, 50), Some(3));
+        assert_eq!(skills.get_level(Skill::Throwing), 0);
+    }
+}
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+use crate::prelude::*;
+
+/// Ongoing conditions, which any system can apply or remove
+#[derive(Clone, Default, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct StatusEffectComponent {
+    pub effects: Vec<StatusEffect>,
+}
+
+impl StatusEffectComponent {
+    //Reapplying an effect keeps whichever lasts longer, rather than stacking
+    pub fn apply(&mut self, effect_type: StatusEffectType, duration: Option<u32>) -> bool {
+        if let Some(prevented_by) = effect_type.get_prevented_by() {
+            if self.has(prevented_by) {
+                return false;
+            }
+        }
+
+        if let Some(cancelled) = effect_type.get_cancelled() {
+            self.remove(cancelled);
+        }
+
+        if let Some(existing) = self
+            .effects
+            .iter_mut()
+            .find(|effect| effect.effect_type == effect_type)
+        {
+            existing.remaining = match (existing.remaining, duration) {
+                (Some(existing), Some(duration)) => Some(existing.max(duration)),
+                _ => None,
+            };
+
+            false
+        } else {
+            self.effects.push(StatusEffect {
+                effect_type,
+                remaining: duration,
+            });
+
+            true
+        }
+    }
+
+    pub fn remove(&mut self, effect_type: StatusEffectType) -> bool {
+        let before = self.effects.len();
+
+        self.effects
+            .retain(|effect| effect.effect_type != effect_type);
+
+        self.effects.len() != before
+    }
+
+    pub fn has(&self, effect_type: StatusEffectType) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| effect.effect_type == effect_type)
+    }
+
+    //Counts every timed effect down a turn, and gives back the ones that wore off
+    pub fn tick(&mut self) -> Vec<StatusEffectType> {
+        let mut expired = Vec::new();
+
+        for effect in self.effects.iter_mut() {
+            if let Some(remaining) = &mut effect.remaining {
+                *remaining = remaining.saturating_sub(1);
+
+                if *remaining == 0 {
+                    expired.push(effect.effect_type);
+                }
+            }
+        }
+
+        self.effects.retain(|effect| effect.remaining != Some(0));
+
+        expired
+    }
+
+    pub fn get_modifiers(&self) -> StatModifiers {
+        self.effects
+            .iter()
+            .map(|effect| effect.effect_type.get_modifiers())
+            .fold(StatModifiers::default(), |total, modifiers| {
+                total + modifiers
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reapplying_keeps_the_longer_duration() {
+        let mut sts = StatusEffectComponent::default();
+
+        assert!(sts.apply(StatusEffectType::Stunned, Some(3)));
+        assert!(!sts.apply(StatusEffectType::Stunned, Some(1)));
+        assert_eq!(sts.effects.len(), 1);
+        assert_eq!(sts.effects[0].remaining, Some(3));
+    }
+
+    #[test]
+    fn test_timed_effects_wear_off() {
+        let mut sts = StatusEffectComponent::default();
+
+        sts.apply(StatusEffectType::Stunned, Some(2));
+        sts.apply(StatusEffectType::Bleeding, None);
+
+        assert!(sts.tick().is_empty());
+        assert_eq!(sts.tick(), vec![StatusEffectType::Stunned]);
+        assert!(sts.has(StatusEffectType::Bleeding));
+        assert!(!sts.has(StatusEffectType::Stunned));
+    }
+
+    #[test]
+    fn test_water_puts_out_fire() {
+        let mut sts = StatusEffectComponent::default();
+
+        sts.apply(StatusEffectType::Burning, Some(5));
+        sts.apply(StatusEffectType::Wet, Some(5));
+
+        assert!(!sts.has(StatusEffectType::Burning));
+        assert!(!sts.apply(StatusEffectType::Burning, Some(5)));
+    }
+
+    #[test]
+    fn test_modifiers_stack() {
+        let mut sts = StatusEffectComponent::default();
+
+        sts.apply(StatusEffectType::Stunned, Some(2));
+        sts.apply(StatusEffectType::Wet, Some(2));
+
+        assert_eq!(sts.get_modifiers().accuracy, -5);
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, Entities, Entity, Join, ReadStorage, VecStorage};
+
+use crate::prelude::*;
+
+//Owners are remembered by id rather than by entity, so that saving a pet doesn't drag its owner along with it
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct TameComponent {
+    pub owner: u64,
+    //How many times the owner has fed this creature
+    pub feedings: u32,
+    pub tamed: bool,
+    pub order: PetOrder,
+}
+
+impl TameComponent {
+    pub fn is_owned_by(&self, entity: Entity, id: &ReadStorage<IdComponent>) -> bool {
+        self.tamed && id.get(entity).map_or(false, |id| id.id == self.owner)
+    }
+
+    pub fn find_owner(&self, entities: &Entities, id: &ReadStorage<IdComponent>) -> Option<Entity> {
+        if !self.tamed {
+            return None;
+        }
+
+        (entities, id)
+            .join()
+            .find(|(_, id)| id.id == self.owner)
+            .map(|(entity, _)| entity)
+    }
+}
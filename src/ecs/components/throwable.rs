@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, Entity, VecStorage};
+
+use crate::prelude::*;
+
+/// Made to be thrown, and does more than an improvised missile would
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct ThrowableComponent {
+    pub range: u32,
+    pub attack_dice: DiceRoll,
+    pub damage_type: DamageType,
+    pub material: Option<Material>,
+}
+
+impl ThrowableComponent {
+    //Anything else can still be thrown, just not very far or very hard
+    pub fn improvised(material: Option<Material>) -> Self {
+        Self {
+            range: 4,
+            attack_dice: DiceRoll::new_from_string("1d2"),
+            damage_type: DamageType::Blunt,
+            material,
+        }
+    }
+
+    pub fn roll_damage(&self, thrower: Entity) -> Damage {
+        Damage {
+            amount: self.attack_dice.roll(),
+            damage_type: self.damage_type,
+            material: self.material,
+            attacker: Some(thrower),
+        }
+    }
+}
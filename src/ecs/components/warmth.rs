@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+//Gives off heat to anything within radius
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct WarmthComponent {
+    pub radius: i32,
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use specs::{Component, VecStorage};
+
+#[derive(Clone, Component, Serialize, Deserialize)]
+#[storage(VecStorage)]
+pub struct WaterContainerComponent {
+    pub water: usize,
+    pub capacity: usize,
+}
+
+impl WaterContainerComponent {
+    pub fn is_full(&self) -> bool {
+        self.water >= self.capacity
+    }
+
+    pub fn refill(&mut self) {
+        self.water = self.capacity;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_drinking_empties_and_refilling_fills() {
+        let mut container = WaterContainerComponent {
+            water: 30,
+            capacity: 100,
+        };
+        let mut hyd = HydrationComponent {
+            value: 0,
+            max_value: 200,
+        };
+
+        container.water -= hyd.drink(container.water);
+        assert_eq!(container.water, 0);
+        assert_eq!(hyd.value, 30);
+        assert!(!container.is_full());
+
+        container.refill();
+        assert_eq!(container.water, 100);
+        assert!(container.is_full());
+    }
+}
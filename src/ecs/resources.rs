@@ -1,10 +1,16 @@
+pub mod clock;
+pub mod debug_view;
+pub mod flow_fields;
 pub mod gen_package;
 pub mod id_generator;
 pub mod keyboard;
+pub mod message_log;
 pub mod mouse;
 pub mod particle_map;
 pub mod pending_load;
+pub mod recipes;
 pub mod save_marker_allocator;
+pub mod sound;
 pub mod tile_world;
 pub mod viewport;
 pub mod weather;
@@ -0,0 +1,112 @@
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//How long a day lasts, and when the sun is up
+pub const TURNS_PER_HOUR: u64 = 60;
+const HOURS_PER_DAY: u64 = 24;
+const DAWN_HOUR: u64 = 6;
+const DUSK_HOUR: u64 = 20;
+
+/// The in-game time of day, counted in turns
+#[derive(Serialize, Deserialize)]
+pub struct ClockResource {
+    pub turn: u64,
+}
+
+impl ClockResource {
+    //The world starts at dawn on the first day
+    pub fn new() -> Self {
+        Self {
+            turn: DAWN_HOUR * TURNS_PER_HOUR,
+        }
+    }
+
+    //Picks the time back up from the save directory, if it was saved there
+    pub fn load() -> Self {
+        match fs::read(clock_filename()) {
+            Ok(save_buf) => deserialize_data(&save_buf),
+            Err(_) => Self::new(),
+        }
+    }
+
+    pub fn save(&self, save_buf: &mut Vec<u8>) {
+        save_buf.clear();
+        serialize_data(self, save_buf);
+
+        fs::write(clock_filename(), save_buf).unwrap();
+    }
+
+    pub fn tick(&mut self) {
+        self.turn += 1;
+    }
+
+    pub fn get_day(&self) -> u64 {
+        self.turn / (TURNS_PER_HOUR * HOURS_PER_DAY) + 1
+    }
+
+    pub fn get_hour(&self) -> u64 {
+        (self.turn / TURNS_PER_HOUR) % HOURS_PER_DAY
+    }
+
+    pub fn get_minute(&self) -> u64 {
+        (self.turn % TURNS_PER_HOUR) * 60 / TURNS_PER_HOUR
+    }
+
+    pub fn is_day(&self) -> bool {
+        (DAWN_HOUR..DUSK_HOUR).contains(&self.get_hour())
+    }
+
+    pub fn get_time_string(&self) -> String {
+        format!(
+            "Day {}, {:02}:{:02}",
+            self.get_day(),
+            self.get_hour(),
+            self.get_minute()
+        )
+    }
+}
+
+impl Default for ClockResource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_and_night_follow_the_clock() {
+        let mut clock = ClockResource::new();
+
+        assert!(clock.is_day());
+        assert_eq!(clock.get_time_string(), "Day 1, 06:00");
+
+        clock.turn = DUSK_HOUR * TURNS_PER_HOUR;
+        assert!(!clock.is_day());
+
+        clock.turn = (HOURS_PER_DAY + DAWN_HOUR) * TURNS_PER_HOUR - 1;
+        assert!(!clock.is_day());
+        assert_eq!(clock.get_day(), 2);
+
+        clock.tick();
+        assert!(clock.is_day());
+    }
+
+    #[test]
+    fn test_clock_survives_saving() {
+        let mut clock = ClockResource::new();
+        clock.turn += 12345;
+
+        let mut save_buf = Vec::new();
+        serialize_data(&clock, &mut save_buf);
+        let loaded: ClockResource = deserialize_data(&save_buf);
+
+        assert_eq!(loaded.turn, clock.turn);
+        assert_eq!(loaded.get_time_string(), clock.get_time_string());
+    }
+}
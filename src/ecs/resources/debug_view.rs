@@ -0,0 +1,24 @@
+use specs::Entity;
+
+/// Which creature the AI debug overlay is inspecting
+#[derive(Default)]
+pub struct DebugViewResource {
+    pub enabled: bool,
+    pub selected: Option<Entity>,
+}
+
+impl DebugViewResource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    //Candidates should be in a stable order so that cycling visits each of them in turn
+    pub fn select_next(&mut self, candidates: &[Entity]) {
+        let next_index = self
+            .selected
+            .and_then(|selected| candidates.iter().position(|candidate| *candidate == selected))
+            .map_or(0, |index| (index + 1) % candidates.len());
+
+        self.selected = candidates.get(next_index).copied();
+    }
+}
@@ -0,0 +1,38 @@
+use fxhash::FxHashMap;
+
+use crate::prelude::*;
+
+#[derive(Default)]
+pub struct FlowFieldResource {
+    pub fields: FxHashMap<FlowFieldTarget, FlowField>,
+    //The terrain the current fields were flooded over
+    pub terrain_revision: u32,
+}
+
+impl FlowFieldResource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, target: FlowFieldTarget) -> Option<&FlowField> {
+        self.fields.get(&target)
+    }
+
+    //Reuses the buffers of last turn's field where possible
+    pub fn get_or_insert(&mut self, target: FlowFieldTarget, origin: IPosition) -> &mut FlowField {
+        self.fields
+            .entry(target)
+            .or_insert_with(|| FlowField::new(origin, CHUNK_SIZE * 3, CHUNK_SIZE * 3))
+    }
+
+    //Only hands back the field if it has to be recalculated for these sources
+    pub fn get_outdated(
+        &mut self,
+        target: FlowFieldTarget,
+        origin: IPosition,
+        sources: &[IPosition],
+    ) -> Option<&mut FlowField> {
+        Some(self.get_or_insert(target, origin))
+            .filter(|field| !field.was_calculated_from(origin, sources))
+    }
+}
@@ -3,5 +3,14 @@ use ggez::event::{KeyCode, KeyMods};
 #[derive(Default)]
 pub struct KeyboardResource {
     pub last_pressed_key: Option<KeyCode>,
+    //Whatever was held down the tick before, to tell a fresh press from a key still being held
+    pub previous_key: Option<KeyCode>,
     pub modifiers: KeyMods,
 }
+
+impl KeyboardResource {
+    pub fn get_new_press(&self) -> Option<KeyCode> {
+        self.last_pressed_key
+            .filter(|key| self.previous_key != Some(*key))
+    }
+}
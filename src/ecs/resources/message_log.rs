@@ -0,0 +1,24 @@
+use std::collections::VecDeque;
+
+//Older messages are forgotten once there are more than this
+const MAX_MESSAGES: usize = 32;
+
+/// Messages for the player, newest last
+#[derive(Default)]
+pub struct MessageLogResource {
+    pub messages: VecDeque<String>,
+}
+
+impl MessageLogResource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, message: impl Into<String>) {
+        self.messages.push_back(message.into());
+
+        while self.messages.len() > MAX_MESSAGES {
+            self.messages.pop_front();
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::io::Read;
+
+use ggez::{Context, GameError, GameResult};
+use serde::Deserialize;
+
+use crate::prelude::*;
+
+//What settlers make themselves before anything else
+pub const SETTLER_TOOL_RECIPE: &str = "axe";
+//Recipes the game asks for by name, so the data file has to have them
+const REQUIRED_RECIPES: &[&str] = &[SETTLER_TOOL_RECIPE];
+
+#[derive(Deserialize)]
+struct RecipeFile {
+    recipe: Vec<Recipe>,
+}
+
+/// Every recipe known to the game, read in from the recipes data file at startup
+pub struct RecipeResource {
+    pub recipes: Vec<Recipe>,
+}
+
+impl RecipeResource {
+    pub fn load(ctx: &mut Context) -> GameResult<Self> {
+        let mut contents = String::new();
+
+        ggez::filesystem::open(ctx, "/recipes.toml")?.read_to_string(&mut contents)?;
+
+        Self::from_toml(&contents).map_err(|errors| {
+            GameError::ResourceLoadError(format!("Invalid recipes: {}", errors.join("; ")))
+        })
+    }
+
+    pub fn from_toml(contents: &str) -> Result<Self, Vec<String>> {
+        let file: RecipeFile = toml::from_str(contents).map_err(|error| vec![error.to_string()])?;
+
+        let resource = Self {
+            recipes: file.recipe,
+        };
+
+        let errors = resource.validate();
+
+        if errors.is_empty() {
+            Ok(resource)
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Recipe> {
+        self.recipes.iter().find(|recipe| recipe.name == name)
+    }
+
+    //Catches everything the file format itself can't, so a bad recipe stops the game from starting
+    //rather than turning up halfway through a craft
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        for name in REQUIRED_RECIPES {
+            if self.get(name).is_none() {
+                errors.push(format!("Recipe \"{}\" is needed but not defined", name));
+            }
+        }
+
+        for (index, recipe) in self.recipes.iter().enumerate() {
+            if self.recipes[..index]
+                .iter()
+                .any(|other| other.name == recipe.name)
+            {
+                errors.push(format!(
+                    "Recipe \"{}\" is defined more than once",
+                    recipe.name
+                ));
+            }
+
+            if recipe.get_slots().is_empty() {
+                errors.push(format!("Recipe \"{}\" has no ingredients", recipe.name));
+            }
+
+            let tools = recipe
+                .ingredients
+                .iter()
+                .filter(|ingredient| ingredient.role == IngredientRole::Tool);
+
+            if tools.clone().count() > 1 {
+                errors.push(format!(
+                    "Recipe \"{}\" needs more tools than can be held at once",
+                    recipe.name
+                ));
+            }
+
+            for tool in tools.filter(|tool| tool.count != 1) {
+                errors.push(format!(
+                    "Tool \"{}\" of recipe \"{}\" can only be held one at a time",
+                    tool.part_name, recipe.name
+                ));
+            }
+
+            for (index, ingredient) in recipe.ingredients.iter().enumerate() {
+                if ingredient.count == 0 {
+                    errors.push(format!(
+                        "Part \"{}\" of recipe \"{}\" needs a count of at least 1",
+                        ingredient.part_name, recipe.name
+                    ));
+                }
+
+                if recipe.ingredients[..index]
+                    .iter()
+                    .any(|other| other.part_name == ingredient.part_name)
+                {
+                    errors.push(format!(
+                        "Part \"{}\" of recipe \"{}\" is defined more than once",
+                        ingredient.part_name, recipe.name
+                    ));
+                }
+            }
+
+            //Only what goes into the result can lend it a material, and it has to have one to lend
+            for part_name in recipe.output.get_part_names() {
+                match recipe
+                    .get_slots()
+                    .into_iter()
+                    .find(|ingredient| ingredient.part_name == part_name)
+                {
+                    None => errors.push(format!(
+                        "Output of recipe \"{}\" takes after a \"{}\" it doesn't have",
+                        recipe.name, part_name
+                    )),
+                    Some(ingredient) if !ingredient.requirement.requires_material() => {
+                        errors.push(format!(
+                            "Part \"{}\" of recipe \"{}\" could be something with no material",
+                            part_name, recipe.name
+                        ))
+                    }
+                    Some(_) => (),
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_recipes_are_valid() {
+        let resource = RecipeResource::from_toml(include_str!("../../../resources/recipes.toml"))
+            .unwrap_or_else(|errors| panic!("{:?}", errors));
+
+        assert!(resource.get(SETTLER_TOOL_RECIPE).is_some());
+    }
+
+    #[test]
+    fn test_invalid_recipes_are_caught() {
+        let errors = RecipeResource::from_toml(
+            r#"
+            [[recipe]]
+            name = "knife"
+            output = { Knife = { blade = "blade", handle = "grip" } }
+
+            [[recipe.ingredients]]
+            part_name = "blade"
+            requirement = "Sharp"
+
+            [[recipe.ingredients]]
+            part_name = "binding"
+            count = 0
+            requirement = { Material = { material = "Hide" } }
+
+            [[recipe.ingredients]]
+            part_name = "hammer"
+            count = 2
+            role = "Tool"
+            requirement = { Hardness = { at_least = "Stone" } }
+
+            [[recipe.ingredients]]
+            part_name = "scraper"
+            role = "Tool"
+            requirement = { Not = "Flammable" }
+
+            [[recipe]]
+            name = "knife"
+            ingredients = []
+            output = "CampFire"
+
+            [[recipe]]
+            name = "shield"
+            output = { Shield = { board = "board" } }
+
+            [[recipe.ingredients]]
+            part_name = "board"
+            requirement = { Not = "Flammable" }
+
+            [[recipe.ingredients]]
+            part_name = "board"
+            requirement = "Flammable"
+            "#,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(
+            errors,
+            vec![
+                "Recipe \"axe\" is needed but not defined",
+                "Recipe \"knife\" needs more tools than can be held at once",
+                "Tool \"hammer\" of recipe \"knife\" can only be held one at a time",
+                "Part \"binding\" of recipe \"knife\" needs a count of at least 1",
+                "Output of recipe \"knife\" takes after a \"grip\" it doesn't have",
+                "Recipe \"knife\" is defined more than once",
+                "Recipe \"knife\" has no ingredients",
+                "Part \"board\" of recipe \"shield\" is defined more than once",
+                "Part \"board\" of recipe \"shield\" could be something with no material",
+            ]
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use specs::Entity;
+
+use crate::prelude::*;
+
+/// Sounds made this turn, waiting to be heard
+#[derive(Default)]
+pub struct SoundResource {
+    pub sounds: Vec<Sound>,
+}
+
+impl SoundResource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn emit(&mut self, pos: IPosition, sound_type: SoundType, size: CreatureSize, emitter: Option<Entity>) {
+        self.sounds.push(Sound {
+            pos,
+            sound_type,
+            loudness: sound_type.get_loudness(size),
+            emitter,
+        });
+    }
+}
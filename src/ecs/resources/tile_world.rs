@@ -1,12 +1,20 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fs};
 
-use specs::{Entity, WriteStorage};
+use log::warn;
+use specs::{Entity, ReadStorage, WriteStorage};
 
 use crate::prelude::*;
 
+//How close walls need to be for somewhere to count as sheltered, and how many of them it takes
+const SHELTER_RADIUS: i32 = 3;
+const SHELTER_WALLS_NEEDED: usize = 5;
+
 pub struct TileWorldResource {
     pub offset: IPosition,
     pub buffer: [Chunk; 9],
+    pub chunk_graph: ChunkGraph,
+    //Goes up whenever the loaded terrain changes, so anything worked out from it knows to redo it
+    pub terrain_revision: u32,
 }
 
 impl TileWorldResource {
@@ -37,7 +45,12 @@ impl TileWorldResource {
             }
         }
 
-        let mut world = Self { offset, buffer };
+        let mut world = Self {
+            offset,
+            buffer,
+            chunk_graph: ChunkGraph::new(),
+            terrain_revision: 0,
+        };
 
         let top_left = IPosition::global_from_local(offset, UPosition::ZERO);
         let bottom_right =
@@ -49,9 +62,49 @@ impl TileWorldResource {
             }
         }
 
+        world.refresh_loaded_chunk_connectivity();
+        world.load_saved_chunk_connectivity();
+
         world
     }
 
+    //Routes can lead through chunks that were saved to disk before this world was created,
+    //so the chunk graph is rebuilt from whatever chunks are already saved
+    fn load_saved_chunk_connectivity(&mut self) {
+        let entries = match fs::read_dir(save_path()) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if let Some(chunk_pos) = chunk_pos_from_filename(&path) {
+                if self.is_chunk_loaded(chunk_pos) {
+                    continue;
+                }
+
+                //A bad chunk file only costs routes through that chunk, not the whole game
+                let save_buf = match fs::read(&path) {
+                    Ok(save_buf) => save_buf,
+                    Err(err) => {
+                        warn!("Skipping unreadable chunk file {:?}: {}", path, err);
+                        continue;
+                    }
+                };
+
+                match rmp_serde::decode::from_read_ref::<_, SavedChunk>(&save_buf) {
+                    Ok(saved_chunk) => self.chunk_graph.update_chunk(chunk_pos, &saved_chunk.chunk),
+                    Err(err) => warn!("Skipping corrupt chunk file {:?}: {}", path, err),
+                }
+            }
+        }
+    }
+
+    pub fn is_chunk_loaded(&self, chunk_pos: IPosition) -> bool {
+        let buffer_pos = chunk_pos - self.offset;
+
+        (0..3).contains(&buffer_pos.x) && (0..3).contains(&buffer_pos.y)
+    }
+
     pub fn get(&self, pos: IPosition) -> Option<&ChunkTile> {
         let (chunk_pos, local_pos) = pos.global_to_local();
         let buffer_pos = chunk_pos - self.offset;
@@ -86,6 +139,36 @@ impl TileWorldResource {
         }
     }
 
+    //Recompute the chunk graph node for the chunk containing this position
+    pub fn refresh_chunk_connectivity(&mut self, pos: IPosition) {
+        let (chunk_pos, _) = pos.global_to_local();
+
+        if self.is_chunk_loaded(chunk_pos) {
+            let buffer_pos = UPosition::try_from(chunk_pos - self.offset).unwrap();
+
+            self.terrain_revision = self.terrain_revision.wrapping_add(1);
+
+            self.chunk_graph
+                .update_chunk(chunk_pos, &self.buffer[Self::buffer_idx(buffer_pos).unwrap()]);
+        } else {
+            println!("Cannot refresh connectivity of unloaded chunk {:?}", chunk_pos);
+        }
+    }
+
+    pub fn refresh_loaded_chunk_connectivity(&mut self) {
+        self.terrain_revision = self.terrain_revision.wrapping_add(1);
+
+        for buffer_x in 0..3 {
+            for buffer_y in 0..3 {
+                let buffer_pos = UPosition::new(buffer_x, buffer_y);
+                let chunk_pos = IPosition::try_from(buffer_pos).unwrap() + self.offset;
+
+                self.chunk_graph
+                    .update_chunk(chunk_pos, &self.buffer[Self::buffer_idx(buffer_pos).unwrap()]);
+            }
+        }
+    }
+
     pub fn refresh_tile_and_adjacent_variants(&mut self, pos: IPosition) {
         self.refresh_tile_variant(pos.up());
         self.refresh_tile_variant(pos.left());
@@ -94,6 +177,80 @@ impl TileWorldResource {
         self.refresh_tile_variant(pos.down());
     }
 
+    /// The strongest scent matching the predicate on or next to a position, and where it is
+    pub fn get_strongest_scent(
+        &self,
+        pos: IPosition,
+        predicate: impl Fn(Species) -> bool,
+    ) -> Option<(IPosition, Scent)> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| IPosition::new(dx, dy)))
+            .map(|diff| pos + diff)
+            .filter_map(|scent_pos| {
+                self.get(scent_pos)?
+                    .scents
+                    .iter()
+                    .filter(|scent| predicate(scent.species))
+                    .max_by_key(|scent| scent.strength)
+                    .map(|scent| (scent_pos, *scent))
+            })
+            .max_by_key(|(_, scent)| scent.strength)
+    }
+
+    /// The closest tile next to the target that a creature could stand on to work on it
+    pub fn get_standing_position(
+        &self,
+        target: IPosition,
+        from: IPosition,
+        size: CreatureSize,
+        collider: &ReadStorage<ColliderComponent>,
+        movement_cost: &ReadStorage<MovementCostComponent>,
+    ) -> Option<IPosition> {
+        if from.is_adjacent(target) {
+            return Some(from);
+        }
+
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| IPosition::new(dx, dy)))
+            .map(|diff| target + diff)
+            .filter(|neighbour| *neighbour != target)
+            .filter(|neighbour| {
+                self.get(*neighbour)
+                    .and_then(|tile| tile.get_movement_cost(size, collider, movement_cost))
+                    .is_some()
+            })
+            .min_by_key(|neighbour| {
+                let diff = *neighbour - from;
+                diff.x * diff.x + diff.y * diff.y
+            })
+    }
+
+    pub fn is_sheltered(&self, pos: IPosition) -> bool {
+        (-SHELTER_RADIUS..=SHELTER_RADIUS)
+            .flat_map(|dy| (-SHELTER_RADIUS..=SHELTER_RADIUS).map(move |dx| IPosition::new(dx, dy)))
+            .filter_map(|offset| self.get(pos + offset))
+            .filter(|chunk_tile| {
+                matches!(chunk_tile.tile.tile_type, TileType::ConstructedWall { .. })
+            })
+            .count()
+            >= SHELTER_WALLS_NEEDED
+    }
+
+    pub fn is_water(&self, pos: IPosition) -> bool {
+        self.get(pos)
+            .map_or(false, |chunk_tile| chunk_tile.tile.tile_type == TileType::Water)
+    }
+
+    pub fn get_water_positions(&self) -> Vec<IPosition> {
+        let top_left = IPosition::global_from_local(self.offset, UPosition::ZERO);
+        let size = CHUNK_SIZE as i32 * 3;
+
+        (top_left.y..top_left.y + size)
+            .flat_map(|y| (top_left.x..top_left.x + size).map(move |x| IPosition::new(x, y)))
+            .filter(|pos| self.is_water(*pos))
+            .collect()
+    }
+
     //Get neighbour tile types in (u, d, l, r) order
     pub fn get_neighbours(&self, pos: IPosition) -> [Option<TileType>; 4] {
         [
@@ -1,3 +1,5 @@
+pub mod character_data;
+pub mod combat_data;
 pub mod crafting_data;
 pub mod goal_data;
 pub mod input_data;
@@ -0,0 +1,53 @@
+use crate::prelude::*;
+use specs::prelude::*;
+
+#[derive(SystemData)]
+pub struct CharacterData<'a> {
+    //Read components
+    pub attributes: ReadStorage<'a, AttributesComponent>,
+
+    //Write components
+    pub skills: WriteStorage<'a, SkillsComponent>,
+}
+
+impl<'a> CharacterData<'a> {
+    //Creatures without attributes are treated as thoroughly average
+    pub fn get_modifier(&self, entity: Entity, attribute: Attribute) -> i32 {
+        self.attributes
+            .get(entity)
+            .map_or(0, |attributes| attributes.get_modifier(attribute))
+    }
+
+    pub fn get_level(&self, entity: Entity, skill: Skill) -> u32 {
+        self.skills
+            .get(entity)
+            .map_or(0, |skills| skills.get_level(skill))
+    }
+
+    //How long a job takes someone with this entity's practice and knack for it
+    pub fn get_duration(&self, entity: Entity, skill: Skill, base_turns: u32) -> u32 {
+        Skill::get_duration(
+            base_turns,
+            self.get_level(entity, skill),
+            self.get_modifier(entity, skill.get_attribute()),
+        )
+    }
+
+    //Agility helps both ways, but only practice teaches where to put a blow
+    pub fn get_combat_modifiers(&self, entity: Entity) -> StatModifiers {
+        let agility = self.get_modifier(entity, Attribute::Agility);
+
+        StatModifiers {
+            accuracy: agility + self.get_level(entity, Skill::Melee) as i32,
+            evasion: agility,
+            movement_delay: 0,
+        }
+    }
+
+    //Gives back the new level if the entity learned enough to reach one
+    pub fn practise(&mut self, entity: Entity, skill: Skill) -> Option<u32> {
+        self.skills
+            .get_mut(entity)
+            .and_then(|skills| skills.practise(skill, 1))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use specs::prelude::*;
+
+#[derive(SystemData)]
+pub struct CombatData<'a> {
+    //Read components
+    pub anatomy: ReadStorage<'a, AnatomyComponent>,
+    pub attack: ReadStorage<'a, AttackComponent>,
+    pub armour: ReadStorage<'a, ArmourComponent>,
+    pub name: ReadStorage<'a, NameComponent>,
+
+    //Write components
+    pub durability: WriteStorage<'a, DurabilityComponent>,
+    pub status_effect: WriteStorage<'a, StatusEffectComponent>,
+}
+
+impl<'a> CombatData<'a> {
+    //What to call an entity in combat messages
+    pub fn get_name(&self, entity: Entity) -> &str {
+        self.name
+            .get(entity)
+            .map_or("something", |name| name.name.as_str())
+    }
+
+    //Every use takes a little out of a tool, though only the durability system breaks it
+    pub fn wear(&mut self, item: Option<Entity>) {
+        if let Some(dur) = item.and_then(|item| self.durability.get_mut(item)) {
+            dur.wear(1);
+        }
+    }
+}
@@ -4,5 +4,12 @@ use specs::prelude::*;
 #[derive(SystemData)]
 pub struct CraftingData<'a> {
     //Read components
+    pub butcherable: ReadStorage<'a, ButcherableComponent>,
+    pub digger: ReadStorage<'a, DiggerComponent>,
     pub material: ReadStorage<'a, MaterialComponent>,
+    pub rot: ReadStorage<'a, RotComponent>,
+    pub warmth: ReadStorage<'a, WarmthComponent>,
+
+    //Resources
+    pub recipes: ReadExpect<'a, RecipeResource>,
 }
@@ -12,21 +12,34 @@ pub struct GoalData<'a> {
 
     //Resources
     pub tile_world: ReadExpect<'a, TileWorldResource>,
+    pub flow_fields: ReadExpect<'a, FlowFieldResource>,
+    pub message_log: WriteExpect<'a, MessageLogResource>,
+    pub clock: ReadExpect<'a, ClockResource>,
 
     //Read Components
     pub attack: ReadStorage<'a, AttackComponent>,
     pub collider: ReadStorage<'a, ColliderComponent>,
+    pub death: ReadStorage<'a, DeathComponent>,
     pub edible: ReadStorage<'a, EdibleComponent>,
+    pub fatigue: ReadStorage<'a, FatigueComponent>,
     pub field_of_view: ReadStorage<'a, FieldOfViewComponent>,
     pub digestion: ReadStorage<'a, DigestionComponent>,
+    pub durability: ReadStorage<'a, DurabilityComponent>,
     pub health: ReadStorage<'a, HealthComponent>,
+    pub hydration: ReadStorage<'a, HydrationComponent>,
+    pub id: ReadStorage<'a, IdComponent>,
     pub input: ReadStorage<'a, InputComponent>,
     pub inventory: ReadStorage<'a, InventoryComponent>,
+    pub item: ReadStorage<'a, ItemComponent>,
     pub manipulator: ReadStorage<'a, ManipulatorComponent>,
+    pub movement_cost: ReadStorage<'a, MovementCostComponent>,
     pub name: ReadStorage<'a, NameComponent>,
     pub perception: ReadStorage<'a, AIPerceptionComponent>,
     pub personality: ReadStorage<'a, AIPersonalityComponent>,
     pub position: ReadStorage<'a, PositionComponent>,
+    pub species: ReadStorage<'a, SpeciesComponent>,
+    pub tame: ReadStorage<'a, TameComponent>,
+    pub water_container: ReadStorage<'a, WaterContainerComponent>,
 
     //Write components
     pub pathing: WriteStorage<'a, PathingComponent>,
@@ -7,28 +7,41 @@ pub struct InputData<'a> {
     pub entities: Entities<'a>,
     pub lazy: Read<'a, LazyUpdate>,
 
+    //Included system data
+    pub crafting_data: CraftingData<'a>,
+
     //Resources
     pub keyboard: ReadExpect<'a, KeyboardResource>,
     pub mouse: ReadExpect<'a, MouseResource>,
     pub tile_world: ReadExpect<'a, TileWorldResource>,
+    pub message_log: WriteExpect<'a, MessageLogResource>,
 
     //Read components
+    pub attack: ReadStorage<'a, AttackComponent>,
+    pub attributes: ReadStorage<'a, AttributesComponent>,
     pub butcherable: ReadStorage<'a, ButcherableComponent>,
     pub collider: ReadStorage<'a, ColliderComponent>,
     pub collision: ReadStorage<'a, CollisionComponent>,
     pub death: ReadStorage<'a, DeathComponent>,
     pub draw: ReadStorage<'a, DrawComponent>,
+    pub edible: ReadStorage<'a, EdibleComponent>,
     pub health: ReadStorage<'a, HealthComponent>,
     pub intended_movement: ReadStorage<'a, IntendedMovementComponent>,
     pub item: ReadStorage<'a, ItemComponent>,
     pub manipulation: ReadStorage<'a, ManipulatorComponent>,
+    pub movement_cost: ReadStorage<'a, MovementCostComponent>,
     pub name: ReadStorage<'a, NameComponent>,
     pub particle: ReadStorage<'a, ParticleComponent>,
     pub position: ReadStorage<'a, PositionComponent>,
+    pub skills: ReadStorage<'a, SkillsComponent>,
+    pub species: ReadStorage<'a, SpeciesComponent>,
+    pub throwable: ReadStorage<'a, ThrowableComponent>,
     pub velocity: ReadStorage<'a, VelocityComponent>,
+    pub water_container: ReadStorage<'a, WaterContainerComponent>,
 
     //Write components
     pub ai_goal: WriteStorage<'a, AIGoalComponent>,
+    pub fatigue: WriteStorage<'a, FatigueComponent>,
     pub input: WriteStorage<'a, InputComponent>,
     pub inventory: WriteStorage<'a, InventoryComponent>,
     pub pathing: WriteStorage<'a, PathingComponent>,
@@ -4,20 +4,27 @@ use specs::prelude::*;
 #[derive(SystemData)]
 pub struct RenderData<'a> {
     //Resources
+    pub debug_view: ReadExpect<'a, DebugViewResource>,
     pub keyboard: ReadExpect<'a, KeyboardResource>,
+    pub message_log: ReadExpect<'a, MessageLogResource>,
+    pub clock: ReadExpect<'a, ClockResource>,
     pub tile_world: ReadExpect<'a, TileWorldResource>,
     pub particle_map: ReadExpect<'a, ParticleMapResource>,
 
     //Read components
+    pub anatomy: ReadStorage<'a, AnatomyComponent>,
     pub butcherable: ReadStorage<'a, ButcherableComponent>,
     pub collider: ReadStorage<'a, ColliderComponent>,
     pub collision: ReadStorage<'a, CollisionComponent>,
     pub death: ReadStorage<'a, DeathComponent>,
     pub digestion: ReadStorage<'a, DigestionComponent>,
     pub draw: ReadStorage<'a, DrawComponent>,
+    pub durability: ReadStorage<'a, DurabilityComponent>,
     pub edible: ReadStorage<'a, EdibleComponent>,
+    pub fatigue: ReadStorage<'a, FatigueComponent>,
     pub field_of_view: ReadStorage<'a, FieldOfViewComponent>,
     pub health: ReadStorage<'a, HealthComponent>,
+    pub hydration: ReadStorage<'a, HydrationComponent>,
     pub intended_movement: ReadStorage<'a, IntendedMovementComponent>,
     pub inventory: ReadStorage<'a, InventoryComponent>,
     pub item: ReadStorage<'a, ItemComponent>,
@@ -25,7 +32,23 @@ pub struct RenderData<'a> {
     pub name: ReadStorage<'a, NameComponent>,
     pub particle: ReadStorage<'a, ParticleComponent>,
     pub position: ReadStorage<'a, PositionComponent>,
+    pub species: ReadStorage<'a, SpeciesComponent>,
+    pub status_effect: ReadStorage<'a, StatusEffectComponent>,
     pub velocity: ReadStorage<'a, VelocityComponent>,
+    pub ai_action: ReadStorage<'a, AIActionComponent>,
     pub ai_goal: ReadStorage<'a, AIGoalComponent>,
+    pub ai_perception: ReadStorage<'a, AIPerceptionComponent>,
     pub input: ReadStorage<'a, InputComponent>,
 }
+
+impl<'a> RenderData<'a> {
+    //An item's name, along with how worn it is if it can wear out at all
+    pub fn get_item_label(&self, item: Entity) -> String {
+        let name = self.name.get(item).unwrap().name.as_str();
+
+        match self.durability.get(item) {
+            Some(dur) => format!("{} ({})", name, dur.get_condition()),
+            None => String::from(name),
+        }
+    }
+}
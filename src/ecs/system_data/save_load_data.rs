@@ -110,24 +110,40 @@ save_load! {
     pub struct SaveLoadData<'a> {
         pub ai_action: WriteStorage<'a, AIActionComponent>,
         pub ai_goal: WriteStorage<'a, AIGoalComponent>,
+        pub anatomy: WriteStorage<'a, AnatomyComponent>,
+        pub armour: WriteStorage<'a, ArmourComponent>,
         pub attack: WriteStorage<'a, AttackComponent>,
+        pub attributes: WriteStorage<'a, AttributesComponent>,
         pub butcherable: WriteStorage<'a, ButcherableComponent>,
         pub collider: WriteStorage<'a, ColliderComponent>,
         pub collision: WriteStorage<'a, CollisionComponent>,
         pub death: WriteStorage<'a, DeathComponent>,
         pub digesion: WriteStorage<'a, DigestionComponent>,
+        pub digger: WriteStorage<'a, DiggerComponent>,
         pub draw: WriteStorage<'a, DrawComponent>,
+        pub durability: WriteStorage<'a, DurabilityComponent>,
         pub edible: WriteStorage<'a, EdibleComponent>,
+        pub fatigue: WriteStorage<'a, FatigueComponent>,
         pub health: WriteStorage<'a, HealthComponent>,
+        pub hydration: WriteStorage<'a, HydrationComponent>,
         pub intended_movement: WriteStorage<'a, IntendedMovementComponent>,
         pub inventory: WriteStorage<'a, InventoryComponent>,
         pub item: WriteStorage<'a, ItemComponent>,
         pub id: WriteStorage<'a, IdComponent>,
+        pub life_cycle: WriteStorage<'a, LifeCycleComponent>,
         pub manipulator: WriteStorage<'a, ManipulatorComponent>,
         pub material: WriteStorage<'a, MaterialComponent>,
+        pub movement_cost: WriteStorage<'a, MovementCostComponent>,
         pub name: WriteStorage<'a, NameComponent>,
         pub particle_emitter: WriteStorage<'a, ParticleEmitterComponent>,
         pub position: WriteStorage<'a, PositionComponent>,
+        pub rot: WriteStorage<'a, RotComponent>,
+        pub skills: WriteStorage<'a, SkillsComponent>,
+        pub status_effect: WriteStorage<'a, StatusEffectComponent>,
+        pub tame: WriteStorage<'a, TameComponent>,
+        pub throwable: WriteStorage<'a, ThrowableComponent>,
         pub velocity: WriteStorage<'a, VelocityComponent>,
+        pub warmth: WriteStorage<'a, WarmthComponent>,
+        pub water_container: WriteStorage<'a, WaterContainerComponent>,
     }
 }
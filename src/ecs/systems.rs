@@ -2,15 +2,25 @@ pub mod action_resolution;
 pub mod collision_calculation;
 pub mod collision_resolution;
 pub mod digestion_resolution;
+pub mod durability;
+pub mod fatigue_resolution;
 pub mod field_of_view_calculation;
+pub mod flow_field_calculation;
 pub mod goal_resolution;
 pub mod health_resolution;
+pub mod hearing_calculation;
+pub mod hydration_resolution;
 pub mod input_resolution;
+pub mod life_cycle;
 pub mod movement_resolution;
 pub mod particle;
 pub mod particle_emitter;
 pub mod perception_resolution;
+pub mod projectile;
 pub mod propagation;
+pub mod rot;
 pub mod save_load;
+pub mod scent_decay;
+pub mod status_effect;
 pub mod weather;
 pub mod world_maintenance;
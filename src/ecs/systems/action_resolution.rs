@@ -1,8 +1,21 @@
+use std::collections::{HashMap, VecDeque};
+
 use rand::prelude::*;
-use specs::{Builder, Entities, Join, LazyUpdate, Read, System, WriteExpect, WriteStorage};
+use specs::{
+    Builder, Entities, Entity, Join, LazyUpdate, Read, ReadStorage, System, WriteExpect,
+    WriteStorage,
+};
 
 use crate::prelude::*;
 
+//How many turns a critical hit leaves its target stunned for
+//Effects start wearing off at the end of the turn they land, so this costs the target its next two
+const CRITICAL_STUN_DURATION: u32 = 3;
+//How many turns jobs take someone with no practice at them
+const CRAFT_TURNS: u32 = 6;
+const BUILD_TURNS: u32 = 10;
+const DIG_TURNS: u32 = 15;
+
 pub struct ActionResolutionSystem;
 
 impl<'a> System<'a> for ActionResolutionSystem {
@@ -10,7 +23,17 @@ impl<'a> System<'a> for ActionResolutionSystem {
         Entities<'a>,
         Read<'a, LazyUpdate>,
         CraftingData<'a>,
+        CombatData<'a>,
+        CharacterData<'a>,
         WriteExpect<'a, TileWorldResource>,
+        WriteExpect<'a, SoundResource>,
+        WriteExpect<'a, IdGeneratorResource>,
+        WriteExpect<'a, MessageLogResource>,
+        ReadStorage<'a, SpeciesComponent>,
+        ReadStorage<'a, EdibleComponent>,
+        ReadStorage<'a, AIPersonalityComponent>,
+        ReadStorage<'a, InputComponent>,
+        ReadStorage<'a, ThrowableComponent>,
         WriteStorage<'a, PositionComponent>,
         WriteStorage<'a, AIActionComponent>,
         WriteStorage<'a, IntendedMovementComponent>,
@@ -18,6 +41,11 @@ impl<'a> System<'a> for ActionResolutionSystem {
         WriteStorage<'a, HealthComponent>,
         WriteStorage<'a, DigestionComponent>,
         WriteStorage<'a, ManipulatorComponent>,
+        WriteStorage<'a, IdComponent>,
+        WriteStorage<'a, TameComponent>,
+        WriteStorage<'a, HydrationComponent>,
+        WriteStorage<'a, WaterContainerComponent>,
+        WriteStorage<'a, FatigueComponent>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
@@ -25,7 +53,17 @@ impl<'a> System<'a> for ActionResolutionSystem {
             eids,
             lup,
             crd,
+            mut cbt,
+            mut chr,
             mut twld,
+            mut snd,
+            mut idg,
+            mut mlg,
+            spc,
+            edb,
+            pers,
+            inp,
+            thr,
             mut pos,
             mut act,
             mut imc,
@@ -33,10 +71,68 @@ impl<'a> System<'a> for ActionResolutionSystem {
             mut hpc,
             mut dig,
             mut man,
+            mut idc,
+            mut tam,
+            mut hyd,
+            mut wtc,
+            mut ftg,
         ) = data;
 
+        //Everyone's defences are settled before anyone swings, so the order doesn't matter
+        let defences: HashMap<Entity, CombatStats> = (&eids, &hpc, (&man).maybe())
+            .join()
+            .map(|(eid, _, man)| {
+                let asleep = ftg.get(eid).map_or(false, |ftg| ftg.is_asleep());
+                let mut stats = match spc.get(eid) {
+                    Some(spc) if !asleep => CombatStats::new(spc.species.get_size()),
+                    _ => CombatStats::helpless(),
+                };
+
+                if let Some(ana) = cbt.anatomy.get(eid) {
+                    stats = stats.hampered(ana.get_movement_penalty());
+                }
+
+                if let Some(sts) = cbt.status_effect.get(eid) {
+                    stats = stats.modified_by(sts.get_modifiers());
+                }
+
+                if !asleep {
+                    stats = stats.modified_by(chr.get_combat_modifiers(eid));
+                }
+
+                if let Some(shield) = man
+                    .and_then(|man| man.held_item)
+                    .and_then(|item| cbt.armour.get(item))
+                {
+                    stats = stats.with_shield(shield.material);
+                }
+
+                (eid, stats)
+            })
+            .collect();
+
         for (eid, act, imc, man) in (&eids, &mut act, &mut imc, (&mut man).maybe()).join() {
             let current_action = &mut act.current_action;
+            let size = spc.get(eid).map(|spc| spc.species.get_size()).unwrap_or_default();
+
+            //Creatures without a personality aren't picky
+            let fits_diet_of = |eater: Entity, food: Entity| {
+                edb.get(food).map_or(false, |edible| {
+                    pers.get(eater)
+                        .map_or(true, |pers| edible.is_edible_by(&pers.diet))
+                })
+            };
+            let fits_diet = |food: Entity| fits_diet_of(eid, food);
+            let is_player = inp.get(eid).is_some();
+
+            //Stunned creatures lose their turn, but hang on to whatever they meant to do
+            if cbt
+                .status_effect
+                .get(eid)
+                .map_or(false, |sts| sts.has(StatusEffectType::Stunned))
+            {
+                continue;
+            }
 
             //TODO: check for interruptions and then cancel action if it's interrupted
             if let Some(action) = current_action.take() {
@@ -44,21 +140,35 @@ impl<'a> System<'a> for ActionResolutionSystem {
 
                 act.current_action = match action {
                     AIAction::MoveInDirection { offset } => {
-                        imc.delta = offset;
-                        imc.controlled = true;
-                        None
+                        if imc.movement_delay > 0 {
+                            //Still wading through the last tile, try again next turn
+                            imc.movement_delay -= 1;
+                            Some(AIAction::MoveInDirection { offset })
+                        } else {
+                            imc.delta = offset;
+                            imc.controlled = true;
+                            None
+                        }
                     }
                     AIAction::AttackInDirection {
                         direction,
                         attack,
+                        swing_direction,
                         attack_offsets,
                     } => {
                         let mut final_action = None;
 
                         let this_pos = pos.get(eid).unwrap();
 
-                        let mut attack_offsets = attack_offsets
-                            .unwrap_or_else(|| attack.attack_type.get_offsets(&direction, None));
+                        //Settle on which way to swing up front, so every tile of it is drawn the same way
+                        let swing_direction =
+                            swing_direction.unwrap_or_else(RotationDirection::get_random);
+
+                        let mut attack_offsets = attack_offsets.unwrap_or_else(|| {
+                            attack
+                                .attack_type
+                                .get_offsets(&direction, Some(swing_direction))
+                        });
 
                         if attack_offsets.len() > 0 {
                             let offset = attack_offsets.pop().unwrap();
@@ -66,23 +176,45 @@ impl<'a> System<'a> for ActionResolutionSystem {
                             let attack_tile = twld.get(attack_pos);
 
                             if let Some(attack_tile) = attack_tile {
-                                for entity in &attack_tile.entities {
-                                    if let Some(target_hp) = &mut hpc.get_mut(*entity) {
-                                        target_hp.turn_damage += attack.attack_dice.roll();
-                                        break;
+                                let target = attack_tile
+                                    .entities
+                                    .iter()
+                                    .copied()
+                                    .find(|entity| hpc.get(*entity).is_some());
+
+                                if let Some(target) = target {
+                                    let damage = roll_attack(
+                                        eid, target, &attack, &defences, &mut cbt, &mut chr, &inp,
+                                        &mut mlg,
+                                    );
+
+                                    if let Some(damage) = damage {
+                                        hpc.get_mut(target).unwrap().pending_hits.push(damage);
                                     }
+
+                                    cbt.wear(man.as_ref().and_then(|man| man.held_item));
+
+                                    snd.emit(attack_pos, SoundType::Combat, size, Some(eid));
                                 }
 
+                                let direction_from_player =
+                                    Direction::from_positions(offset, IPosition::ZERO);
+
                                 lup.create_entity(&eids)
                                     .with(ParticleComponent {
                                         position: attack_pos,
                                         height: 0,
-                                        particle_type: ParticleType::Thrust {
-                                            drawn: false,
-                                            direction_from_player: Direction::from_positions(
-                                                offset,
-                                                IPosition::ZERO,
-                                            ),
+                                        particle_type: if attack.attack_type.is_rotational() {
+                                            ParticleType::Swing {
+                                                drawn: false,
+                                                direction_from_player,
+                                                rotation_direction: swing_direction,
+                                            }
+                                        } else {
+                                            ParticleType::Thrust {
+                                                drawn: false,
+                                                direction_from_player,
+                                            }
                                         },
                                     })
                                     .build();
@@ -90,6 +222,7 @@ impl<'a> System<'a> for ActionResolutionSystem {
                                 final_action = Some(AIAction::AttackInDirection {
                                     direction,
                                     attack,
+                                    swing_direction: Some(swing_direction),
                                     attack_offsets: Some(attack_offsets),
                                 });
                             }
@@ -97,7 +230,7 @@ impl<'a> System<'a> for ActionResolutionSystem {
 
                         final_action
                     }
-                    AIAction::AttackEntity { target } => {
+                    AIAction::AttackEntity { target, attack } => {
                         //Will crash if attempting to attack a target without a position
                         let target_pos = pos.get(target).unwrap();
                         let this_pos = pos.get(eid).unwrap();
@@ -107,7 +240,18 @@ impl<'a> System<'a> for ActionResolutionSystem {
                             //Will crash if attempting to attack a target that has no health component
                             if let Some(target_hp) = &mut hpc.get_mut(target) {
                                 if target_hp.value > 0 {
-                                    target_hp.turn_damage += 1;
+                                    let damage = roll_attack(
+                                        eid, target, &attack, &defences, &mut cbt, &mut chr, &inp,
+                                        &mut mlg,
+                                    );
+
+                                    if let Some(damage) = damage {
+                                        target_hp.pending_hits.push(damage);
+                                    }
+
+                                    cbt.wear(man.as_ref().and_then(|man| man.held_item));
+
+                                    snd.emit(target_pos.pos, SoundType::Combat, size, Some(eid));
                                 }
                             } else {
                                 println!(
@@ -126,6 +270,16 @@ impl<'a> System<'a> for ActionResolutionSystem {
                                     if entity_position.pos == item_position.pos {
                                         if inventory.insert(item) {
                                             twld.despawn_entity(item, &mut pos);
+
+                                            if edb.get(item).is_some() {
+                                                practise(
+                                                    eid,
+                                                    Skill::Foraging,
+                                                    &mut chr,
+                                                    &inp,
+                                                    &mut mlg,
+                                                );
+                                            }
                                         } else {
                                             println!("Entity failed to stow item in inventory!");
                                         }
@@ -189,7 +343,13 @@ impl<'a> System<'a> for ActionResolutionSystem {
                         None
                     }
                     AIAction::HoldItemFromInventory { item } => {
-                        if let Some(man) = man {
+                        if !cbt.anatomy.get(eid).map_or(true, |ana| ana.can_grasp()) {
+                            println!("Entity attempting to hold item with no working arms!");
+
+                            if is_player {
+                                mlg.push("Your arms are too badly hurt to hold anything!");
+                            }
+                        } else if let Some(man) = man {
                             if man.held_item.is_none() {
                                 if let Some(inv) = inv.get_mut(eid) {
                                     if inv.remove(item) {
@@ -211,7 +371,13 @@ impl<'a> System<'a> for ActionResolutionSystem {
                         None
                     }
                     AIAction::EatItemFromInventory { item } => {
-                        if let Some(dig) = dig.get_mut(eid) {
+                        if !fits_diet(item) {
+                            println!("Entity attempting to eat something that doesn't fit its diet!");
+
+                            if is_player {
+                                mlg.push("You can't eat that!");
+                            }
+                        } else if let Some(dig) = dig.get_mut(eid) {
                             if let Some(inv) = inv.get_mut(eid) {
                                 assert!(inv.remove(item));
                                 dig.insert(item);
@@ -220,7 +386,13 @@ impl<'a> System<'a> for ActionResolutionSystem {
                         None
                     }
                     AIAction::EatFromGround { target } => {
-                        if let Some(dig) = dig.get_mut(eid) {
+                        if !fits_diet(target) {
+                            println!("Entity attempting to eat something that doesn't fit its diet!");
+
+                            if is_player {
+                                mlg.push("You can't eat that!");
+                            }
+                        } else if let Some(dig) = dig.get_mut(eid) {
                             if let Some(this_pos) = pos.get(eid) {
                                 if let Some(entity_pos) = pos.get(target) {
                                     if this_pos.pos.is_adjacent_or_same(entity_pos.pos) {
@@ -240,11 +412,28 @@ impl<'a> System<'a> for ActionResolutionSystem {
                         }
                         None
                     }
+                    AIAction::BuildAtLocation {
+                        pos: build_pos,
+                        tile_type,
+                        consumed_entity,
+                    } if act.work_done + 1
+                        < chr.get_duration(eid, Skill::Building, BUILD_TURNS) =>
+                    {
+                        act.work_done += 1;
+
+                        Some(AIAction::BuildAtLocation {
+                            pos: build_pos,
+                            tile_type,
+                            consumed_entity,
+                        })
+                    }
                     AIAction::BuildAtLocation {
                         pos: build_pos,
                         tile_type,
                         consumed_entity,
                     } => {
+                        act.work_done = 0;
+
                         if let Some(chunk_tile) = twld.get(build_pos) {
                             if let Some(pos) = pos.get(eid) {
                                 if pos.pos.is_adjacent(build_pos) {
@@ -287,11 +476,27 @@ impl<'a> System<'a> for ActionResolutionSystem {
                                                         twld.refresh_tile_and_adjacent_variants(
                                                             build_pos,
                                                         );
+                                                        twld.refresh_chunk_connectivity(build_pos);
+
+                                                        snd.emit(
+                                                            build_pos,
+                                                            SoundType::Building,
+                                                            size,
+                                                            Some(eid),
+                                                        );
 
                                                         // tile.tile_type = *tile_type;
                                                         inv.items[item_index] = None;
 
                                                         eids.delete(consumed_entity).unwrap();
+
+                                                        practise(
+                                                            eid,
+                                                            Skill::Building,
+                                                            &mut chr,
+                                                            &inp,
+                                                            &mut mlg,
+                                                        );
                                                         // If entity is adjacent, despawn from entity map
                                                     } else {
                                                         println!("Entity attempting to build with items that do not fulfill the material requirements");
@@ -334,31 +539,453 @@ impl<'a> System<'a> for ActionResolutionSystem {
                         None
                     }
 
+                    AIAction::Craft {
+                        recipe,
+                        ingredients,
+                    } if act.work_done + 1
+                        < chr.get_duration(eid, Skill::Crafting, CRAFT_TURNS) =>
+                    {
+                        act.work_done += 1;
+
+                        Some(AIAction::Craft {
+                            recipe,
+                            ingredients,
+                        })
+                    }
                     AIAction::Craft {
                         recipe,
                         ingredients,
                     } => {
-                        if let Some(ent_pos) = pos.get(eid) {
-                            if let Some(inv) = inv.get_mut(eid) {
-                                match recipe.craft(&ingredients, &lup, &eids, &crd) {
+                        act.work_done = 0;
+
+                        if let Some(ent_pos) = pos.get(eid).map(|pos| pos.pos) {
+                            //Ingredients can be carried, held or lying close by
+                            let out_of_reach = ingredients.iter().copied().find(|item| {
+                                let carried = inv
+                                    .get(eid)
+                                    .map_or(false, |inv| inv.items.contains(&Some(*item)));
+                                let held =
+                                    man.as_ref().map_or(false, |man| man.held_item == Some(*item));
+                                let nearby = pos.get(*item).map_or(false, |item_pos| {
+                                    ent_pos.is_adjacent_or_same(item_pos.pos)
+                                });
+
+                                !(carried || held || nearby)
+                            });
+
+                            let missing_workstation = recipe.workstation.filter(|workstation| {
+                                !workstation.is_nearby(ent_pos, &twld, &crd)
+                            });
+
+                            if let Some(item) = out_of_reach {
+                                println!(
+                                    "Entity attempted to craft with an ingredient out of reach"
+                                );
+
+                                if is_player {
+                                    mlg.push(format!(
+                                        "You can't reach the {}.",
+                                        cbt.get_name(item)
+                                    ));
+                                }
+                            } else if let Some(workstation) = missing_workstation {
+                                println!("Entity attempted to craft away from its workstation");
+
+                                if is_player {
+                                    mlg.push(format!(
+                                        "You need to be by a {} to make a {}.",
+                                        workstation.get_name(),
+                                        recipe.name
+                                    ));
+                                }
+                            } else if let Some(tool) = recipe.get_missing_tool(
+                                man.as_ref().and_then(|man| man.held_item),
+                                &ingredients,
+                                &crd,
+                            ) {
+                                println!("Entity attempted to craft without a tool in hand");
+
+                                if is_player {
+                                    mlg.push(format!(
+                                        "You need to be holding {} to use as the {}.",
+                                        tool.describe(),
+                                        tool.part_name
+                                    ));
+                                }
+                            } else {
+                                let quality = Quality::roll(
+                                    chr.get_level(eid, Skill::Crafting),
+                                    chr.get_modifier(eid, Skill::Crafting.get_attribute()),
+                                );
+
+                                match recipe.craft(&ingredients, quality, &lup, &eids, &crd) {
                                     Ok(crafted_entity) => {
+                                        //The recipe has already used up or kept hold of them,
+                                        //they just need taking from wherever they were
                                         for item in ingredients {
-                                            inv.remove(item);
-                                            eids.delete(item).unwrap();
+                                            if let Some(inv) = inv.get_mut(eid) {
+                                                inv.remove(item);
+                                            }
+
+                                            if let Some(man) = man.as_mut() {
+                                                if man.held_item == Some(item) {
+                                                    man.held_item = None;
+                                                }
+                                            }
+
+                                            if pos.get(item).is_some() {
+                                                twld.despawn_entity(item, &mut pos);
+                                            }
+                                        }
+
+                                        twld.spawn_entity(crafted_entity, ent_pos, &mut pos);
+
+                                        if is_player {
+                                            mlg.push(format!(
+                                                "You finish the {}{}.",
+                                                quality.get_prefix(),
+                                                recipe.name
+                                            ));
                                         }
 
-                                        twld.spawn_entity(crafted_entity, ent_pos.pos, &mut pos);
+                                        practise(eid, Skill::Crafting, &mut chr, &inp, &mut mlg);
                                     }
 
-                                    Err(err) => println!("Crafting error: {}", err),
+                                    Err(err) => {
+                                        println!("Crafting error: {}", err);
+
+                                        if is_player {
+                                            mlg.push(format!(
+                                                "You can't make a {} out of those.",
+                                                recipe.name
+                                            ));
+                                        }
+                                    }
                                 }
-                            } else {
-                                println!("Entity attempted to craft without inventory");
                             }
                         } else {
                             println!("Entity attempted to craft without position");
                         }
 
+                        None
+                    }
+                    AIAction::FeedEntity { target, item } => {
+                        let reachable = match (pos.get(eid), pos.get(target)) {
+                            (Some(this_pos), Some(target_pos)) => {
+                                this_pos.pos.is_adjacent(target_pos.pos)
+                            }
+                            _ => false,
+                        };
+
+                        if !reachable {
+                            println!("Entity attempting to feed something it cannot reach!");
+                        } else if !fits_diet_of(target, item) {
+                            println!("Entity attempting to feed something with food it won't eat!");
+
+                            if is_player {
+                                mlg.push(format!("The {} won't eat that!", cbt.get_name(target)));
+                            }
+                        } else if let (Some(inv), Some(target_dig)) =
+                            (inv.get_mut(eid), dig.get_mut(target))
+                        {
+                            if inv.remove(item) {
+                                target_dig.insert(item);
+
+                                //Wild creatures come to trust whoever keeps feeding them
+                                if let Some(feedings_to_tame) = spc
+                                    .get(target)
+                                    .and_then(|spc| spc.species.get_feedings_to_tame())
+                                {
+                                    let feeder_id = if let Some(id) = idc.get(eid) {
+                                        id.id
+                                    } else {
+                                        let id = idg.generate();
+                                        idc.insert(eid, IdComponent { id }).unwrap();
+                                        id
+                                    };
+
+                                    if tam
+                                        .get(target)
+                                        .map_or(false, |tame| tame.owner == feeder_id)
+                                    {
+                                        tam.get_mut(target).unwrap().feedings += 1;
+                                    } else {
+                                        tam.insert(
+                                            target,
+                                            TameComponent {
+                                                owner: feeder_id,
+                                                feedings: 1,
+                                                tamed: false,
+                                                order: PetOrder::Follow,
+                                            },
+                                        )
+                                        .unwrap();
+                                    }
+
+                                    let tame = tam.get_mut(target).unwrap();
+
+                                    if !tame.tamed && tame.feedings >= feedings_to_tame {
+                                        tame.tamed = true;
+                                        println!("Entity has been tamed!");
+                                    }
+                                }
+                            } else {
+                                println!("Entity attempting to feed with an item that is not in its inventory!");
+                            }
+                        } else {
+                            println!("Entity attempting to feed without an inventory, or feeding something that cannot eat!");
+                        }
+
+                        None
+                    }
+                    AIAction::CommandPets { order } => {
+                        if let Some(id) = idc.get(eid) {
+                            for tame in (&mut tam)
+                                .join()
+                                .filter(|tame| tame.tamed && tame.owner == id.id)
+                            {
+                                tame.order = order;
+                            }
+                        } else {
+                            println!("Entity attempting to command pets despite having none!");
+                        }
+
+                        None
+                    }
+                    AIAction::Drink { source } => {
+                        if let Some(hyd) = hyd.get_mut(eid) {
+                            match source {
+                                DrinkSource::Tile { pos: water_pos } => {
+                                    if !twld.is_water(water_pos) {
+                                        println!("Entity attempting to drink from a tile with no water!");
+                                    } else if pos.get(eid).map_or(false, |this_pos| {
+                                        this_pos.pos.is_adjacent_or_same(water_pos)
+                                    }) {
+                                        hyd.drink(usize::MAX);
+                                    } else {
+                                        println!("Entity attempting to drink water that it cannot reach!");
+                                    }
+                                }
+                                DrinkSource::Container { item } => {
+                                    if !inv.get(eid).map_or(false, |inv| inv.contains(item)) {
+                                        println!("Entity attempting to drink from a container it isn't carrying!");
+                                    } else if let Some(container) = wtc.get_mut(item) {
+                                        container.water -= hyd.drink(container.water);
+                                    } else {
+                                        println!("Entity attempting to drink from something that doesn't hold water!");
+                                    }
+                                }
+                            }
+                        } else {
+                            println!("Entity attempting to drink despite having no hydration component!");
+                        }
+
+                        None
+                    }
+                    AIAction::FillContainer {
+                        item,
+                        pos: water_pos,
+                    } => {
+                        if !twld.is_water(water_pos) {
+                            println!("Entity attempting to fill a container from a tile with no water!");
+                        } else if !pos.get(eid).map_or(false, |this_pos| {
+                            this_pos.pos.is_adjacent_or_same(water_pos)
+                        }) {
+                            println!("Entity attempting to fill a container from water that it cannot reach!");
+                        } else if !inv.get(eid).map_or(false, |inv| inv.contains(item)) {
+                            println!("Entity attempting to fill a container it isn't carrying!");
+                        } else if let Some(container) = wtc.get_mut(item) {
+                            container.refill();
+                        } else {
+                            println!("Entity attempting to fill something that doesn't hold water!");
+                        }
+
+                        None
+                    }
+                    AIAction::Sleep { wake_condition } => {
+                        if let Some(ftg) = ftg.get_mut(eid) {
+                            ftg.sleep = Some(wake_condition);
+
+                            if is_player {
+                                mlg.push("You fall asleep.");
+                            }
+                        } else {
+                            println!("Entity attempting to sleep despite having no fatigue component!");
+                        }
+
+                        None
+                    }
+                    AIAction::Throw { item, target } => {
+                        if let (Some(man), Some(this_pos)) = (man, pos.get(eid)) {
+                            if man.held_item == Some(item) {
+                                //Anything can be thrown, but only some things are made for it
+                                let throwable = thr.get(item).cloned().unwrap_or_else(|| {
+                                    ThrowableComponent::improvised(
+                                        crd.material.get(item).map(|mat| mat.material),
+                                    )
+                                });
+
+                                //Practice carries a throw further, and strength makes it hurt more
+                                let range = throwable.range + chr.get_level(eid, Skill::Throwing);
+                                let strength = chr.get_modifier(eid, Attribute::Strength);
+
+                                let path: VecDeque<IPosition> = this_pos
+                                    .pos
+                                    .line_to(target)
+                                    .into_iter()
+                                    .take(range as usize)
+                                    .collect();
+
+                                if path.is_empty() {
+                                    println!("Entity attempting to throw an item at itself!");
+                                } else {
+                                    man.held_item = None;
+
+                                    lup.insert(
+                                        item,
+                                        ProjectileComponent {
+                                            pos: this_pos.pos,
+                                            flight_length: path.len(),
+                                            path,
+                                            damage: throwable.roll_damage(eid).boosted_by(strength),
+                                        },
+                                    );
+
+                                    practise(eid, Skill::Throwing, &mut chr, &inp, &mut mlg);
+
+                                    if is_player {
+                                        mlg.push("You throw it.");
+                                    }
+                                }
+                            } else {
+                                println!("Entity attempting to throw an item it isn't holding!");
+                            }
+                        } else {
+                            println!("Entity attempting to throw without a manipulator or position!");
+                        }
+
+                        None
+                    }
+                    AIAction::Butcher { target } => {
+                        let reachable = match (pos.get(eid), pos.get(target)) {
+                            (Some(this_pos), Some(target_pos)) => {
+                                this_pos.pos.is_adjacent_or_same(target_pos.pos)
+                            }
+                            _ => false,
+                        };
+                        let can_cut = man
+                            .as_ref()
+                            .and_then(|man| man.held_item)
+                            .and_then(|item| cbt.attack.get(item))
+                            .or_else(|| cbt.attack.get(eid))
+                            .map_or(false, |atk| {
+                                atk.available_attacks.iter().any(|attack| attack.cuts())
+                            });
+
+                        if !reachable {
+                            println!("Entity attempting to butcher something it cannot reach!");
+                        } else if !can_cut {
+                            println!("Entity attempting to butcher without anything to cut with!");
+
+                            if is_player {
+                                mlg.push("You need a blade to butcher with.");
+                            }
+                        } else if let Some(btc) = crd.butcherable.get(target) {
+                            let target_pos = pos.get(target).unwrap().pos;
+                            let rotten = crd.rot.get(target).map_or(false, |rot| rot.is_rotten());
+
+                            for item in btc.yielded_entities.iter().copied() {
+                                //Hide and bone keep, but the meat goes off along with the carcass
+                                if rotten && edb.get(item).is_some() {
+                                    lup.insert(item, crd.rot.get(target).unwrap().clone());
+                                    lup.insert(
+                                        item,
+                                        NameComponent {
+                                            name: format!("rotting {}", cbt.get_name(item)),
+                                        },
+                                    );
+                                }
+
+                                twld.spawn_entity(item, target_pos, &mut pos);
+                            }
+
+                            if is_player {
+                                mlg.push(if rotten {
+                                    "You butcher the carcass, but the meat has spoiled."
+                                } else {
+                                    "You butcher the carcass."
+                                });
+                            }
+
+                            twld.despawn_entity(target, &mut pos);
+                            eids.delete(target).unwrap();
+
+                            cbt.wear(man.as_ref().and_then(|man| man.held_item));
+                        } else {
+                            println!("Entity attempting to butcher something that can't be butchered!");
+                        }
+
+                        None
+                    }
+                    AIAction::Dig { pos: dig_pos }
+                        if act.work_done + 1 < chr.get_duration(eid, Skill::Digging, DIG_TURNS) =>
+                    {
+                        act.work_done += 1;
+
+                        Some(AIAction::Dig { pos: dig_pos })
+                    }
+                    AIAction::Dig { pos: dig_pos } => {
+                        act.work_done = 0;
+
+                        let held_item = man.as_ref().and_then(|man| man.held_item);
+                        let reachable = pos
+                            .get(eid)
+                            .map_or(false, |this_pos| this_pos.pos.is_adjacent(dig_pos));
+
+                        if !reachable {
+                            println!("Entity attempting to dig somewhere it cannot reach!");
+                        } else if !held_item.map_or(false, |item| crd.digger.get(item).is_some()) {
+                            println!("Entity attempting to dig without anything to dig with!");
+                        } else if let Some(chunk_tile) = twld.get(dig_pos) {
+                            if let TileType::Wall { material } = chunk_tile.tile.tile_type {
+                                twld.get_mut(dig_pos).unwrap().tile = Tile {
+                                    seed: thread_rng().gen::<usize>(),
+                                    fertility: chunk_tile.tile.fertility,
+                                    tile_type: TileType::Ground,
+                                    tile_variant: TileVariant::get_from_neighbours(
+                                        twld.get_neighbours(dig_pos),
+                                    ),
+                                };
+
+                                twld.refresh_tile_and_adjacent_variants(dig_pos);
+                                twld.refresh_chunk_connectivity(dig_pos);
+
+                                snd.emit(dig_pos, SoundType::Building, size, Some(eid));
+
+                                //Rock comes away in lumps that can be used again
+                                if material == Material::Stone {
+                                    let stone = ItemBuilder::Stone.build(&lup, &eids);
+                                    twld.spawn_entity(stone, dig_pos, &mut pos);
+                                }
+
+                                if is_player {
+                                    mlg.push(format!(
+                                        "You dig through the {} wall.",
+                                        material.get_name()
+                                    ));
+                                }
+
+                                cbt.wear(held_item);
+                                practise(eid, Skill::Digging, &mut chr, &inp, &mut mlg);
+                            } else {
+                                println!(
+                                    "Entity attempting to dig through something that isn't rock!"
+                                );
+                            }
+                        } else {
+                            println!("Entity attempting to dig in an unloaded tile!");
+                        }
+
                         None
                     }
                 }
@@ -366,3 +993,64 @@ impl<'a> System<'a> for ActionResolutionSystem {
         }
     }
 }
+
+//Rolls whether an attack lands, tells the player how it went, and gives back any damage dealt
+fn roll_attack(
+    attacker: Entity,
+    target: Entity,
+    attack: &Attack,
+    defences: &HashMap<Entity, CombatStats>,
+    cbt: &mut CombatData,
+    chr: &mut CharacterData,
+    inp: &ReadStorage<InputComponent>,
+    mlg: &mut MessageLogResource,
+) -> Option<Damage> {
+    let attacker_stats = defences.get(&attacker).copied().unwrap_or_default();
+    let target_stats = defences.get(&target).copied().unwrap_or_default();
+    let outcome = attacker_stats.resolve_attack(attack, &target_stats);
+
+    println!("Attack outcome: {:?}", outcome);
+
+    if inp.get(attacker).is_some() {
+        if let Some(message) = outcome.get_attacker_message(cbt.get_name(target), &attack.name) {
+            mlg.push(message);
+        }
+    } else if inp.get(target).is_some() {
+        if let Some(message) = outcome.get_defender_message(cbt.get_name(attacker), &attack.name)
+        {
+            mlg.push(message);
+        }
+    }
+
+    //A clean blow leaves the target reeling
+    if outcome == AttackOutcome::Critical {
+        if let Some(sts) = cbt.status_effect.get_mut(target) {
+            sts.apply(StatusEffectType::Stunned, Some(CRITICAL_STUN_DURATION));
+        }
+    }
+
+    practise(attacker, Skill::Melee, chr, inp, mlg);
+
+    let strength = chr.get_modifier(attacker, Attribute::Strength);
+
+    outcome.apply(attack.roll_damage(attacker).boosted_by(strength))
+}
+
+//Lets the player know when all that practice has paid off
+fn practise(
+    entity: Entity,
+    skill: Skill,
+    chr: &mut CharacterData,
+    inp: &ReadStorage<InputComponent>,
+    mlg: &mut MessageLogResource,
+) {
+    if let Some(level) = chr.practise(entity, skill) {
+        if inp.get(entity).is_some() {
+            mlg.push(format!(
+                "Your {} skill has improved to {}.",
+                skill.get_name(),
+                level
+            ));
+        }
+    }
+}
@@ -1,41 +1,113 @@
 use rand::prelude::*;
-use specs::{Entities, Join, System, WriteStorage};
+use specs::{Entities, Join, ReadStorage, System, WriteExpect, WriteStorage};
 
 use crate::prelude::*;
 
+//How long a good meal keeps a creature in good spirits once it stops being sated
+const WELL_FED_DURATION: u32 = 200;
+//How long a creature stays sick after the last of something rotten has gone through it
+const POISONED_DURATION: u32 = 100;
+
 pub struct DigestionResolutionSystem;
 
 impl<'a> System<'a> for DigestionResolutionSystem {
     type SystemData = (
         Entities<'a>,
+        WriteExpect<'a, MessageLogResource>,
+        ReadStorage<'a, SpeciesComponent>,
+        ReadStorage<'a, InputComponent>,
+        ReadStorage<'a, RotComponent>,
         WriteStorage<'a, HealthComponent>,
         WriteStorage<'a, DigestionComponent>,
         WriteStorage<'a, EdibleComponent>,
+        WriteStorage<'a, StatusEffectComponent>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (eids, mut hpc, mut dig, mut edc) = data;
+        let (eids, mut mlg, spc, inp, rot, mut hpc, mut dig, mut edc, mut sts) = data;
+
+        for (eid, hpc, dig) in (&eids, &mut hpc, &mut dig).join() {
+            let metabolism = spc
+                .get(eid)
+                .map(|spc| spc.species.get_metabolism())
+                .unwrap_or_default();
+            let is_player = inp.get(eid).is_some();
+            let previous_stage = dig.get_hunger_stage(&edc, &metabolism);
 
-        for (hpc, dig) in (&mut hpc, &mut dig).join() {
-            if thread_rng().gen::<bool>() {
-                if let Some(edible) = dig.contents.first() {
-                    let mut nutrient = edc.get_mut(*edible).unwrap();
+            if previous_stage == HungerStage::Overfull {
+                println!("Entity has overeaten and is vomiting");
+
+                for item in dig.contents.drain(..) {
+                    eids.delete(item).unwrap();
+                }
+
+                hpc.turn_damage += 1;
+
+                if is_player {
+                    mlg.push("You ate too much and threw up!");
+                }
+
+                continue;
+            }
+
+            //Anything rotten keeps its eater sick for as long as it takes to get through them
+            if dig
+                .contents
+                .iter()
+                .any(|item| rot.get(*item).map_or(false, |rot| rot.is_rotten()))
+            {
+                if let Some(sts) = sts.get_mut(eid) {
+                    if sts.apply(StatusEffectType::Poisoned, Some(POISONED_DURATION)) && is_player {
+                        mlg.push("You feel sick.");
+                    }
+                }
+            }
+
+            if let Some(edible) = dig.contents.first().copied() {
+                dig.starvation = 0;
+
+                if thread_rng().gen_range(0..metabolism.burn_interval) == 0 {
+                    let mut nutrient = edc.get_mut(edible).unwrap();
 
                     nutrient.nutrient_value -= 1;
 
                     //TODO: assert that nutrients have no position, and that they're not in the map
                     if nutrient.nutrient_value == 0 {
-                        eids.delete(*edible).unwrap();
+                        eids.delete(edible).unwrap();
                         dig.contents.remove(0);
                     }
 
+                    //Healing is paid for out of what gets digested
                     if hpc.value < hpc.max_value {
-                        hpc.value += 1;
+                        dig.healing_progress += 1;
+
+                        if dig.healing_progress >= metabolism.healing_cost {
+                            dig.healing_progress = 0;
+                            hpc.value += 1;
+                        }
                     }
-                } else {
+                }
+            } else {
+                dig.starvation += 1;
+            }
+
+            let stage = dig.get_hunger_stage(&edc, &metabolism);
+
+            if stage == HungerStage::Sated {
+                if let Some(sts) = sts.get_mut(eid) {
+                    sts.apply(StatusEffectType::WellFed, Some(WELL_FED_DURATION));
+                }
+            }
+
+            if let Some(damage_chance) = stage.get_damage_chance() {
+                if thread_rng().gen_range(0..damage_chance) == 0 {
                     hpc.turn_damage += 1;
                 }
             }
+
+            if is_player && stage != previous_stage && stage >= HungerStage::Hungry {
+                mlg.push(format!("You are {}!", stage.get_name()));
+            }
         }
     }
 }
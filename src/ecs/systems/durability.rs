@@ -0,0 +1,94 @@
+use specs::{
+    Entities, Entity, Join, LazyUpdate, Read, ReadStorage, System, WriteExpect, WriteStorage,
+};
+
+use crate::prelude::*;
+
+pub struct DurabilitySystem;
+
+impl<'a> System<'a> for DurabilitySystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, LazyUpdate>,
+        WriteExpect<'a, TileWorldResource>,
+        WriteExpect<'a, MessageLogResource>,
+        ReadStorage<'a, DurabilityComponent>,
+        ReadStorage<'a, InputComponent>,
+        ReadStorage<'a, MaterialComponent>,
+        ReadStorage<'a, NameComponent>,
+        WriteStorage<'a, InventoryComponent>,
+        WriteStorage<'a, ManipulatorComponent>,
+        WriteStorage<'a, PositionComponent>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (eids, lup, mut twld, mut mlg, dur, inp, mat, nam, mut inv, mut man, mut pos) = data;
+
+        let broken: Vec<Entity> = (&eids, &dur)
+            .join()
+            .filter(|(_, dur)| dur.is_broken())
+            .map(|(eid, _)| eid)
+            .collect();
+
+        for item in broken {
+            println!("Item has broken");
+
+            //It's either lying around, in someone's hands, or in their pack
+            let holder = (&eids, &man)
+                .join()
+                .find(|(_, man)| man.held_item == Some(item))
+                .map(|(eid, _)| eid)
+                .or_else(|| {
+                    (&eids, &inv)
+                        .join()
+                        .find(|(_, inv)| inv.items.contains(&Some(item)))
+                        .map(|(eid, _)| eid)
+                });
+            let drop_pos = pos
+                .get(item)
+                .or_else(|| holder.and_then(|holder| pos.get(holder)))
+                .map(|pos| pos.pos);
+
+            if let Some(holder) = holder {
+                if let Some(man) = man.get_mut(holder) {
+                    if man.held_item == Some(item) {
+                        man.held_item = None;
+                    }
+                }
+
+                if let Some(inv) = inv.get_mut(holder) {
+                    inv.remove(item);
+                }
+
+                if inp.get(holder).is_some() {
+                    if let Some(name) = nam.get(item) {
+                        mlg.push(format!("Your {} breaks!", name.name));
+                    }
+                }
+            }
+
+            if pos.get(item).is_some() {
+                twld.despawn_entity(item, &mut pos);
+            }
+
+            //Whatever's left of its parts falls to the ground, a little the worse for wear
+            for part in dur.get(item).unwrap().parts.iter().copied() {
+                match (mat.get(part), drop_pos) {
+                    (Some(part_mat), Some(drop_pos)) if part_mat.amount > 1 => {
+                        lup.insert(
+                            part,
+                            MaterialComponent {
+                                amount: part_mat.amount - 1,
+                                ..part_mat.clone()
+                            },
+                        );
+                        twld.spawn_entity(part, drop_pos, &mut pos);
+                    }
+                    _ => eids.delete(part).unwrap(),
+                }
+            }
+
+            eids.delete(item).unwrap();
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use specs::{Entities, Join, ReadExpect, ReadStorage, System, WriteExpect, WriteStorage};
+
+use crate::prelude::*;
+
+//How much fatigue a night's sleep takes off each turn, before shelter and warmth
+const SLEEP_RECOVERY: usize = 2;
+//Sounds at least this loud wake sleepers, so the patter of footsteps won't
+const WAKE_VOLUME: u32 = 4;
+
+pub struct FatigueResolutionSystem;
+
+impl<'a> System<'a> for FatigueResolutionSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, TileWorldResource>,
+        ReadExpect<'a, ClockResource>,
+        WriteExpect<'a, MessageLogResource>,
+        ReadStorage<'a, InputComponent>,
+        ReadStorage<'a, PositionComponent>,
+        ReadStorage<'a, IntendedMovementComponent>,
+        ReadStorage<'a, HealthComponent>,
+        ReadStorage<'a, AIPerceptionComponent>,
+        ReadStorage<'a, WarmthComponent>,
+        WriteStorage<'a, FatigueComponent>,
+        WriteStorage<'a, StatusEffectComponent>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (eids, twld, clk, mut mlg, inp, pos, imc, hpc, per, wrm, mut ftg, mut sts) = data;
+
+        for (eid, pos, ftg) in (&eids, &pos, &mut ftg).join() {
+            let is_player = inp.get(eid).is_some();
+
+            //Worn out creatures fight and move worse until they've slept it off
+            if let Some(sts) = sts.get_mut(eid) {
+                if ftg.is_tired() {
+                    sts.apply(StatusEffectType::Exhausted, None);
+                } else {
+                    sts.remove(StatusEffectType::Exhausted);
+                }
+            }
+
+            if let Some(wake_condition) = ftg.sleep {
+                //Sleep comes easier out of the weather and by a fire
+                let sheltered = twld.is_sheltered(pos.pos);
+                let warm = (&pos, &wrm).join().any(|(warm_pos, warmth)| {
+                    warm_pos.pos.chebyshev_distance(&pos.pos) <= warmth.radius
+                });
+                let recovery = SLEEP_RECOVERY + sheltered as usize + warm as usize;
+
+                ftg.fatigue = ftg.fatigue.saturating_sub(recovery);
+
+                let hurt = hpc.get(eid).map_or(false, |hpc| {
+                    hpc.turn_damage > 0 || !hpc.pending_hits.is_empty()
+                });
+                let disturbed = per.get(eid).map_or(false, |per| {
+                    per.heard.iter().any(|heard| {
+                        heard.volume >= WAKE_VOLUME && heard.sound.emitter != Some(eid)
+                    })
+                });
+                let finished = match wake_condition {
+                    WakeCondition::Rested => ftg.fatigue == 0,
+                    WakeCondition::Healed => {
+                        hpc.get(eid).map_or(true, |hpc| hpc.value >= hpc.max_value)
+                    }
+                    WakeCondition::Morning => clk.is_day(),
+                };
+
+                if hurt || disturbed || finished {
+                    println!("Entity is waking up");
+                    ftg.sleep = None;
+
+                    if is_player {
+                        mlg.push(if hurt {
+                            "You are woken by pain!"
+                        } else if disturbed {
+                            "You are woken by a noise!"
+                        } else {
+                            "You wake up."
+                        });
+                    }
+                }
+            } else {
+                let was_tired = ftg.is_tired();
+
+                //Being up and about is more tiring than standing still
+                let moving = imc
+                    .get(eid)
+                    .map_or(false, |imc| imc.delta != IPosition::ZERO);
+                ftg.fatigue += 1 + moving as usize;
+
+                if ftg.is_exhausted() {
+                    println!("Entity has collapsed from exhaustion");
+                    ftg.sleep = Some(WakeCondition::Rested);
+
+                    if is_player {
+                        mlg.push("You collapse from exhaustion!");
+                    }
+                } else if is_player && !was_tired && ftg.is_tired() {
+                    mlg.push("You are tired.");
+                }
+            }
+        }
+    }
+}
@@ -4,30 +4,46 @@ use specs::prelude::*;
 
 use crate::prelude::*;
 
+//Sleepers only notice what's right next to them
+const SLEEPING_SIGHT: u32 = 1;
+
 pub struct FieldOfViewCalculationSystem;
 
 impl<'a> System<'a> for FieldOfViewCalculationSystem {
     type SystemData = (
         ReadExpect<'a, TileWorldResource>,
         ReadStorage<'a, PositionComponent>,
+        ReadStorage<'a, FatigueComponent>,
         WriteStorage<'a, FieldOfViewComponent>,
         WriteStorage<'a, AIPerceptionComponent>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (twld, pos, mut fov, mut apc) = data;
+        let (twld, pos, ftg, mut fov, mut apc) = data;
 
-        for (pos, fov, mut apc) in (&pos, &mut fov, (&mut apc).maybe()).join() {
+        for (pos, ftg, fov, mut apc) in
+            (&pos, (&ftg).maybe(), &mut fov, (&mut apc).maybe()).join()
+        {
             if let Some(apc) = &mut apc {
                 apc.all.clear();
+                apc.water.clear();
             }
 
-            fov.shadowcast.shadowcast(&mut FieldOfViewCallbacks {
-                radius: fov.shadowcast.radius(),
-                tile_world: &*twld,
-                position: pos,
-                ai_perception: apc,
-            });
+            let sight = if ftg.map_or(false, |ftg| ftg.is_asleep()) {
+                SLEEPING_SIGHT
+            } else {
+                fov.shadowcast.radius()
+            };
+
+            fov.shadowcast.shadowcast_within(
+                sight,
+                &mut FieldOfViewCallbacks {
+                    radius: fov.shadowcast.radius(),
+                    tile_world: &*twld,
+                    position: pos,
+                    ai_perception: apc,
+                },
+            );
         }
     }
 }
@@ -66,6 +82,10 @@ impl<'a> ShadowcastCallbacks for FieldOfViewCallbacks<'a> {
                 for entity in chunk_tile.entities.iter() {
                     ai_perception.all.push(*entity);
                 }
+
+                if chunk_tile.tile.tile_type == TileType::Water {
+                    ai_perception.water.push(pos);
+                }
             }
         }
     }
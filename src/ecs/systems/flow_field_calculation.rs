@@ -0,0 +1,115 @@
+use fxhash::FxHashMap;
+use specs::{Entities, Join, ReadExpect, ReadStorage, System, WriteExpect};
+
+use crate::prelude::*;
+
+pub struct FlowFieldCalculationSystem;
+
+impl<'a> System<'a> for FlowFieldCalculationSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, TileWorldResource>,
+        WriteExpect<'a, FlowFieldResource>,
+        ReadStorage<'a, PositionComponent>,
+        ReadStorage<'a, SpeciesComponent>,
+        ReadStorage<'a, InputComponent>,
+        ReadStorage<'a, ColliderComponent>,
+        ReadStorage<'a, MovementCostComponent>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (eids, twld, mut flw, pos, spc, inp, cld, mvc) = data;
+
+        let origin = twld.offset * CHUNK_SIZE as i32;
+
+        //Fields are shared by creatures of every size, and creatures move out of each other's way,
+        //so only static colliders block them
+        let cost_fn = |tile_pos: IPosition| {
+            let chunk_tile = twld.get(tile_pos)?;
+            let mut cost = chunk_tile.tile.tile_type.get_movement_cost()?;
+
+            for entity in chunk_tile.entities.iter() {
+                if cld.get(*entity).is_some() && spc.get(*entity).is_none() {
+                    return None;
+                }
+
+                if let Some(entity_cost) = mvc.get(*entity) {
+                    cost += CreatureSize::default().scale_obstruction(entity_cost.cost);
+                }
+            }
+
+            Some(cost)
+        };
+
+        //Blocked or opened up tiles change every route, not just the ones near the sources
+        //Obstacles that come and go without changing the terrain are caught once the sources move
+        if flw.terrain_revision != twld.terrain_revision {
+            flw.fields.clear();
+            flw.terrain_revision = twld.terrain_revision;
+        }
+
+        let mut species_positions: FxHashMap<Species, Vec<IPosition>> = FxHashMap::default();
+
+        for (pos, spc) in (&pos, &spc).join() {
+            species_positions
+                .entry(spc.species)
+                .or_default()
+                .push(pos.pos);
+        }
+
+        let player_positions: Vec<_> = (&eids, &pos, &inp).join().map(|(_, pos, _)| pos.pos).collect();
+
+        let mut targets = Vec::new();
+
+        for (species, positions) in species_positions.iter() {
+            let herd_centre = positions.iter().copied().sum::<IPosition>() / positions.len() as i32;
+
+            let target = FlowFieldTarget::HerdCentre { species: *species };
+            if let Some(field) = flw.get_outdated(target, origin, &[herd_centre]) {
+                field.calculate(origin, Some(herd_centre), &cost_fn);
+            }
+            targets.push(target);
+
+            let mut threat_positions: Vec<_> = species_positions
+                .iter()
+                .filter(|(other, _)| species.is_threatened_by(other))
+                .flat_map(|(_, positions)| positions.iter().copied())
+                .collect();
+
+            if !threat_positions.is_empty() {
+                //The same threats in a different order still make the same field
+                threat_positions.sort_unstable_by_key(|pos| (pos.x, pos.y));
+
+                let target = FlowFieldTarget::AwayFromThreats { species: *species };
+                if let Some(field) = flw.get_outdated(target, origin, &threat_positions) {
+                    field.calculate(origin, threat_positions, &cost_fn);
+                    field.invert(&cost_fn);
+                }
+                targets.push(target);
+            }
+        }
+
+        if !player_positions.is_empty() {
+            let target = FlowFieldTarget::Player;
+            if let Some(field) = flw.get_outdated(target, origin, &player_positions) {
+                field.calculate(origin, player_positions, &cost_fn);
+            }
+            targets.push(target);
+        }
+
+        //Water only moves when the terrain does, which already throws the old field away
+        let target = FlowFieldTarget::Water;
+        if flw.get(target).is_none() {
+            let water_positions = twld.get_water_positions();
+
+            if !water_positions.is_empty() {
+                flw.get_or_insert(target, origin)
+                    .calculate(origin, water_positions, &cost_fn);
+            }
+        }
+        targets.push(target);
+
+        //Nobody is left to follow fields for targets that no longer exist
+        flw.fields.retain(|target, _| targets.contains(target));
+    }
+}
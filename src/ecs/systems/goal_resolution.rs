@@ -17,6 +17,11 @@ impl<'a> System<'a> for GoalResolutionSystem {
 
     fn run(&mut self, (eids, mut gol, mut act, mut data): Self::SystemData) {
         for (eid, gol, act) in (&eids, &mut gol, &mut act).join() {
+            //Sleepers don't think about anything until they wake up
+            if data.fatigue.get(eid).map_or(false, |ftg| ftg.is_asleep()) {
+                continue;
+            }
+
             //Check for latest goal in stack
             //Attempt to resolve goal
             //match result
@@ -26,8 +31,20 @@ impl<'a> System<'a> for GoalResolutionSystem {
 
             if gol.goal_stack.len() == 0 {
                 println!("No goal, falling back on default behaviour");
-                if let Some(pers) = data.personality.get(eid) {
-                    if let Some(default_goal) = pers.get_default_goal(false) {
+                if data.tame.get(eid).map_or(false, |tame| tame.tamed) {
+                    //Pets do as they're told when they have nothing more pressing to do
+                    gol.goal_stack.push(AIGoal::ObeyOwner(ObeyOwnerGoal {
+                        travel_to_position_goal: None,
+                    }));
+                } else if let Some(pers) = data.personality.get(eid) {
+                    //Thinking creatures left to their own devices look after themselves
+                    let settler = data.input.get(eid).is_none()
+                        && data
+                            .species
+                            .get(eid)
+                            .map_or(false, |species| species.species.is_sapient());
+
+                    if let Some(default_goal) = pers.get_default_goal(false, settler) {
                     // if let Some(default_goal) = pers.get_default_goal(data.input.get(eid).is_some()) {
                         gol.goal_stack.push(default_goal);
                     }
@@ -1,7 +1,22 @@
-use specs::{Entities, Join, LazyUpdate, ReadExpect, System, WriteExpect, WriteStorage};
+use rand::prelude::*;
+use specs::{
+    Entities, Entity, Join, LazyUpdate, ReadExpect, ReadStorage, System, WriteExpect,
+    WriteStorage,
+};
 
 use crate::prelude::*;
 
+//Each turn an open wound has a 1 in n chance of costing a point of health
+const BLEED_INTERVAL: u32 = 10;
+//Each turn an open wound has a 1 in n chance of clotting
+const CLOT_CHANCE: u32 = 30;
+//Each turn a closed wound has a 1 in n chance of knitting back together a little
+const WOUND_HEAL_CHANCE: u32 = 200;
+//How long something hit by fire keeps burning for
+const BURNING_DURATION: u32 = 3;
+//How badly a fire burns whatever is standing in it each turn
+const FIRE_DAMAGE: u32 = 1;
+
 pub struct HealthResolutionSystem;
 
 impl<'a> System<'a> for HealthResolutionSystem {
@@ -9,16 +24,179 @@ impl<'a> System<'a> for HealthResolutionSystem {
         Entities<'a>,
         ReadExpect<'a, LazyUpdate>,
         WriteExpect<'a, TileWorldResource>,
+        WriteExpect<'a, SoundResource>,
+        WriteExpect<'a, MessageLogResource>,
+        ReadStorage<'a, SpeciesComponent>,
+        ReadStorage<'a, ArmourComponent>,
+        ReadStorage<'a, NameComponent>,
+        ReadStorage<'a, InputComponent>,
+        ReadStorage<'a, WarmthComponent>,
+        WriteStorage<'a, ManipulatorComponent>,
+        WriteStorage<'a, AnatomyComponent>,
         WriteStorage<'a, HealthComponent>,
         WriteStorage<'a, PositionComponent>,
         WriteStorage<'a, DeathComponent>,
+        WriteStorage<'a, StatusEffectComponent>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (eids, lup, mut twld, mut hpc, mut pos, mut dec) = data;
+        let (
+            eids,
+            lup,
+            mut twld,
+            mut snd,
+            mut mlg,
+            spc,
+            arm,
+            nam,
+            inp,
+            wrm,
+            mut man,
+            mut ana,
+            mut hpc,
+            mut pos,
+            mut dec,
+            mut sts,
+        ) = data;
+
+        let get_name =
+            |entity: Entity| nam.get(entity).map_or("something", |name| name.name.as_str());
+
+        //Anything warm enough to heat a camp burns whatever stands in it
+        for (fire, fire_pos, _) in (&eids, &pos, &wrm).join() {
+            if let Some(chunk_tile) = twld.get(fire_pos.pos) {
+                for entity in chunk_tile.entities.iter().copied() {
+                    if let Some(hpc) = hpc.get_mut(entity).filter(|_| entity != fire) {
+                        hpc.pending_hits.push(Damage {
+                            amount: FIRE_DAMAGE,
+                            damage_type: DamageType::Fire,
+                            material: None,
+                            attacker: Some(fire),
+                        });
+                    }
+                }
+            }
+        }
 
         for (eid, hpc, dec) in (&eids, &mut hpc, &mut dec).join() {
             let position = pos.get(eid).unwrap().pos;
+            let is_player = inp.get(eid).is_some();
+
+            //Blows are softened by whatever is held up to block them, then by the hide beneath
+            let armour: Vec<Material> = man
+                .get(eid)
+                .and_then(|man| man.held_item)
+                .into_iter()
+                .chain(Some(eid))
+                .filter_map(|entity| arm.get(entity))
+                .map(|armour| armour.material)
+                .collect();
+
+            for hit in hpc.pending_hits.drain(..) {
+                let hit = armour
+                    .iter()
+                    .fold(hit, |hit, material| hit.reduced_by(*material));
+                let by_player = hit.attacker.map_or(false, |attacker| inp.get(attacker).is_some());
+
+                hpc.turn_damage += hit.amount;
+
+                if hit.damage_type == DamageType::Fire && hit.amount > 0 {
+                    if let Some(sts) = sts.get_mut(eid) {
+                        if sts.apply(StatusEffectType::Burning, Some(BURNING_DURATION)) && is_player
+                        {
+                            mlg.push("You catch fire!");
+                        }
+                    }
+                }
+
+                let anatomy = ana.get_mut(eid);
+                let part = anatomy.and_then(|anatomy| anatomy.choose_part());
+
+                //Describe the blow from the player's side of it
+                let target_desc = match &part {
+                    Some(part) if is_player => format!("your {}", part.name),
+                    Some(part) => format!("the {}'s {}", get_name(eid), part.name),
+                    None if is_player => String::from("you"),
+                    None => format!("the {}", get_name(eid)),
+                };
+
+                if is_player || by_player {
+                    let attacker_desc = match hit.attacker {
+                        _ if by_player => String::from("You hit"),
+                        Some(attacker) => format!("The {} hits", get_name(attacker)),
+                        None => String::from("Something hits"),
+                    };
+
+                    mlg.push(if hit.amount == 0 {
+                        format!("{} {}, but it glances off.", attacker_desc, target_desc)
+                    } else {
+                        format!(
+                            "{} {} for {} {} damage.",
+                            attacker_desc,
+                            target_desc,
+                            hit.amount,
+                            hit.damage_type.get_name()
+                        )
+                    });
+                }
+
+                if let Some(part) = part {
+                    if hit.amount == 0 {
+                        continue;
+                    }
+
+                    if part.wound(&hit) {
+                        println!("Entity has had its {} disabled", part.name);
+
+                        if is_player || by_player {
+                            mlg.push(format!("{} is crippled!", capitalise(&target_desc)));
+                        }
+                    }
+
+                    //A blow to the arm loosens its grip on whatever it's holding
+                    if part.part_type == BodyPartType::Arm {
+                        if let Some(item) = man.get_mut(eid).and_then(|man| man.held_item.take())
+                        {
+                            twld.spawn_entity(item, position, &mut pos);
+
+                            if is_player {
+                                mlg.push(format!("You drop the {}!", get_name(item)));
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(anatomy) = ana.get_mut(eid) {
+                for part in anatomy.parts.iter_mut() {
+                    if part.bleeding > 0 {
+                        if thread_rng().gen_range(0..BLEED_INTERVAL) < part.bleeding {
+                            hpc.turn_damage += 1;
+                        }
+
+                        if thread_rng().gen_range(0..CLOT_CHANCE) == 0 {
+                            part.bleeding -= 1;
+                        }
+                    } else if part.wound > 0 && thread_rng().gen_range(0..WOUND_HEAL_CHANCE) == 0 {
+                        part.wound -= 1;
+                    }
+                }
+
+                //Keep the status in step with the wounds, so everything else can see it
+                if let Some(sts) = sts.get_mut(eid) {
+                    if anatomy.get_bleeding() > 0 {
+                        sts.apply(StatusEffectType::Bleeding, None);
+                    } else {
+                        sts.remove(StatusEffectType::Bleeding);
+                    }
+                }
+
+                //Nothing survives losing its head
+                if anatomy.is_fatally_wounded() {
+                    println!("Entity has been fatally wounded");
+                    hpc.turn_damage = hpc.turn_damage.max(hpc.value);
+                }
+            }
 
             for _ in 0..hpc.turn_damage {
                 if let Some(particle) = hpc.hit_particle {
@@ -30,10 +208,19 @@ impl<'a> System<'a> for HealthResolutionSystem {
                 println!("New health: {}", hpc.value);
 
                 if hpc.value == 0 {
+                    let size = spc.get(eid).map(|spc| spc.species.get_size()).unwrap_or_default();
+                    snd.emit(position, SoundType::Death, size, Some(eid));
+
                     for dec in dec.contained_entities.drain(..) {
                         twld.spawn_entity(dec, position, &mut pos);
                     }
 
+                    //Creatures leave a carcass to be cut up, rather than vanishing
+                    if let Some(spc) = spc.get(eid) {
+                        let corpse = CreatureBuilder::build_corpse(spc.species, &lup, &eids);
+                        twld.spawn_entity(corpse, position, &mut pos);
+                    }
+
                     twld.despawn_entity(eid, &mut pos);
                     eids.delete(eid).unwrap();
                     break;
@@ -0,0 +1,43 @@
+use specs::{Entities, Join, ReadExpect, ReadStorage, System, WriteExpect, WriteStorage};
+
+use crate::prelude::*;
+
+pub struct HearingCalculationSystem;
+
+impl<'a> System<'a> for HearingCalculationSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, TileWorldResource>,
+        WriteExpect<'a, SoundResource>,
+        ReadStorage<'a, PositionComponent>,
+        WriteStorage<'a, AIPerceptionComponent>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (eids, twld, mut snd, pos, mut apc) = data;
+
+        for apc in (&mut apc).join() {
+            apc.heard.clear();
+        }
+
+        for sound in snd.sounds.drain(..) {
+            let volumes = sound.propagate(|tile_pos| {
+                twld.get(tile_pos)
+                    .map(|chunk_tile| chunk_tile.tile.tile_type.collides())
+            });
+
+            for (eid, pos, apc) in (&eids, &pos, &mut apc).join() {
+                if sound.emitter == Some(eid) {
+                    continue;
+                }
+
+                if let Some(volume) = volumes.get(&pos.pos) {
+                    apc.heard.push(HeardSound {
+                        sound: sound.clone(),
+                        volume: *volume,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use rand::prelude::*;
+use specs::{Entities, Join, ReadExpect, ReadStorage, System, WriteExpect, WriteStorage};
+
+use crate::prelude::*;
+
+//Each turn out in the rain there is a 1 in n chance of catching a mouthful
+const RAIN_DRINK_CHANCE: u32 = 5;
+//Each turn completely dried out there is a 1 in n chance of taking a point of damage
+const DEHYDRATION_DAMAGE_CHANCE: u32 = 20;
+
+pub struct HydrationResolutionSystem;
+
+impl<'a> System<'a> for HydrationResolutionSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, TileWorldResource>,
+        ReadExpect<'a, WeatherResource>,
+        WriteExpect<'a, MessageLogResource>,
+        ReadStorage<'a, SpeciesComponent>,
+        ReadStorage<'a, InputComponent>,
+        ReadStorage<'a, PositionComponent>,
+        WriteStorage<'a, HealthComponent>,
+        WriteStorage<'a, HydrationComponent>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (eids, twld, weat, mut mlg, spc, inp, pos, mut hpc, mut hyd) = data;
+
+        let heat = weat.current_weather.get_heat();
+        let raining = matches!(weat.current_weather, WeatherStatus::Raining);
+
+        for (eid, pos, hpc, hyd) in (&eids, &pos, &mut hpc, &mut hyd).join() {
+            let metabolism = spc
+                .get(eid)
+                .map(|spc| spc.species.get_metabolism())
+                .unwrap_or_default();
+            let was_thirsty = metabolism.is_thirsty(hyd.value);
+
+            if thread_rng().gen_range(0..metabolism.thirst_interval) < heat {
+                hyd.value = hyd.value.saturating_sub(1);
+            }
+
+            if raining
+                && !twld.is_sheltered(pos.pos)
+                && thread_rng().gen_range(0..RAIN_DRINK_CHANCE) == 0
+            {
+                hyd.value = (hyd.value + 1).min(hyd.max_value);
+            }
+
+            if hyd.value == 0 && thread_rng().gen_range(0..DEHYDRATION_DAMAGE_CHANCE) == 0 {
+                hpc.turn_damage += 1;
+            }
+
+            if inp.get(eid).is_some() && !was_thirsty && metabolism.is_thirsty(hyd.value) {
+                mlg.push("You are thirsty!");
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use ggez::event::KeyCode;
 use log::debug;
-use specs::{Join, System};
+use specs::{Entity, Join, ReadStorage, System, WriteStorage};
+use strum::IntoEnumIterator;
 
 use crate::prelude::*;
 
@@ -17,13 +18,27 @@ impl<'a> System<'a> for InputResolutionSystem {
         let twld = data.tile_world;
         let kb = data.keyboard;
         let ms = data.mouse;
+        let mut mlg = data.message_log;
+
+        //Included system data
+        let crd = data.crafting_data;
 
         //Readable components
         let pos = data.position;
+        let atk = data.attack;
+        let man = data.manipulation;
         let itc = data.item;
+        let edb = data.edible;
+        let wtc = data.water_container;
+        let thr = data.throwable;
+        let btc = data.butcherable;
+        let atr = data.attributes;
+        let skl = data.skills;
+        let nam = data.name;
 
         //Writable components
         let mut gol = data.ai_goal;
+        let mut ftg = data.fatigue;
         let mut pth = data.pathing;
         let mut inc = data.input;
         let mut inv = data.inventory;
@@ -38,6 +53,31 @@ impl<'a> System<'a> for InputResolutionSystem {
             //deal with popup
             //pass keyboard to popup
 
+            let offset = MAP_X_SIZE as i32 / 2;
+            let top_left = pos.pos - IPosition::new(offset, offset);
+            let (mouse_x, mouse_y) = ms.position;
+            let char_mouse = IPosition::new(
+                (mouse_x / (RENDER_SCALE * 8.0)).floor() as i32,
+                (mouse_y / (RENDER_SCALE * 8.0)).floor() as i32,
+            );
+
+            let tile_mouse = if (0..MAP_X_SIZE as i32).contains(&char_mouse.x)
+                && (0..MAP_Y_SIZE as i32).contains(&char_mouse.y)
+            {
+                Some(top_left + char_mouse)
+            } else {
+                None
+            };
+
+            //Any key shakes the player awake, but not the one still held from settling down to rest
+            if let (Some(_), Some(ftg)) = (kb.get_new_press(), ftg.get_mut(eid)) {
+                if ftg.is_asleep() {
+                    ftg.sleep = None;
+                    mlg.push("You wake up.");
+                    continue;
+                }
+            }
+
             if let Some(key) = kb.last_pressed_key {
                 if let Some(popup) = &mut inc.popup {
                     popup.handle_input(key, kb.modifiers);
@@ -45,6 +85,17 @@ impl<'a> System<'a> for InputResolutionSystem {
                     match &popup.state {
                         PopupState::Waiting => {}
                         PopupState::Canceling => inc.popup = None,
+                        //Crafting asks for one ingredient after another until it has them all
+                        PopupState::Returning(AIGoal::Craft(craft)) if craft.is_partly_chosen() => {
+                            let available = craft.recipe.get_ingredient_candidates(
+                                &Self::get_crafting_materials(
+                                    eid, pos.pos, &inv, &man, &itc, &twld,
+                                ),
+                                man.get(eid).and_then(|man| man.held_item),
+                            );
+
+                            inc.popup = Some(Self::ingredient_popup(craft, &available, &nam, &crd));
+                        }
                         PopupState::Returning(goal) => {
                             gol.goal_stack.push(goal.clone());
                             inc.popup = None;
@@ -102,6 +153,368 @@ impl<'a> System<'a> for InputResolutionSystem {
                                 attempted: false,
                             }));
                         }
+                        KeyCode::F => {
+                            inc.popup = Some(Popup::directions(
+                                String::from("Feed what?"),
+                                Directions::all(),
+                                |direction| {
+                                    AIGoal::FeedEntity(FeedEntityGoal {
+                                        direction,
+                                        attempted: false,
+                                    })
+                                },
+                            ));
+                        }
+                        KeyCode::O => {
+                            let mut orders = vec![PetOrder::Follow, PetOrder::Stay];
+
+                            //Pets can be sent to wherever the cursor is
+                            if let Some(tile_mouse) = tile_mouse {
+                                orders.push(PetOrder::GoTo { pos: tile_mouse });
+                            }
+
+                            let order_goals = orders
+                                .into_iter()
+                                .enumerate()
+                                .map(|(index, order)| {
+                                    PopupListItem::new(
+                                        index,
+                                        Some(order.get_name()),
+                                        AIGoal::CommandPets(CommandPetsGoal {
+                                            order,
+                                            attempted: false,
+                                        }),
+                                    )
+                                })
+                                .collect();
+
+                            inc.popup = Some(Popup::list(
+                                String::from("Order pets to do what?"),
+                                order_goals,
+                            ));
+                        }
+                        KeyCode::E => {
+                            //Anything edible we carry or can reach, whether or not we can stomach it
+                            let carried = inv
+                                .get(eid)
+                                .map(|inv| {
+                                    inv.items.iter().flatten().copied().collect::<Vec<_>>()
+                                })
+                                .unwrap_or_else(Vec::new);
+                            let nearby = Direction::iter().flat_map(|direction| {
+                                twld.get(pos.pos + direction.get_offset())
+                                    .map(|chunk_tile| chunk_tile.entities.clone())
+                                    .unwrap_or_default()
+                            });
+
+                            let eat_goals: Vec<_> = carried
+                                .into_iter()
+                                .chain(nearby)
+                                .filter(|entity| edb.get(*entity).is_some())
+                                .enumerate()
+                                .map(|(index, food)| {
+                                    PopupListItem::new(
+                                        index,
+                                        None,
+                                        AIGoal::Eat(EatGoal {
+                                            target: food,
+                                            eat_from_inventory_goal: None,
+                                            eat_from_world_goal: None,
+                                        }),
+                                    )
+                                })
+                                .collect();
+
+                            if !eat_goals.is_empty() {
+                                inc.popup =
+                                    Some(Popup::list(String::from("Eat what?"), eat_goals));
+                            }
+                        }
+                        KeyCode::Q => {
+                            //Any water within reach, or anything carried with water left in it
+                            let water = Direction::iter()
+                                .map(|direction| pos.pos + direction.get_offset())
+                                .filter(|water_pos| twld.is_water(*water_pos))
+                                .map(|water_pos| DrinkSource::Tile { pos: water_pos });
+                            let containers = inv
+                                .get(eid)
+                                .map(|inv| {
+                                    inv.items.iter().flatten().copied().collect::<Vec<_>>()
+                                })
+                                .unwrap_or_else(Vec::new)
+                                .into_iter()
+                                .filter(|item| {
+                                    wtc.get(*item).map_or(false, |container| container.water > 0)
+                                })
+                                .map(|item| DrinkSource::Container { item });
+
+                            let drink_goals: Vec<_> = water
+                                .chain(containers)
+                                .enumerate()
+                                .map(|(index, source)| {
+                                    PopupListItem::new(
+                                        index,
+                                        None,
+                                        AIGoal::Drink(DrinkGoal::new(source)),
+                                    )
+                                })
+                                .collect();
+
+                            if !drink_goals.is_empty() {
+                                inc.popup =
+                                    Some(Popup::list(String::from("Drink what?"), drink_goals));
+                            }
+                        }
+                        KeyCode::A => {
+                            //Pick an attack, then which way to swing it if it sweeps, then where
+                            let attack_menu = AttackComponent::get_attacks(eid, &atk, &man)
+                                .iter()
+                                .map(|attack| {
+                                    let popup = if attack.attack_type.is_rotational() {
+                                        Popup::submenu(
+                                            String::from("Swing which way?"),
+                                            vec![
+                                                RotationDirection::Clockwise,
+                                                RotationDirection::CounterClockwise,
+                                            ]
+                                            .into_iter()
+                                            .map(|swing_direction| {
+                                                (
+                                                    swing_direction.get_name(),
+                                                    Self::attack_popup(
+                                                        attack.clone(),
+                                                        Some(swing_direction),
+                                                    ),
+                                                )
+                                            })
+                                            .collect(),
+                                        )
+                                    } else {
+                                        Self::attack_popup(attack.clone(), None)
+                                    };
+
+                                    (attack.get_name(), popup)
+                                })
+                                .collect::<Vec<_>>();
+
+                            if !attack_menu.is_empty() {
+                                inc.popup = Some(Popup::submenu(
+                                    String::from("Attack with what?"),
+                                    attack_menu,
+                                ));
+                            }
+                        }
+                        KeyCode::T => {
+                            if let Some(held_item) = man.get(eid).and_then(|man| man.held_item) {
+                                //Improvised missiles don't carry as far
+                                let range = thr.get(held_item).map_or_else(
+                                    || ThrowableComponent::improvised(None).range,
+                                    |thr| thr.range,
+                                );
+                                let origin = pos.pos;
+
+                                inc.popup = Some(Popup::target(
+                                    String::from("Throw where?"),
+                                    range,
+                                    move |cursor| AIGoal::Throw(ThrowGoal::new(origin + cursor)),
+                                ));
+                            } else {
+                                mlg.push("You aren't holding anything to throw.");
+                            }
+                        }
+                        KeyCode::U => {
+                            //Cut up any carcass underfoot or next to us
+                            let butcher_goals: Vec<_> = Direction::iter()
+                                .flat_map(|direction| {
+                                    twld.get(pos.pos + direction.get_offset())
+                                        .map(|chunk_tile| chunk_tile.entities.clone())
+                                        .unwrap_or_default()
+                                })
+                                .filter(|entity| btc.get(*entity).is_some())
+                                .enumerate()
+                                .map(|(index, carcass)| {
+                                    PopupListItem::new(
+                                        index,
+                                        None,
+                                        AIGoal::Butcher(ButcherGoal::new(carcass)),
+                                    )
+                                })
+                                .collect();
+
+                            if !butcher_goals.is_empty() {
+                                inc.popup = Some(Popup::list(
+                                    String::from("Butcher what?"),
+                                    butcher_goals,
+                                ));
+                            }
+                        }
+                        KeyCode::D => {
+                            //Dig through any bare rock next to us
+                            let dig_goals: Vec<_> = Direction::iter()
+                                .map(|direction| pos.pos + direction.get_offset())
+                                .filter(|dig_pos| {
+                                    *dig_pos != pos.pos
+                                        && twld.get(*dig_pos).map_or(false, |chunk_tile| {
+                                            chunk_tile.tile.tile_type.can_dig()
+                                        })
+                                })
+                                .enumerate()
+                                .map(|(index, dig_pos)| {
+                                    PopupListItem::new(
+                                        index,
+                                        None,
+                                        AIGoal::Dig(DigGoal::new(dig_pos)),
+                                    )
+                                })
+                                .collect();
+
+                            if !dig_goals.is_empty() {
+                                inc.popup =
+                                    Some(Popup::list(String::from("Dig where?"), dig_goals));
+                            }
+                        }
+                        KeyCode::S => {
+                            let mut lines = Vec::new();
+
+                            if let Some(atr) = atr.get(eid) {
+                                lines.extend(Attribute::iter().map(|attribute| {
+                                    format!(
+                                        "{:<12}{:>3} ({:+})",
+                                        attribute.get_name(),
+                                        atr.get(attribute),
+                                        atr.get_modifier(attribute)
+                                    )
+                                }));
+                                lines.push(String::new());
+                            }
+
+                            if let Some(skl) = skl.get(eid) {
+                                lines.extend(Skill::iter().map(|skill| {
+                                    format!(
+                                        "{:<12}{:>3} ({}/{} xp)",
+                                        skill.get_name(),
+                                        skl.get_level(skill),
+                                        skl.get_experience(skill),
+                                        Skill::get_experience_for_level(skl.get_level(skill) + 1)
+                                    )
+                                }));
+                            }
+
+                            inc.popup = Some(Popup::info(String::from("Character"), lines));
+                        }
+                        KeyCode::C => {
+                            let available =
+                                Self::get_crafting_materials(eid, pos.pos, &inv, &man, &itc, &twld);
+                            let held = man.get(eid).and_then(|man| man.held_item);
+                            //Tools get taken out before crafting, so they can't be lying around
+                            let holdable: Vec<_> = inv
+                                .get(eid)
+                                .map(|inv| inv.items.iter().flatten().copied().collect::<Vec<_>>())
+                                .unwrap_or_default()
+                                .into_iter()
+                                .chain(held)
+                                .collect();
+
+                            //Recipes that can't be made still get listed, but only to say why not
+                            let options = crd
+                                .recipes
+                                .recipes
+                                .iter()
+                                .map(|recipe| {
+                                    let name = &recipe.name;
+                                    let available =
+                                        recipe.get_ingredient_candidates(&available, held);
+
+                                    let missing_tool = recipe.get_tool().filter(|tool| {
+                                        !holdable.iter().any(|item| {
+                                            tool.requirement.requirement_fulfilled(*item, &crd)
+                                        })
+                                    });
+
+                                    let missing = recipe
+                                        .get_missing_ingredient(&[], &available, &crd)
+                                        .map(|missing| {
+                                            (
+                                                missing.part_name.clone(),
+                                                format!(
+                                                    "You need {} to use as the {}.",
+                                                    missing.describe(),
+                                                    missing.part_name
+                                                ),
+                                            )
+                                        })
+                                        .or_else(|| {
+                                            missing_tool.map(|tool| {
+                                                (
+                                                    tool.part_name.clone(),
+                                                    format!(
+                                                        "You need {} to hold as the {}.",
+                                                        tool.describe(),
+                                                        tool.part_name
+                                                    ),
+                                                )
+                                            })
+                                        })
+                                        .or_else(|| {
+                                            recipe
+                                                .workstation
+                                                .filter(|workstation| {
+                                                    !workstation.is_nearby(pos.pos, &twld, &crd)
+                                                })
+                                                .map(|workstation| {
+                                                    (
+                                                        String::from(workstation.get_name()),
+                                                        format!(
+                                                            "This has to be made by a {}.",
+                                                            workstation.get_name()
+                                                        ),
+                                                    )
+                                                })
+                                        });
+
+                                    match missing {
+                                        None => (
+                                            name.clone(),
+                                            Self::ingredient_popup(
+                                                &CraftGoal::new(recipe.clone()),
+                                                &available,
+                                                &nam,
+                                                &crd,
+                                            ),
+                                        ),
+                                        Some((missing, reason)) => (
+                                            format!("{} (no {})", name, missing),
+                                            Popup::info(
+                                                format!("Can't craft a {}", name),
+                                                vec![reason],
+                                            ),
+                                        ),
+                                    }
+                                })
+                                .collect();
+
+                            inc.popup = Some(Popup::submenu(String::from("Craft what?"), options));
+                        }
+                        KeyCode::R => {
+                            let rest_goals = [
+                                WakeCondition::Healed,
+                                WakeCondition::Morning,
+                                WakeCondition::Rested,
+                            ]
+                            .iter()
+                            .enumerate()
+                            .map(|(index, wake_condition)| {
+                                PopupListItem::new(
+                                    index,
+                                    Some(format!("Rest {}", wake_condition.get_name())),
+                                    AIGoal::Sleep(SleepGoal::new(*wake_condition)),
+                                )
+                            })
+                            .collect();
+
+                            inc.popup =
+                                Some(Popup::list(String::from("Rest how long?"), rest_goals));
+                        }
                         // //TODO: add modifier check to see if player presses G or g.
                         // //G picks up an entity in a manipulator
                         // //g places an entity in the inventory
@@ -221,30 +634,27 @@ impl<'a> System<'a> for InputResolutionSystem {
                         //         },
                         //     ));
                         // }
-                        // KeyCode::C => gol.goal_stack.push(AIGoal::Craft {
-                        //     recipe: None,
-                        //     ingredients: Vec::new(),
-                        // }),
                         _ => (),
                     }
                 }
             }
 
-            let offset = MAP_X_SIZE as i32 / 2;
-            let top_left = pos.pos - IPosition::new(offset, offset);
-            let (mouse_x, mouse_y) = ms.position;
-            let char_mouse = IPosition::new(
-                (mouse_x / (RENDER_SCALE * 8.0)).floor() as i32,
-                (mouse_y / (RENDER_SCALE * 8.0)).floor() as i32,
-            );
-
-            if (0..MAP_X_SIZE as i32).contains(&char_mouse.x)
-                && (0..MAP_Y_SIZE as i32).contains(&char_mouse.y)
-            {
-                let tile_mouse = top_left + char_mouse;
-
+            if let Some(tile_mouse) = tile_mouse {
                 if let Some(pth) = pth {
-                    inc.path = pth.pathfind(&*twld, pos.pos, tile_mouse, &data.collider);
+                    let size = data
+                        .species
+                        .get(eid)
+                        .map(|species| species.species.get_size())
+                        .unwrap_or_default();
+
+                    inc.path = pth.pathfind(
+                        &*twld,
+                        pos.pos,
+                        tile_mouse,
+                        size,
+                        &data.collider,
+                        &data.movement_cost,
+                    );
                 }
             }
 
@@ -257,3 +667,90 @@ impl<'a> System<'a> for InputResolutionSystem {
         }
     }
 }
+
+impl InputResolutionSystem {
+    //Whatever the crafter is carrying or holding, and anything lying within arm's reach
+    fn get_crafting_materials(
+        eid: Entity,
+        at: IPosition,
+        inv: &WriteStorage<InventoryComponent>,
+        man: &ReadStorage<ManipulatorComponent>,
+        itc: &ReadStorage<ItemComponent>,
+        twld: &TileWorldResource,
+    ) -> Vec<Entity> {
+        let carried = inv
+            .get(eid)
+            .map(|inv| inv.items.iter().flatten().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let held = man.get(eid).and_then(|man| man.held_item);
+        let nearby = Direction::iter()
+            .flat_map(|direction| {
+                twld.get(at + direction.get_offset())
+                    .map(|chunk_tile| chunk_tile.entities.clone())
+                    .unwrap_or_default()
+            })
+            .filter(|entity| itc.get(*entity).is_some());
+
+        carried.into_iter().chain(held).chain(nearby).collect()
+    }
+
+    //Offers up everything that could go into the next part, leaving enough for the parts after it
+    fn ingredient_popup(
+        craft: &CraftGoal,
+        available: &[Entity],
+        nam: &ReadStorage<NameComponent>,
+        crd: &CraftingData,
+    ) -> Popup {
+        let recipe = &craft.recipe;
+        let ingredient = recipe.get_slots()[craft.ingredients.len()];
+
+        let goals = available
+            .iter()
+            .copied()
+            .filter(|item| {
+                !craft.ingredients.contains(item)
+                    && ingredient.requirement.requirement_fulfilled(*item, crd)
+            })
+            .map(|item| craft.with_ingredient(item))
+            .filter(|craft| {
+                recipe
+                    .get_missing_ingredient(&craft.ingredients, available, crd)
+                    .is_none()
+            })
+            .enumerate()
+            .map(|(index, craft)| {
+                let item = *craft.ingredients.last().unwrap();
+
+                PopupListItem::new(
+                    index,
+                    nam.get(item).map(|name| name.name.clone()),
+                    AIGoal::Craft(craft),
+                )
+            })
+            .collect();
+
+        Popup::list(
+            format!(
+                "Use what as the {} ({})?",
+                ingredient.part_name,
+                ingredient.requirement.describe()
+            ),
+            goals,
+        )
+    }
+
+    fn attack_popup(attack: Attack, swing_direction: Option<RotationDirection>) -> Popup {
+        Popup::directions(
+            String::from("Attack where?"),
+            Directions::all() - Directions::NONE,
+            move |direction| {
+                AIGoal::AttackInDirection(AttackInDirectionGoal {
+                    direction,
+                    attack: Some(attack),
+                    swing_direction,
+                    attempted: false,
+                })
+            },
+        )
+    }
+}
@@ -0,0 +1,156 @@
+use fxhash::FxHashMap;
+use rand::prelude::*;
+use specs::prelude::*;
+
+use crate::prelude::*;
+
+//How close a mate has to be
+const MATE_RANGE: i32 = 2;
+//Each turn a ready pair has this 1 in n chance of having young
+const BREEDING_CHANCE: u32 = 100;
+
+pub struct LifeCycleSystem;
+
+impl<'a> System<'a> for LifeCycleSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, LazyUpdate>,
+        WriteExpect<'a, TileWorldResource>,
+        ReadStorage<'a, SpeciesComponent>,
+        ReadStorage<'a, DigestionComponent>,
+        ReadStorage<'a, EdibleComponent>,
+        ReadStorage<'a, InputComponent>,
+        ReadStorage<'a, ColliderComponent>,
+        WriteStorage<'a, LifeCycleComponent>,
+        WriteStorage<'a, HealthComponent>,
+        WriteStorage<'a, AnatomyComponent>,
+        WriteStorage<'a, DrawComponent>,
+        WriteStorage<'a, PositionComponent>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (
+            eids,
+            lup,
+            mut twld,
+            spc,
+            dig,
+            edb,
+            inp,
+            col,
+            mut lfc,
+            mut hpc,
+            mut ana,
+            mut drw,
+            mut pos,
+        ) = data;
+
+        for (eid, spc, lfc, hpc) in (&eids, &spc, &mut lfc, &mut hpc).join() {
+            let species = spc.species;
+
+            lfc.age += 1;
+            lfc.breeding_cooldown = lfc.breeding_cooldown.saturating_sub(1);
+
+            if lfc.age == species.get_maturity_age() {
+                println!("Entity has grown up");
+
+                let growth = species.get_max_health().saturating_sub(hpc.max_value);
+                hpc.max_value += growth;
+                hpc.value += growth;
+
+                if let Some(ana) = ana.get_mut(eid) {
+                    ana.grow(hpc.max_value);
+                }
+
+                if let Some(drw) = drw.get_mut(eid) {
+                    drw.sprite_builder = species.get_sprite_builder(false);
+                    drw.symbol_builder = Some(species.get_symbol_builder(false));
+                }
+            }
+
+            //The player's lifespan is left up to the player
+            if lfc.age >= species.get_lifespan() && inp.get(eid).is_none() {
+                println!("Entity is dying of old age");
+                hpc.turn_damage = hpc.value;
+            }
+        }
+
+        //Count how many of each species live in each chunk, so that crowded chunks stop breeding
+        let mut populations: FxHashMap<(IPosition, Species), usize> = FxHashMap::default();
+
+        for (spc, _lfc, pos) in (&spc, &lfc, &pos).join() {
+            *populations
+                .entry((pos.pos.global_to_local().0, spc.species))
+                .or_insert(0) += 1;
+        }
+
+        let ready: Vec<_> = (&eids, &spc, &lfc, &pos, &dig, !&inp)
+            .join()
+            .filter(|(_, spc, lfc, _, dig, _)| {
+                !lfc.is_juvenile(spc.species)
+                    && lfc.breeding_cooldown == 0
+                    //Only well fed creatures have young
+                    && dig.get_hunger_stage(&edb, &spc.species.get_metabolism()) <= HungerStage::Fed
+            })
+            .map(|(eid, spc, _, pos, _, _)| (eid, spc.species, pos.pos))
+            .collect();
+
+        let mut parents = Vec::new();
+
+        for (i, (parent, species, parent_pos)) in ready.iter().enumerate() {
+            if parents.contains(parent) || thread_rng().gen_range(0..BREEDING_CHANCE) != 0 {
+                continue;
+            }
+
+            let population = populations
+                .entry((parent_pos.global_to_local().0, *species))
+                .or_insert(0);
+
+            if *population >= species.get_population_cap() {
+                continue;
+            }
+
+            let mate = ready[i + 1..].iter().find(|(mate, mate_species, mate_pos)| {
+                let diff = *mate_pos - *parent_pos;
+
+                mate_species == species
+                    && !parents.contains(mate)
+                    && diff.x.abs() <= MATE_RANGE
+                    && diff.y.abs() <= MATE_RANGE
+            });
+
+            if let Some((mate, _, _)) = mate {
+                //Young are born next to their parent, wherever there's room
+                let birth_pos = (-1..=1)
+                    .flat_map(|dy| (-1..=1).map(move |dx| IPosition::new(dx, dy)))
+                    .map(|offset| *parent_pos + offset)
+                    .find(|birth_pos| {
+                        twld.get(*birth_pos).map_or(false, |chunk_tile| {
+                            !chunk_tile.tile.tile_type.collides()
+                                && !chunk_tile
+                                    .entities
+                                    .iter()
+                                    .any(|entity| col.get(*entity).is_some())
+                        })
+                    });
+
+                if let Some(birth_pos) = birth_pos {
+                    println!("Entity has had young");
+
+                    let young = CreatureBuilder::for_species(*species).build_young(&lup, &eids);
+                    twld.spawn_entity(young, birth_pos, &mut pos);
+
+                    *population += 1;
+                    parents.push(*parent);
+                    parents.push(*mate);
+                }
+            }
+        }
+
+        for parent in parents {
+            if let (Some(lfc), Some(spc)) = (lfc.get_mut(parent), spc.get(parent)) {
+                lfc.breeding_cooldown = spc.species.get_breeding_interval();
+            }
+        }
+    }
+}
@@ -8,21 +8,32 @@ impl<'a> System<'a> for MovementResolutionSystem {
     type SystemData = (
         Entities<'a>,
         WriteExpect<'a, TileWorldResource>,
+        WriteExpect<'a, SoundResource>,
         ReadStorage<'a, CollisionComponent>,
+        ReadStorage<'a, ColliderComponent>,
+        ReadStorage<'a, MovementCostComponent>,
+        ReadStorage<'a, SpeciesComponent>,
+        ReadStorage<'a, AnatomyComponent>,
+        ReadStorage<'a, StatusEffectComponent>,
         WriteStorage<'a, IntendedMovementComponent>,
         WriteStorage<'a, PositionComponent>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (eids, mut twld, col, mut imc, mut pos) = data;
+        let (eids, mut twld, mut snd, col, cld, mvc, spc, ana, sts, mut imc, mut pos) = data;
 
-        for (eid, col, imc, pos) in (&eids, &col, &mut imc, &mut pos).join() {
+        for (eid, col, imc, pos, spc) in (&eids, &col, &mut imc, &mut pos, (&spc).maybe()).join() {
             if imc.delta != IPosition::ZERO {
                 //Take current position
                 //Remove entity from entity map at current position
 
                 let new_pos = pos.pos + imc.delta;
 
+                let size = spc.map(|spc| spc.species.get_size()).unwrap_or_default();
+                let new_cost = twld
+                    .get(new_pos)
+                    .and_then(|chunk_tile| chunk_tile.get_movement_cost(size, &cld, &mvc));
+
                 if let Some(new_chunk_tile) = twld.get_mut(new_pos) {
                     if !col.tile_collision.is_some() && col.entity_collisions.is_empty() {
                         //Apply intended movement delta
@@ -44,6 +55,22 @@ impl<'a> System<'a> for MovementResolutionSystem {
                         chunk_tile.entities.remove(index);
 
                         pos.pos = new_pos;
+
+                        //Costly terrain, crippled legs and ailments all slow the entity's next step
+                        let limp = ana.get(eid).map_or(0, |ana| ana.get_movement_penalty());
+                        let ailing = sts
+                            .get(eid)
+                            .map_or(0, |sts| sts.get_modifiers().movement_delay);
+                        imc.movement_delay =
+                            new_cost.unwrap_or(1).saturating_sub(1) + limp + ailing;
+
+                        snd.emit(new_pos, SoundType::Footsteps, size, Some(eid));
+
+                        if let Some(spc) = spc {
+                            twld.get_mut(new_pos)
+                                .unwrap()
+                                .add_scent(spc.species, MAX_SCENT_STRENGTH);
+                        }
                     }
                 } else {
                     println!("Entity trying to move into unloaded tile!");
@@ -1,32 +1,83 @@
-use specs::{Join, Entities, ReadStorage, System, WriteStorage};
+use specs::{Entities, Entity, Join, ReadExpect, ReadStorage, System, WriteStorage};
 
 use crate::prelude::*;
 
+//How many turns a timid creature keeps running after hearing a fight it can't see
+const PANIC_TURNS: u32 = 10;
+//How fresh a trail has to be before a creature takes notice of it
+const SCENT_NOTICE_STRENGTH: u8 = MAX_SCENT_STRENGTH / 2;
+
 pub struct PerceptionResolutionSystem;
 
 impl<'a> System<'a> for PerceptionResolutionSystem {
     type SystemData = (
         Entities<'a>,
+        ReadExpect<'a, TileWorldResource>,
+        ReadExpect<'a, ClockResource>,
+        ReadStorage<'a, PositionComponent>,
         ReadStorage<'a, DigestionComponent>,
         ReadStorage<'a, EdibleComponent>,
+        ReadStorage<'a, HydrationComponent>,
+        ReadStorage<'a, FatigueComponent>,
+        ReadStorage<'a, InputComponent>,
+        ReadStorage<'a, AIPersonalityComponent>,
         ReadStorage<'a, SpeciesComponent>,
+        ReadStorage<'a, IdComponent>,
+        ReadStorage<'a, TameComponent>,
         WriteStorage<'a, AIPerceptionComponent>,
         WriteStorage<'a, AIGoalComponent>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (eids, dig, edb, spc, mut per, mut gol) = data;
+        let (
+            eids,
+            twld,
+            clk,
+            pos,
+            dig,
+            edb,
+            hyd,
+            ftg,
+            inp,
+            pers,
+            spc,
+            idc,
+            tam,
+            mut per,
+            mut gol,
+        ) = data;
 
-        for (eid, dig, per, gol) in (&eids, (&dig).maybe(), &mut per, &mut gol).join() {
+        for (eid, dig, hyd, per, gol) in (
+            &eids,
+            (&dig).maybe(),
+            (&hyd).maybe(),
+            &mut per,
+            &mut gol,
+        )
+            .join()
+        {
             per.food.clear();
             per.allies.clear();
             per.threats.clear();
 
             let this_species = spc.get(eid);
+            let diet = pers.get(eid).map(|pers| &pers.diet);
+            let tame = tam.get(eid).filter(|tame| tame.tamed);
+            let is_owner =
+                |entity: Entity| tame.map_or(false, |tame| tame.is_owned_by(entity, &idc));
 
             for entity in per.all.iter() {
-                if edb.get(*entity).is_some() {
-                    per.food.push(*entity);
+                //Only things that fit our diet count as food
+                if let Some(edible) = edb.get(*entity) {
+                    if diet.map_or(true, |diet| edible.is_edible_by(diet)) {
+                        per.food.push(*entity);
+                    }
+                }
+
+                //Pets trust their owner whatever species they are
+                if is_owner(*entity) {
+                    per.allies.push(*entity);
+                    continue;
                 }
 
                 //TODO: Change to a more complex hostility check
@@ -34,19 +85,133 @@ impl<'a> System<'a> for PerceptionResolutionSystem {
                     if let Some(other_species) = spc.get(*entity) {
                         if this_species.species == other_species.species {
                             per.allies.push(*entity);
+                        } else if this_species.species.is_threatened_by(&other_species.species) {
+                            per.threats.push(*entity);
                         }
                     }
                 }
             }
 
+            //The loudest sound of fighting or dying
+            let alarming_noise = per
+                .heard
+                .iter()
+                .filter(|heard| heard.sound.sound_type.is_alarming())
+                .filter(|heard| !heard.sound.emitter.map_or(false, is_owner))
+                .max_by_key(|heard| heard.volume)
+                .map(|heard| heard.sound.pos);
+
+            //The loudest sound made by something out of sight that isn't one of our own
+            let unseen_noise = per
+                .heard
+                .iter()
+                .filter(|heard| {
+                    heard.sound.emitter.map_or(true, |emitter| {
+                        !per.all.contains(&emitter)
+                            && spc.get(emitter).map(|species| species.species)
+                                != this_species.map(|species| species.species)
+                    })
+                })
+                .max_by_key(|heard| heard.volume)
+                .map(|heard| heard.sound.pos);
+
+            //The freshest scent of a creature of another species nearby, and where it is
+            let smelled = match (this_species, pos.get(eid)) {
+                (Some(this_species), Some(this_pos)) => twld.get_strongest_scent(this_pos.pos, |species| {
+                    species != this_species.species
+                }),
+                _ => None,
+            }
+            //Pets can't tell their owner's trail apart from anyone else's, so they stop minding trails altogether
+            .filter(|_| tame.is_none())
+            .filter(|(_, scent)| scent.strength >= SCENT_NOTICE_STRENGTH);
+
+            if inp.get(eid).is_none() {
+                match pers.get(eid).map(|pers| &pers.disposition) {
+                    //Timid creatures bolt as soon as they see, hear or smell something dangerous
+                    Some(Disposition::Timid) => {
+                        let threat_scent = smelled
+                            .filter(|(_, scent)| {
+                                this_species.map_or(false, |this_species| {
+                                    this_species.species.is_threatened_by(&scent.species)
+                                })
+                            })
+                            .map(|(scent_pos, _)| scent_pos);
+
+                        let danger_pos = alarming_noise.or(threat_scent);
+
+                        if !per.threats.is_empty() || danger_pos.is_some() {
+                            let panic_turns = if danger_pos.is_some() { PANIC_TURNS } else { 0 };
+
+                            let flee_goal = gol.goal_stack.iter_mut().find_map(|goal| match goal {
+                                AIGoal::FleeDanger(goal) => Some(goal),
+                                _ => None,
+                            });
+
+                            if let Some(flee_goal) = flee_goal {
+                                if danger_pos.is_some() {
+                                    flee_goal.heard_at = danger_pos;
+                                    flee_goal.panic_turns = panic_turns;
+                                }
+                            } else {
+                                gol.goal_stack.push(AIGoal::FleeDanger(FleeDangerGoal {
+                                    heard_at: danger_pos,
+                                    panic_turns,
+                                }));
+                            }
+                        }
+                    }
+                    //Aggressive creatures hunt down prey they can smell, and go looking for whatever made a noise
+                    Some(Disposition::Agressive) => {
+                        let eats_meat = pers
+                            .get(eid)
+                            .map_or(false, |pers| pers.diet.can_consume(&NutrientType::Meat));
+
+                        let prey_scent = smelled.filter(|(_, scent)| {
+                            this_species.map_or(false, |this_species| {
+                                scent.species.is_threatened_by(&this_species.species)
+                            })
+                        });
+
+                        if let (true, Some((_, scent))) = (eats_meat, prey_scent) {
+                            if !gol.goal_stack.iter().any(|goal| match goal {
+                                AIGoal::TrackScent(_) | AIGoal::KillEntity(_) => true,
+                                _ => false,
+                            }) {
+                                gol.goal_stack.push(AIGoal::TrackScent(TrackScentGoal {
+                                    prey: scent.species,
+                                    kill_entity_goal: None,
+                                }));
+                            }
+                        }
+
+                        if let Some(noise_pos) = unseen_noise {
+                            if !gol.goal_stack.iter().any(|goal| match goal {
+                                AIGoal::InvestigateNoise(_) => true,
+                                _ => false,
+                            }) {
+                                gol.goal_stack.push(AIGoal::InvestigateNoise(InvestigateNoiseGoal {
+                                    noise_pos,
+                                    travel_to_position_goal: None,
+                                }));
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            let metabolism = this_species
+                .map(|spc| spc.species.get_metabolism())
+                .unwrap_or_default();
+
             println!("Entity perceiving things");
             if let Some(dig) = dig {
                 println!(
                     "Entity has digestion, total nutrition: {}",
                     dig.get_total_nutrition(&edb)
                 );
-                //TODO: allow for different thresholds depending on size of entity
-                if dig.get_total_nutrition(&edb) < 100 {
+                if dig.get_hunger_stage(&edb, &metabolism) >= HungerStage::Hungry {
                     println!("Entity hungry, fulfilling hunger");
                     if !gol.goal_stack.iter().any(|goal| match goal {
                         AIGoal::FulfilHunger(_) => true,
@@ -54,11 +219,45 @@ impl<'a> System<'a> for PerceptionResolutionSystem {
                     }) {
                         gol.goal_stack.push(AIGoal::FulfilHunger(FulfilHungerGoal {
                             eat_food_goal: None,
+                            butcher_goal: None,
                         }));
                     }
                 }
             }
 
+            if let Some(hyd) = hyd {
+                if metabolism.is_thirsty(hyd.value) {
+                    println!("Entity thirsty, fulfilling thirst");
+                    if !gol
+                        .goal_stack
+                        .iter()
+                        .any(|goal| matches!(goal, AIGoal::FulfilThirst(_)))
+                    {
+                        gol.goal_stack
+                            .push(AIGoal::FulfilThirst(FulfilThirstGoal::new()));
+                    }
+                }
+            }
+
+            //The player decides for themselves when to turn in
+            if let (None, Some(ftg)) = (inp.get(eid), ftg.get(eid)) {
+                let resting_hours = this_species.map_or(false, |spc| {
+                    !spc.species.get_activity_schedule().is_active(clk.is_day())
+                });
+
+                if !ftg.is_asleep() && ftg.wants_sleep(resting_hours) && per.threats.is_empty() {
+                    println!("Entity tired, going to sleep");
+                    if !gol
+                        .goal_stack
+                        .iter()
+                        .any(|goal| matches!(goal, AIGoal::Sleep(_)))
+                    {
+                        gol.goal_stack
+                            .push(AIGoal::Sleep(SleepGoal::new(WakeCondition::Rested)));
+                    }
+                }
+            }
+
             println!("Entity sees {} entities", per.all.len());
             println!("Entity sees {} food", per.food.len());
             println!("Entity sees {} threats", per.threats.len());
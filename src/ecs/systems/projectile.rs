@@ -0,0 +1,107 @@
+use specs::{
+    Builder, Entities, Join, LazyUpdate, Read, ReadStorage, System, WriteExpect, WriteStorage,
+};
+
+use crate::prelude::*;
+
+//How many tiles a projectile covers each turn
+const PROJECTILE_SPEED: usize = 3;
+
+pub struct ProjectileSystem;
+
+impl<'a> System<'a> for ProjectileSystem {
+    type SystemData = (
+        Entities<'a>,
+        Read<'a, LazyUpdate>,
+        WriteExpect<'a, TileWorldResource>,
+        WriteExpect<'a, SoundResource>,
+        ReadStorage<'a, ColliderComponent>,
+        WriteStorage<'a, HealthComponent>,
+        WriteStorage<'a, PositionComponent>,
+        WriteStorage<'a, ProjectileComponent>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (eids, lup, mut twld, mut snd, cld, mut hpc, mut pos, mut prj) = data;
+
+        let mut landed = Vec::new();
+
+        for (eid, prj) in (&eids, &mut prj).join() {
+            let thrower = prj.damage.attacker;
+            let mut stopped = false;
+
+            for _ in 0..PROJECTILE_SPEED {
+                let next_pos = match prj.path.pop_front() {
+                    Some(next_pos) => next_pos,
+                    None => {
+                        stopped = true;
+                        break;
+                    }
+                };
+
+                let chunk_tile = match twld.get(next_pos) {
+                    Some(chunk_tile) => chunk_tile,
+                    None => {
+                        println!("Projectile flew into an unloaded tile");
+                        stopped = true;
+                        break;
+                    }
+                };
+
+                //Walls stop it dead, and it drops where it was
+                if chunk_tile.tile.tile_type.collides() {
+                    stopped = true;
+                    break;
+                }
+
+                //Anything alive in the way takes the hit, and the projectile falls at its feet
+                let target = chunk_tile
+                    .entities
+                    .iter()
+                    .copied()
+                    .find(|entity| Some(*entity) != thrower && hpc.get(*entity).is_some());
+
+                if let Some(target) = target {
+                    hpc.get_mut(target)
+                        .unwrap()
+                        .pending_hits
+                        .push(prj.damage.clone());
+                    snd.emit(next_pos, SoundType::Combat, CreatureSize::Small, Some(eid));
+
+                    prj.pos = next_pos;
+                    stopped = true;
+                    break;
+                }
+
+                if chunk_tile
+                    .entities
+                    .iter()
+                    .any(|entity| cld.get(*entity).is_some())
+                {
+                    stopped = true;
+                    break;
+                }
+
+                prj.pos = next_pos;
+
+                lup.create_entity(&eids)
+                    .with(ParticleComponent {
+                        position: next_pos,
+                        height: prj.get_height(),
+                        particle_type: ParticleType::Projectile { drawn: false },
+                    })
+                    .build();
+            }
+
+            if stopped || prj.path.is_empty() {
+                landed.push((eid, prj.pos));
+            }
+        }
+
+        for (eid, landing_pos) in landed {
+            println!("Projectile landed at {}", landing_pos);
+            prj.remove(eid);
+            twld.spawn_entity(eid, landing_pos, &mut pos);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use specs::{Entities, Join, ReadStorage, System, WriteExpect, WriteStorage};
+
+use crate::prelude::*;
+
+pub struct RotSystem;
+
+impl<'a> System<'a> for RotSystem {
+    type SystemData = (
+        Entities<'a>,
+        WriteExpect<'a, TileWorldResource>,
+        ReadStorage<'a, ButcherableComponent>,
+        WriteStorage<'a, NameComponent>,
+        WriteStorage<'a, PositionComponent>,
+        WriteStorage<'a, RotComponent>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (eids, mut twld, btc, mut nam, mut pos, mut rot) = data;
+
+        let mut rotted_away = Vec::new();
+
+        for (eid, rot) in (&eids, &mut rot).join() {
+            let was_rotten = rot.is_rotten();
+
+            rot.remaining = rot.remaining.saturating_sub(1);
+
+            //Anything picked up or eaten stays rotten, instead of vanishing from a pack or stomach
+            if rot.remaining == 0 && pos.get(eid).is_some() {
+                rotted_away.push(eid);
+            } else if !was_rotten && rot.is_rotten() {
+                if let Some(name) = nam.get_mut(eid) {
+                    name.name = format!("rotting {}", name.name);
+                }
+            }
+        }
+
+        for eid in rotted_away {
+            println!("Entity has rotted away");
+
+            //Anything nobody got round to cutting from it goes with it
+            if let Some(btc) = btc.get(eid) {
+                for item in btc.yielded_entities.iter() {
+                    eids.delete(*item).unwrap();
+                }
+            }
+
+            twld.despawn_entity(eid, &mut pos);
+            eids.delete(eid).unwrap();
+        }
+    }
+}
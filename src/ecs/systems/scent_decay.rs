@@ -0,0 +1,33 @@
+use specs::{ReadExpect, System, WriteExpect};
+
+use crate::prelude::*;
+
+//Rain washes trails away several times faster than they fade on their own
+const SCENT_DECAY: u8 = 1;
+const RAIN_SCENT_DECAY: u8 = 4;
+
+pub struct ScentDecaySystem;
+
+impl<'a> System<'a> for ScentDecaySystem {
+    type SystemData = (
+        ReadExpect<'a, WeatherResource>,
+        WriteExpect<'a, TileWorldResource>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (weat, mut twld) = data;
+
+        let decay = match weat.current_weather {
+            WeatherStatus::Raining => RAIN_SCENT_DECAY,
+            _ => SCENT_DECAY,
+        };
+
+        for chunk in twld.buffer.iter_mut() {
+            for chunk_tile in chunk.tiles.iter_mut() {
+                if !chunk_tile.scents.is_empty() {
+                    chunk_tile.decay_scents(decay);
+                }
+            }
+        }
+    }
+}
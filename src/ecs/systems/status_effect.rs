@@ -0,0 +1,35 @@
+use specs::{Entities, Join, ReadStorage, System, WriteExpect, WriteStorage};
+
+use crate::prelude::*;
+
+pub struct StatusEffectSystem;
+
+impl<'a> System<'a> for StatusEffectSystem {
+    type SystemData = (
+        Entities<'a>,
+        WriteExpect<'a, MessageLogResource>,
+        ReadStorage<'a, InputComponent>,
+        WriteStorage<'a, HealthComponent>,
+        WriteStorage<'a, StatusEffectComponent>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (eids, mut mlg, inp, mut hpc, mut sts) = data;
+
+        for (eid, sts) in (&eids, &mut sts).join() {
+            if let Some(hpc) = hpc.get_mut(eid) {
+                for effect in sts.effects.iter() {
+                    effect.effect_type.on_tick(hpc);
+                }
+            }
+
+            for expired in sts.tick() {
+                println!("Entity is no longer {}", expired.get_name());
+
+                if inp.get(eid).is_some() {
+                    mlg.push(format!("You are no longer {}.", expired.get_name()));
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,9 @@ use specs::prelude::*;
 
 use crate::prelude::*;
 
+//How long something stays wet after it's out of the rain or water
+const WET_DURATION: u32 = 50;
+
 pub struct WeatherSystem;
 
 impl<'a> System<'a> for WeatherSystem {
@@ -11,10 +14,22 @@ impl<'a> System<'a> for WeatherSystem {
         Read<'a, LazyUpdate>,
         ReadExpect<'a, ViewportResource>,
         ReadExpect<'a, WeatherResource>,
+        ReadExpect<'a, TileWorldResource>,
+        ReadStorage<'a, PositionComponent>,
+        WriteStorage<'a, StatusEffectComponent>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
-        let (eids, lup, view, weat) = data;
+        let (eids, lup, view, weat, twld, pos, mut sts) = data;
+
+        let raining = matches!(weat.current_weather, WeatherStatus::Raining);
+
+        //Anything out in the rain or standing in water gets soaked
+        for (pos, sts) in (&pos, &mut sts).join() {
+            if (raining && !twld.is_sheltered(pos.pos)) || twld.is_water(pos.pos) {
+                sts.apply(StatusEffectType::Wet, Some(WET_DURATION));
+            }
+        }
 
         let (left, right, top, bottom) = view.get_viewport_bounds();
 
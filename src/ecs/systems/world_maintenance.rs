@@ -3,7 +3,6 @@ use std::{
     convert::TryFrom,
     fs::{self, File},
     io::Read,
-    path::PathBuf,
 };
 
 use log::{debug, trace};
@@ -19,6 +18,7 @@ pub struct WorldMaintenanceSystem {
 impl<'a> System<'a> for WorldMaintenanceSystem {
     type SystemData = (
         ReadExpect<'a, GenPackageResource>,
+        ReadExpect<'a, ClockResource>,
         WorldData<'a>,
         WriteExpect<'a, TileWorldResource>,
         WriteExpect<'a, IdGeneratorResource>,
@@ -30,6 +30,7 @@ impl<'a> System<'a> for WorldMaintenanceSystem {
     fn run(&mut self, data: Self::SystemData) {
         let (
             gpac,
+            clock,
             mut world_data,
             mut twld,
             mut id_generator,
@@ -153,7 +154,11 @@ impl<'a> System<'a> for WorldMaintenanceSystem {
                     }
                 }
 
+                //Keep the time saved alongside the chunks, so it carries on from where it was
+                clock.save(&mut self.save_buf);
+
                 twld.offset = new_offset;
+                twld.refresh_loaded_chunk_connectivity();
 
                 // TODO Optimize this to only recompute variants on new chunks and tiles adjacent to them rather than everywhere
 
@@ -172,7 +177,3 @@ impl<'a> System<'a> for WorldMaintenanceSystem {
         }
     }
 }
-
-fn chunk_filename(chunk_pos: IPosition) -> PathBuf {
-    save_path().join(format!("c_{:+04}_{:+04}.bin", chunk_pos.x, chunk_pos.y))
-}
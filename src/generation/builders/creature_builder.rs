@@ -3,6 +3,9 @@ use specs::{world::EntitiesRes, Builder, Entity, LazyUpdate};
 
 use crate::prelude::*;
 
+//How many turns a carcass lies around before there's nothing left of it
+const CORPSE_ROT_TIME: u32 = 2000;
+
 pub enum CreatureBuilder {
     Humanoid { species: Species },
     Deer,
@@ -10,21 +13,119 @@ pub enum CreatureBuilder {
 
 //To be refactored to either be split into multiple specialised builders or one very generic entity builder
 impl CreatureBuilder {
+    pub fn for_species(species: Species) -> Self {
+        match species {
+            Species::Deer => Self::Deer,
+            _ => Self::Humanoid { species },
+        }
+    }
+
+    pub fn get_species(&self) -> Species {
+        match self {
+            Self::Humanoid { species } => *species,
+            Self::Deer => Species::Deer,
+        }
+    }
+
+    //Creatures spawned with the world are already grown, somewhere in the first half of their lives
+    fn adult_life_cycle(species: Species) -> LifeCycleComponent {
+        LifeCycleComponent {
+            age: thread_rng().gen_range(species.get_maturity_age()..species.get_lifespan() / 2),
+            breeding_cooldown: 0,
+        }
+    }
+
+    //Creatures start out with as much in their stomachs as they'd eat themselves, at 100 nutrition a berry
+    fn sated_stomach(species: Species, lazy: &LazyUpdate, entities: &EntitiesRes) -> Vec<Entity> {
+        (0..species.get_metabolism().sated_threshold / 100)
+            .map(|_| ItemBuilder::Berry.build(lazy, entities))
+            .collect()
+    }
+
+    fn full_hydration(species: Species) -> HydrationComponent {
+        let capacity = species.get_metabolism().hydration_capacity;
+
+        HydrationComponent {
+            value: capacity,
+            max_value: capacity,
+        }
+    }
+
+    //What a creature leaves behind when it dies
+    pub fn build_corpse(species: Species, lazy: &LazyUpdate, entities: &EntitiesRes) -> Entity {
+        let yielded_entities = species
+            .get_butchering_yield()
+            .iter()
+            .map(|item| item.build(lazy, entities))
+            .collect();
+
+        lazy.create_entity(entities)
+            .with(DrawComponent {
+                seed: thread_rng().gen::<usize>(),
+                sprite_builder: SpriteBuilder::Corpse { species },
+                symbol_builder: Some(SymbolBuilder::Corpse { species }),
+            })
+            .with(ButcherableComponent { yielded_entities })
+            .with(RotComponent::new(CORPSE_ROT_TIME))
+            .with(NameComponent {
+                name: format!("{} corpse", species.get_name()),
+            })
+            .build()
+    }
+
+    //Newborns start out small and frail, and grow into their full health as they mature
+    pub fn build_young(&self, lazy: &LazyUpdate, entities: &EntitiesRes) -> Entity {
+        let species = self.get_species();
+        let entity = self.build(lazy, entities);
+
+        lazy.insert(
+            entity,
+            HealthComponent {
+                hit_particle: Some(ParticleBuilder::Blood { spawn_height: 1 }),
+                turn_damage: 0,
+                pending_hits: Vec::new(),
+                value: species.get_max_health() / 2,
+                max_value: species.get_max_health() / 2,
+            },
+        );
+        lazy.insert(
+            entity,
+            AnatomyComponent::new(species.get_body_plan(), species.get_max_health() / 2),
+        );
+        lazy.insert(
+            entity,
+            DrawComponent {
+                seed: thread_rng().gen::<usize>(),
+                sprite_builder: species.get_sprite_builder(true),
+                symbol_builder: Some(species.get_symbol_builder(true)),
+            },
+        );
+        lazy.insert(
+            entity,
+            LifeCycleComponent {
+                age: 0,
+                breeding_cooldown: 0,
+            },
+        );
+
+        entity
+    }
+
     pub fn build(&self, lazy: &LazyUpdate, entities: &EntitiesRes) -> Entity {
         match self {
             Self::Humanoid { species } => {
                 //TODO: create stomach contents from something representative of the race
-                let stomach_contents = vec![
-                    ItemBuilder::Berry.build(lazy, entities),
-                    ItemBuilder::Berry.build(lazy, entities),
-                    ItemBuilder::Berry.build(lazy, entities),
-                    ItemBuilder::Berry.build(lazy, entities),
-                ];
+                let stomach_contents = Self::sated_stomach(*species, lazy, entities);
+                let mut inventory = InventoryComponent::default();
+                let attributes = AttributesComponent::roll(*species);
+                inventory.insert(ItemBuilder::Waterskin.build(lazy, entities));
+
                 lazy.create_entity(entities)
                     .with(VelocityComponent { x: 0, y: 0 })
                     .with(IntendedMovementComponent {
                         delta: IPosition::ZERO,
                         controlled: true,
+                        movement_delay: 0,
                     })
                     .with(ColliderComponent)
                     .with(CollisionComponent {
@@ -33,6 +134,7 @@ impl CreatureBuilder {
                     })
                     .with(AIActionComponent {
                         current_action: None,
+                        work_done: 0,
                     })
                     .with(AIGoalComponent {
                         goal_stack: Vec::new(),
@@ -45,22 +147,37 @@ impl CreatureBuilder {
                     .with(SpeciesComponent {
                         species: *species,
                     })
-                    .with(FieldOfViewComponent::new(12))
+                    .with(FieldOfViewComponent::new(attributes.get_sight_radius(12)))
                     .with(PathingComponent::default())
                     .with(HealthComponent {
                         hit_particle: Some(ParticleBuilder::Blood { spawn_height: 1 }),
                         turn_damage: 0,
-                        value: 100,
-                        max_value: 100,
+                        pending_hits: Vec::new(),
+                        value: species.get_max_health(),
+                        max_value: species.get_max_health(),
                     })
-                    .with(InventoryComponent::default())
+                    .with(AnatomyComponent::new(
+                        species.get_body_plan(),
+                        species.get_max_health(),
+                    ))
+                    .with(Self::adult_life_cycle(*species))
+                    .with(inventory)
                     .with(DigestionComponent {
                         contents: stomach_contents,
+                        ..Default::default()
+                    })
+                    .with(Self::full_hydration(*species))
+                    .with(FatigueComponent::default())
+                    .with(StatusEffectComponent::default())
+                    .with(attributes)
+                    .with(SkillsComponent::default())
+                    .with(AttackComponent {
+                        available_attacks: species.get_natural_attacks(),
                     })
                     .with(DrawComponent {
                         seed: thread_rng().gen::<usize>(),
-                        sprite_builder: SpriteBuilder::Humanoid { species: *species },
-                        symbol_builder: Some(SymbolBuilder::Humanoid { species: *species }),
+                        sprite_builder: species.get_sprite_builder(false),
+                        symbol_builder: Some(species.get_symbol_builder(false)),
                     })
                     .with(DeathComponent {
                         contained_entities: Vec::new(),
@@ -69,12 +186,14 @@ impl CreatureBuilder {
                     .build()
             }
             Self::Deer => {
-                let stomach_contents = vec![ItemBuilder::Berry.build(lazy, entities)];
+                let stomach_contents = Self::sated_stomach(Species::Deer, lazy, entities);
+                let attributes = AttributesComponent::roll(Species::Deer);
                 lazy.create_entity(entities)
                     .with(VelocityComponent { x: 0, y: 0 })
                     .with(IntendedMovementComponent {
                         delta: IPosition::ZERO,
                         controlled: true,
+                        movement_delay: 0,
                     })
                     .with(ColliderComponent)
                     .with(CollisionComponent {
@@ -83,6 +202,7 @@ impl CreatureBuilder {
                     })
                     .with(AIActionComponent {
                         current_action: None,
+                        work_done: 0,
                     })
                     .with(AIGoalComponent {
                         goal_stack: Vec::new(),
@@ -95,24 +215,41 @@ impl CreatureBuilder {
                     .with(SpeciesComponent {
                         species: Species::Deer,
                     })
-                    .with(FieldOfViewComponent::new(8))
+                    .with(FieldOfViewComponent::new(attributes.get_sight_radius(8)))
                     .with(PathingComponent::default())
                     .with(HealthComponent {
                         hit_particle: Some(ParticleBuilder::Blood { spawn_height: 1 }),
                         turn_damage: 0,
-                        value: 10,
-                        max_value: 10,
+                        pending_hits: Vec::new(),
+                        value: Species::Deer.get_max_health(),
+                        max_value: Species::Deer.get_max_health(),
                     })
+                    .with(AnatomyComponent::new(
+                        Species::Deer.get_body_plan(),
+                        Species::Deer.get_max_health(),
+                    ))
+                    .with(Self::adult_life_cycle(Species::Deer))
                     .with(DigestionComponent {
                         contents: stomach_contents,
+                        ..Default::default()
+                    })
+                    .with(Self::full_hydration(Species::Deer))
+                    .with(FatigueComponent::default())
+                    .with(StatusEffectComponent::default())
+                    .with(attributes)
+                    .with(SkillsComponent::default())
+                    .with(AttackComponent {
+                        available_attacks: Species::Deer.get_natural_attacks(),
+                    })
+                    .with(ArmourComponent {
+                        material: Material::Hide,
                     })
                     .with(DrawComponent {
                         seed: thread_rng().gen::<usize>(),
-                        sprite_builder: SpriteBuilder::Deer,
-                        symbol_builder: Some(SymbolBuilder::Deer),
+                        sprite_builder: Species::Deer.get_sprite_builder(false),
+                        symbol_builder: Some(Species::Deer.get_symbol_builder(false)),
                     })
                     .with(DeathComponent {
-                        //TODO: put meats
                         contained_entities: Vec::new(),
                     })
                     .build()
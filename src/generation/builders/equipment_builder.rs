@@ -6,26 +6,57 @@ use crate::prelude::*;
 pub enum EquipmentBuilder {
     Spear {
         head_material: Material,
+        head_shape: MaterialShape,
         handle_material: Material,
     },
     Pick {
         head_material: Material,
+        head_shape: MaterialShape,
         handle_material: Material,
     },
     Axe {
         head_material: Material,
+        head_shape: MaterialShape,
         handle_material: Material,
     },
     Knife {
         blade_material: Material,
+        blade_shape: MaterialShape,
         handle_material: Material,
     },
+    Shield {
+        board_material: Material,
+    },
 }
 
 impl EquipmentBuilder {
-    pub fn build(&self, lazy: &LazyUpdate, entities: &EntitiesRes) -> Entity {
+    //Harder heads hit harder, sharper ones bite deeper, and better work makes the most of both
+    fn get_damage_dice(
+        base_sides: u32,
+        material: Material,
+        shape: MaterialShape,
+        quality: Quality,
+    ) -> DiceRoll {
+        let sides = base_sides as i32 + material.get_hardness() as i32 - 3
+            + shape.get_sharpness()
+            + quality.get_damage_modifier();
+
+        DiceRoll {
+            rolls: 1,
+            dice: sides.max(2) as u32,
+            bonus: 0,
+        }
+    }
+
+    pub fn build(&self, quality: Quality, lazy: &LazyUpdate, entities: &EntitiesRes) -> Entity {
+        let prefix = quality.get_prefix();
+
         match self {
-            Self::Spear { head_material, .. } => lazy
+            Self::Spear {
+                head_material,
+                head_shape,
+                ..
+            } => lazy
                 .create_entity(entities)
                 .with(ItemComponent)
                 .with(DrawComponent {
@@ -37,14 +68,26 @@ impl EquipmentBuilder {
                     available_attacks: vec![Attack {
                         name: String::from("thrust"),
                         attack_type: AttackType::Thrust,
-                        attack_dice: DiceRoll::new_from_string("1d6"),
+                        attack_dice: Self::get_damage_dice(6, *head_material, *head_shape, quality),
+                        damage_type: DamageType::from_attack(AttackType::Thrust, *head_material),
+                        material: Some(*head_material),
                     }],
                 })
+                .with(ThrowableComponent {
+                    range: 8,
+                    attack_dice: Self::get_damage_dice(8, *head_material, *head_shape, quality),
+                    damage_type: DamageType::from_attack(AttackType::Thrust, *head_material),
+                    material: Some(*head_material),
+                })
                 .with(NameComponent {
-                    name: format!("{} spear", head_material.get_name()),
+                    name: format!("{}{} spear", prefix, head_material.get_name()),
                 })
                 .build(),
-            Self::Pick { head_material, .. } => lazy
+            Self::Pick {
+                head_material,
+                head_shape,
+                ..
+            } => lazy
                 .create_entity(entities)
                 .with(ItemComponent)
                 .with(DrawComponent {
@@ -52,18 +95,25 @@ impl EquipmentBuilder {
                     sprite_builder: SpriteBuilder::Pick,
                     symbol_builder: Some(SymbolBuilder::Pick),
                 })
+                .with(DiggerComponent)
                 .with(AttackComponent {
                     available_attacks: vec![Attack {
                         name: String::from("swing"),
                         attack_type: AttackType::Swing,
-                        attack_dice: DiceRoll::new_from_string("1d4"),
+                        attack_dice: Self::get_damage_dice(4, *head_material, *head_shape, quality),
+                        damage_type: DamageType::from_attack(AttackType::Swing, *head_material),
+                        material: Some(*head_material),
                     }],
                 })
                 .with(NameComponent {
-                    name: format!("{} pick", head_material.get_name()),
+                    name: format!("{}{} pick", prefix, head_material.get_name()),
                 })
                 .build(),
-            Self::Axe { head_material, .. } => lazy
+            Self::Axe {
+                head_material,
+                head_shape,
+                ..
+            } => lazy
                 .create_entity(entities)
                 .with(ItemComponent)
                 .with(DrawComponent {
@@ -75,14 +125,20 @@ impl EquipmentBuilder {
                     available_attacks: vec![Attack {
                         name: String::from("swing"),
                         attack_type: AttackType::Swing,
-                        attack_dice: DiceRoll::new_from_string("1d6"),
+                        attack_dice: Self::get_damage_dice(6, *head_material, *head_shape, quality),
+                        damage_type: DamageType::from_attack(AttackType::Swing, *head_material),
+                        material: Some(*head_material),
                     }],
                 })
                 .with(NameComponent {
-                    name: format!("{} axe", head_material.get_name()),
+                    name: format!("{}{} axe", prefix, head_material.get_name()),
                 })
                 .build(),
-            Self::Knife { blade_material, .. } => lazy
+            Self::Knife {
+                blade_material,
+                blade_shape,
+                ..
+            } => lazy
                 .create_entity(entities)
                 .with(ItemComponent)
                 .with(DrawComponent {
@@ -94,11 +150,33 @@ impl EquipmentBuilder {
                     available_attacks: vec![Attack {
                         name: String::from("stab"),
                         attack_type: AttackType::Stab,
-                        attack_dice: DiceRoll::new_from_string("1d4"),
+                        attack_dice: Self::get_damage_dice(
+                            4,
+                            *blade_material,
+                            *blade_shape,
+                            quality,
+                        ),
+                        damage_type: DamageType::from_attack(AttackType::Stab, *blade_material),
+                        material: Some(*blade_material),
                     }],
                 })
                 .with(NameComponent {
-                    name: format!("{} knife", blade_material.get_name()),
+                    name: format!("{}{} knife", prefix, blade_material.get_name()),
+                })
+                .build(),
+            Self::Shield { board_material } => lazy
+                .create_entity(entities)
+                .with(ItemComponent)
+                .with(DrawComponent {
+                    seed: thread_rng().gen::<usize>(),
+                    sprite_builder: SpriteBuilder::Shield,
+                    symbol_builder: Some(SymbolBuilder::Shield),
+                })
+                .with(ArmourComponent {
+                    material: *board_material,
+                })
+                .with(NameComponent {
+                    name: format!("{}{} shield", prefix, board_material.get_name()),
                 })
                 .build(),
         }
@@ -23,6 +23,10 @@ impl FurnitureBuilder {
                         lifetime: 0,
                     },
                 })
+                .with(WarmthComponent { radius: 3 })
+                .with(NameComponent {
+                    name: String::from("camp fire"),
+                })
                 .build(),
         }
     }
@@ -8,6 +8,10 @@ pub enum ItemBuilder {
     Log,
     Stone,
     Berry,
+    Meat,
+    Hide,
+    Bone,
+    Waterskin,
 }
 
 impl ItemBuilder {
@@ -43,6 +47,7 @@ impl ItemBuilder {
                     shape: MaterialShape::Log,
                     amount: 5,
                 })
+                .with(MovementCostComponent { cost: 1 })
                 .with(NameComponent {
                     name: String::from("log"),
                 })
@@ -60,6 +65,12 @@ impl ItemBuilder {
                     shape: MaterialShape::Rock,
                     amount: 5,
                 })
+                .with(ThrowableComponent {
+                    range: 6,
+                    attack_dice: DiceRoll::new_from_string("1d4"),
+                    damage_type: DamageType::Blunt,
+                    material: Some(Material::Stone),
+                })
                 .with(NameComponent {
                     name: String::from("stone"),
                 })
@@ -74,11 +85,78 @@ impl ItemBuilder {
                 .with(ItemComponent)
                 .with(EdibleComponent {
                     nutrient_value: 100,
+                    nutrient_type: NutrientType::Plant,
                 })
                 .with(NameComponent {
                     name: String::from("berry"),
                 })
                 .build(),
+            Self::Meat => lazy
+                .create_entity(entities)
+                .with(DrawComponent {
+                    seed: thread_rng().gen::<usize>(),
+                    sprite_builder: SpriteBuilder::Meat,
+                    symbol_builder: Some(SymbolBuilder::Meat),
+                })
+                .with(ItemComponent)
+                .with(EdibleComponent {
+                    nutrient_value: 200,
+                    nutrient_type: NutrientType::Meat,
+                })
+                .with(NameComponent {
+                    name: String::from("meat"),
+                })
+                .build(),
+            Self::Hide => lazy
+                .create_entity(entities)
+                .with(DrawComponent {
+                    seed: thread_rng().gen::<usize>(),
+                    sprite_builder: SpriteBuilder::Hide,
+                    symbol_builder: Some(SymbolBuilder::Hide),
+                })
+                .with(ItemComponent)
+                .with(MaterialComponent {
+                    material: Material::Hide,
+                    shape: MaterialShape::Pelt,
+                    amount: 5,
+                })
+                .with(NameComponent {
+                    name: String::from("hide"),
+                })
+                .build(),
+            Self::Bone => lazy
+                .create_entity(entities)
+                .with(DrawComponent {
+                    seed: thread_rng().gen::<usize>(),
+                    sprite_builder: SpriteBuilder::Bone,
+                    symbol_builder: Some(SymbolBuilder::Bone),
+                })
+                .with(ItemComponent)
+                .with(MaterialComponent {
+                    material: Material::Bone,
+                    shape: MaterialShape::Shard,
+                    amount: 5,
+                })
+                .with(NameComponent {
+                    name: String::from("bone"),
+                })
+                .build(),
+            Self::Waterskin => lazy
+                .create_entity(entities)
+                .with(DrawComponent {
+                    seed: thread_rng().gen::<usize>(),
+                    sprite_builder: SpriteBuilder::Waterskin,
+                    symbol_builder: Some(SymbolBuilder::Waterskin),
+                })
+                .with(ItemComponent)
+                .with(WaterContainerComponent {
+                    water: 200,
+                    capacity: 200,
+                })
+                .with(NameComponent {
+                    name: String::from("waterskin"),
+                })
+                .build(),
         }
     }
 }
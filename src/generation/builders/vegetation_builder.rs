@@ -27,7 +27,9 @@ impl VegetationBuilder {
                 })
                 .with(EdibleComponent {
                     nutrient_value: 50,
+                    nutrient_type: NutrientType::Plant,
                 })
+                .with(MovementCostComponent { cost: 1 })
                 .with(VegPropagationComponent {
                     propagation_chance: 100,
                     parent_builder: VegetationBuilder::Grass,
@@ -49,12 +51,17 @@ impl VegetationBuilder {
                         name: String::from("tree"),
                     })
                     .with(ColliderComponent)
+                    .with(ArmourComponent {
+                        material: Material::Wood,
+                    })
                     .with(HealthComponent {
                         hit_particle: Some(ParticleBuilder::Leaf { spawn_height: 4 }),
                         turn_damage: 0,
+                        pending_hits: Vec::new(),
                         value: 10,
                         max_value: 10,
                     })
+                    .with(AnatomyComponent::new(BodyPlan::Tree, 10))
                     .with(DeathComponent { contained_entities })
                     .build()
             }
@@ -73,13 +80,16 @@ impl VegetationBuilder {
                     .with(NameComponent {
                         name: String::from("berry bush"),
                     })
-                    .with(ColliderComponent)
+                    //Thick enough to push through slowly, rather than something to go around
+                    .with(MovementCostComponent { cost: 3 })
                     .with(HealthComponent {
                         hit_particle: Some(ParticleBuilder::Leaf { spawn_height: 1 }),
                         turn_damage: 0,
+                        pending_hits: Vec::new(),
                         value: 10,
                         max_value: 10,
                     })
+                    .with(AnatomyComponent::new(BodyPlan::Tree, 10))
                     .with(DeathComponent { contained_entities })
                     .build()
             }
@@ -25,10 +25,10 @@ use specs::{
 };
 use structopt::StructOpt;
 use tui::{
-    layout::{Constraint, Direction as LayoutDirection, Layout},
+    layout::{Constraint, Direction as LayoutDirection, Layout, Rect},
     style::{Color as TuiColor, Style},
     widgets::{Block, Borders, Gauge, List, ListItem, Paragraph},
-    Terminal,
+    Frame, Terminal,
 };
 
 use crate::prelude::*;
@@ -62,17 +62,27 @@ struct MainState {
     weather_system: WeatherSystem,
     input_resolution_system: InputResolutionSystem,
     perception_resolution_system: PerceptionResolutionSystem,
+    flow_field_calculation_system: FlowFieldCalculationSystem,
     goal_resolution_system: GoalResolutionSystem,
     action_resolution_system: ActionResolutionSystem,
+    projectile_system: ProjectileSystem,
     collision_calculation_system: CollisionCalculationSystem,
     movement_resolution_system: MovementResolutionSystem,
     collision_resolution_system: CollisionResolutionSystem,
     digestion_resolution_system: DigestionResolutionSystem,
+    hydration_resolution_system: HydrationResolutionSystem,
+    fatigue_resolution_system: FatigueResolutionSystem,
+    status_effect_system: StatusEffectSystem,
+    life_cycle_system: LifeCycleSystem,
     health_resolution_system: HealthResolutionSystem,
+    rot_system: RotSystem,
+    durability_system: DurabilitySystem,
     propagation_system: PropagationSystem,
+    scent_decay_system: ScentDecaySystem,
     particle_emitter_system: ParticleEmitterSystem,
     particle_system: ParticleSystem,
     field_of_view_calculation_system: FieldOfViewCalculationSystem,
+    hearing_calculation_system: HearingCalculationSystem,
     world_maintenance_system: WorldMaintenanceSystem,
     save_load_system: SaveLoadSystem,
 
@@ -87,6 +97,8 @@ impl MainState {
         let mut texture = Image::new(ctx, "/master8x8.png")?;
         texture.set_filter(FilterMode::Nearest);
 
+        let recipes = RecipeResource::load(ctx)?;
+
         // Clear and create save directory
         let save_path = save_path();
 
@@ -102,37 +114,55 @@ impl MainState {
         ecs_world.register::<AIGoalComponent>();
         ecs_world.register::<AIPerceptionComponent>();
         ecs_world.register::<AIPersonalityComponent>();
+        ecs_world.register::<AnatomyComponent>();
+        ecs_world.register::<ArmourComponent>();
         ecs_world.register::<AttackComponent>();
+        ecs_world.register::<AttributesComponent>();
         ecs_world.register::<ButcherableComponent>();
         ecs_world.register::<ColliderComponent>();
         ecs_world.register::<CollisionComponent>();
         ecs_world.register::<DeathComponent>();
         ecs_world.register::<DigestionComponent>();
+        ecs_world.register::<DiggerComponent>();
         ecs_world.register::<DrawComponent>();
+        ecs_world.register::<DurabilityComponent>();
         ecs_world.register::<EdibleComponent>();
+        ecs_world.register::<FatigueComponent>();
         ecs_world.register::<FieldOfViewComponent>();
         ecs_world.register::<HealthComponent>();
+        ecs_world.register::<HydrationComponent>();
         ecs_world.register::<IdComponent>();
         ecs_world.register::<InputComponent>();
         ecs_world.register::<IntendedMovementComponent>();
         ecs_world.register::<InventoryComponent>();
         ecs_world.register::<ItemComponent>();
+        ecs_world.register::<LifeCycleComponent>();
         ecs_world.register::<ManipulatorComponent>();
         ecs_world.register::<MaterialComponent>();
+        ecs_world.register::<MovementCostComponent>();
         ecs_world.register::<NameComponent>();
         ecs_world.register::<ParticleComponent>();
         ecs_world.register::<ParticleEmitterComponent>();
         ecs_world.register::<PathingComponent>();
         ecs_world.register::<PositionComponent>();
+        ecs_world.register::<ProjectileComponent>();
+        ecs_world.register::<RotComponent>();
         ecs_world.register::<SaveMarkerComponent>();
+        ecs_world.register::<SkillsComponent>();
         ecs_world.register::<SpeciesComponent>();
+        ecs_world.register::<StatusEffectComponent>();
+        ecs_world.register::<TameComponent>();
+        ecs_world.register::<ThrowableComponent>();
         ecs_world.register::<ToSaveComponent>();
         ecs_world.register::<VegPropagationComponent>();
         ecs_world.register::<VelocityComponent>();
+        ecs_world.register::<WarmthComponent>();
+        ecs_world.register::<WaterContainerComponent>();
 
         //Initialise all resources
         let keyboard = KeyboardResource {
             last_pressed_key: None,
+            previous_key: None,
             modifiers: KeyMods::default(),
         };
 
@@ -174,11 +204,17 @@ impl MainState {
         ecs_world.insert(mouse);
         ecs_world.insert(tile_world);
         ecs_world.insert(particle_map);
+        ecs_world.insert(recipes);
         ecs_world.insert(IdGeneratorResource::new());
         ecs_world.insert(SaveMarkerAllocatorResource::new());
         ecs_world.insert(PendingLoadResource::new());
         ecs_world.insert(ViewportResource::new());
         ecs_world.insert(WeatherResource::new());
+        ecs_world.insert(FlowFieldResource::new());
+        ecs_world.insert(SoundResource::new());
+        ecs_world.insert(DebugViewResource::new());
+        ecs_world.insert(MessageLogResource::new());
+        ecs_world.insert(ClockResource::load());
 
         let (char_width, char_height) = (8, 8);
         let (ui_width, ui_height) = (
@@ -217,17 +253,27 @@ impl MainState {
             weather_system: WeatherSystem,
             input_resolution_system: InputResolutionSystem,
             perception_resolution_system: PerceptionResolutionSystem,
+            flow_field_calculation_system: FlowFieldCalculationSystem,
             goal_resolution_system: GoalResolutionSystem,
             action_resolution_system: ActionResolutionSystem,
+            projectile_system: ProjectileSystem,
             collision_calculation_system: CollisionCalculationSystem,
             movement_resolution_system: MovementResolutionSystem,
             collision_resolution_system: CollisionResolutionSystem,
             digestion_resolution_system: DigestionResolutionSystem,
+            hydration_resolution_system: HydrationResolutionSystem,
+            fatigue_resolution_system: FatigueResolutionSystem,
+            status_effect_system: StatusEffectSystem,
+            life_cycle_system: LifeCycleSystem,
             health_resolution_system: HealthResolutionSystem,
+            rot_system: RotSystem,
+            durability_system: DurabilitySystem,
             propagation_system: PropagationSystem,
+            scent_decay_system: ScentDecaySystem,
             particle_emitter_system: ParticleEmitterSystem,
             particle_system: ParticleSystem,
             field_of_view_calculation_system: FieldOfViewCalculationSystem,
+            hearing_calculation_system: HearingCalculationSystem,
             world_maintenance_system: WorldMaintenanceSystem {
                 save_buf: Vec::new(),
                 ids: Vec::new(),
@@ -248,10 +294,81 @@ impl event::EventHandler<ggez::GameError> for MainState {
     fn key_down_event(
         &mut self,
         _ctx: &mut Context,
-        _keycode: KeyCode,
+        keycode: KeyCode,
         _keymods: KeyMods,
-        _repeat: bool,
+        repeat: bool,
     ) {
+        if repeat {
+            return;
+        }
+
+        match keycode {
+            KeyCode::F3 => {
+                let mut debug_view = self.ecs_world.write_resource::<DebugViewResource>();
+                debug_view.enabled = !debug_view.enabled;
+            }
+            KeyCode::Tab => {
+                let candidates: Vec<_> = {
+                    let entities = self.ecs_world.entities();
+                    let ai_goal = self.ecs_world.read_storage::<AIGoalComponent>();
+                    let position = self.ecs_world.read_storage::<PositionComponent>();
+
+                    (&entities, &ai_goal, &position)
+                        .join()
+                        .map(|(entity, _, _)| entity)
+                        .collect()
+                };
+
+                let mut debug_view = self.ecs_world.write_resource::<DebugViewResource>();
+
+                if debug_view.enabled {
+                    debug_view.select_next(&candidates);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn mouse_button_down_event(&mut self, _ctx: &mut Context, button: MouseButton, x: f32, y: f32) {
+        if button != MouseButton::Right
+            || !self.ecs_world.read_resource::<DebugViewResource>().enabled
+        {
+            return;
+        }
+
+        let char_mouse = IPosition::new(
+            (x / (RENDER_SCALE * 8.0)).floor() as i32,
+            (y / (RENDER_SCALE * 8.0)).floor() as i32,
+        );
+
+        if !(0..MAP_X_SIZE as i32).contains(&char_mouse.x)
+            || !(0..MAP_Y_SIZE as i32).contains(&char_mouse.y)
+        {
+            return;
+        }
+
+        let offset = MAP_X_SIZE as i32 / 2;
+        let top_left = self
+            .ecs_world
+            .read_resource::<ViewportResource>()
+            .camera_world_position
+            - IPosition::new(offset, offset);
+
+        let clicked = {
+            let tile_world = self.ecs_world.read_resource::<TileWorldResource>();
+            let ai_goal = self.ecs_world.read_storage::<AIGoalComponent>();
+
+            tile_world.get(top_left + char_mouse).and_then(|chunk_tile| {
+                chunk_tile
+                    .entities
+                    .iter()
+                    .copied()
+                    .find(|entity| ai_goal.get(*entity).is_some())
+            })
+        };
+
+        //Clicking on nothing clears the selection
+        self.ecs_world.write_resource::<DebugViewResource>().selected = clicked;
     }
 
     fn update(&mut self, ctx: &mut Context) -> GameResult {
@@ -294,6 +411,7 @@ impl event::EventHandler<ggez::GameError> for MainState {
         {
             let mut keyboard = self.ecs_world.write_resource::<KeyboardResource>();
 
+            keyboard.previous_key = keyboard.last_pressed_key;
             keyboard.last_pressed_key = keyboard::pressed_keys(ctx).iter().next().copied();
             keyboard.modifiers = keyboard::active_mods(ctx);
         }
@@ -315,18 +433,28 @@ impl event::EventHandler<ggez::GameError> for MainState {
         self.weather_system.run_now(&self.ecs_world);
         self.input_resolution_system.run_now(&self.ecs_world);
         self.perception_resolution_system.run_now(&self.ecs_world);
+        self.flow_field_calculation_system.run_now(&self.ecs_world);
         self.goal_resolution_system.run_now(&self.ecs_world);
         self.action_resolution_system.run_now(&self.ecs_world);
+        self.projectile_system.run_now(&self.ecs_world);
         self.collision_calculation_system.run_now(&self.ecs_world);
         self.movement_resolution_system.run_now(&self.ecs_world);
         self.collision_resolution_system.run_now(&self.ecs_world);
         self.digestion_resolution_system.run_now(&self.ecs_world);
+        self.hydration_resolution_system.run_now(&self.ecs_world);
+        self.fatigue_resolution_system.run_now(&self.ecs_world);
+        self.status_effect_system.run_now(&self.ecs_world);
+        self.life_cycle_system.run_now(&self.ecs_world);
         self.health_resolution_system.run_now(&self.ecs_world);
+        self.rot_system.run_now(&self.ecs_world);
+        self.durability_system.run_now(&self.ecs_world);
         self.propagation_system.run_now(&self.ecs_world);
+        self.scent_decay_system.run_now(&self.ecs_world);
         self.particle_emitter_system.run_now(&self.ecs_world);
         self.particle_system.run_now(&self.ecs_world);
         self.field_of_view_calculation_system
             .run_now(&self.ecs_world);
+        self.hearing_calculation_system.run_now(&self.ecs_world);
 
         self.ecs_world.maintain();
 
@@ -336,6 +464,7 @@ impl event::EventHandler<ggez::GameError> for MainState {
         self.ecs_world.maintain();
 
         self.current_tic += 1;
+        self.ecs_world.write_resource::<ClockResource>().tick();
 
         std::thread::sleep(Duration::from_millis(100));
 
@@ -358,6 +487,11 @@ impl event::EventHandler<ggez::GameError> for MainState {
             digestion,
             health,
             ai_goal,
+            species,
+            hydration,
+            fatigue,
+            anatomy,
+            status_effect,
         )) = (
             &data.input,
             &data.position,
@@ -367,6 +501,11 @@ impl event::EventHandler<ggez::GameError> for MainState {
             (&data.digestion).maybe(),
             (&data.health).maybe(),
             (&data.ai_goal).maybe(),
+            (&data.species).maybe(),
+            (&data.hydration).maybe(),
+            (&data.fatigue).maybe(),
+            (&data.anatomy).maybe(),
+            (&data.status_effect).maybe(),
         )
             .join()
             .next()
@@ -479,6 +618,116 @@ impl event::EventHandler<ggez::GameError> for MainState {
                 }
             }
 
+            //Overlay of everything the selected creature is thinking about
+            let debug_selection = data
+                .debug_view
+                .selected
+                .filter(|_| data.debug_view.enabled)
+                .and_then(|selected| data.position.get(selected).map(|pos| (selected, pos.pos)));
+
+            if let Some((selected, selected_pos)) = debug_selection {
+                let font_batch = &mut self.font_batch;
+                let mut highlight = |world_pos: IPosition, index: usize, color: Color| {
+                    let screen_pos = world_pos - top_left;
+
+                    if (0..MAP_X_SIZE as i32).contains(&screen_pos.x)
+                        && (0..MAP_Y_SIZE as i32).contains(&screen_pos.y)
+                    {
+                        GgBunnyChar {
+                            index,
+                            foreground: color,
+                            background: Some(Color::new(color.r, color.g, color.b, 0.25)),
+                            rotation: CharRotation::None,
+                            mirror: CharMirror::None,
+                        }
+                        .draw_to_font_batch(font_batch, screen_pos.as_tuple(), RENDER_SCALE);
+                    }
+                };
+
+                if let Some(selected_fov) = data.field_of_view.get(selected) {
+                    let radius = i32::try_from(selected_fov.shadowcast.radius()).unwrap();
+
+                    for ((fov_x, fov_y), visible) in selected_fov.shadowcast.fov().indexed_iter() {
+                        if *visible {
+                            let fov_pos = IPosition::new(fov_x as i32, fov_y as i32);
+
+                            highlight(
+                                fov_pos - IPosition::new(radius, radius) + selected_pos,
+                                0,
+                                Color::new(0.0, 0.0, 0.5, 1.0),
+                            );
+                        }
+                    }
+                }
+
+                if let Some(selected_perception) = data.ai_perception.get(selected) {
+                    let perceived = [
+                        (&selected_perception.food, Color::new(0.0, 0.75, 0.0, 1.0)),
+                        (&selected_perception.allies, Color::new(0.0, 0.5, 0.75, 1.0)),
+                        (&selected_perception.threats, Color::new(0.75, 0.0, 0.0, 1.0)),
+                    ];
+
+                    for (entities, color) in perceived.iter() {
+                        for entity in entities.iter() {
+                            if let Some(entity_pos) = data.position.get(*entity) {
+                                highlight(entity_pos.pos, 0, *color);
+                            }
+                        }
+                    }
+                }
+
+                let selected_path = data.ai_goal.get(selected).and_then(|selected_goal| {
+                    selected_goal
+                        .goal_stack
+                        .iter()
+                        .rev()
+                        .find_map(|goal| goal.get_path())
+                });
+
+                if let Some(path) = selected_path {
+                    for step in path {
+                        highlight(*step, b'.' as usize, Color::new(0.0, 0.75, 0.75, 1.0));
+                    }
+                }
+
+                highlight(selected_pos, 0, Color::new(0.75, 0.75, 0.0, 1.0));
+            }
+
+            //Show where a throw would fly, and where the cursor sits
+            if let Some(cursor) = input
+                .popup
+                .as_ref()
+                .and_then(|popup| popup.get_target_cursor())
+            {
+                let target_pos = position.pos + cursor;
+
+                for step in position.pos.line_to(target_pos) {
+                    let (index, color) = if step == target_pos {
+                        (b'X' as usize, Color::new(1.0, 0.25, 0.25, 1.0))
+                    } else {
+                        (b'.' as usize, Color::new(0.75, 0.75, 0.0, 1.0))
+                    };
+                    let screen_pos = step - top_left;
+
+                    if (0..MAP_X_SIZE as i32).contains(&screen_pos.x)
+                        && (0..MAP_Y_SIZE as i32).contains(&screen_pos.y)
+                    {
+                        GgBunnyChar {
+                            index,
+                            foreground: color,
+                            background: Some(Color::new(color.r, color.g, color.b, 0.25)),
+                            rotation: CharRotation::None,
+                            mirror: CharMirror::None,
+                        }
+                        .draw_to_font_batch(
+                            &mut self.font_batch,
+                            screen_pos.as_tuple(),
+                            RENDER_SCALE,
+                        );
+                    }
+                }
+            }
+
             self.tui
                 .draw(|f| {
                     let (left_pane, mut right_pane) = Layout::default()
@@ -498,6 +747,13 @@ impl event::EventHandler<ggez::GameError> for MainState {
                         .into_iter()
                         .collect_tuple()
                         .unwrap();
+                    let (status_pane, message_pane) = Layout::default()
+                        .direction(LayoutDirection::Vertical)
+                        .constraints([Constraint::Length(6), Constraint::Min(0)])
+                        .split(bottom_pane)
+                        .into_iter()
+                        .collect_tuple()
+                        .unwrap();
 
                     if let Some(inventory) = inventory {
                         let (inventory_pane, rest) = Layout::default()
@@ -522,8 +778,11 @@ impl event::EventHandler<ggez::GameError> for MainState {
                                     let c = index_to_letter(i).unwrap();
 
                                     if let Some(item) = slot {
-                                        let name = data.name.get(*item).unwrap();
-                                        ListItem::new(format!("{}) {}", c, name.name))
+                                        ListItem::new(format!(
+                                            "{}) {}",
+                                            c,
+                                            data.get_item_label(*item)
+                                        ))
                                     } else {
                                         ListItem::new(format!("{}) -", c))
                                     }
@@ -566,10 +825,16 @@ impl event::EventHandler<ggez::GameError> for MainState {
                                 .collect::<Vec<_>>(),
                         );
 
+                        let metabolism = species
+                            .map(|species| species.species.get_metabolism())
+                            .unwrap_or_default();
+
                         let block = Block::default()
                             .title(format!(
-                                "Stomach ({})",
-                                digestion.get_total_nutrition(&data.edible)
+                                "Stomach ({}/{}, {})",
+                                digestion.get_total_nutrition(&data.edible),
+                                metabolism.stomach_capacity,
+                                digestion.get_hunger_stage(&data.edible, &metabolism).get_name()
                             ))
                             .borders(Borders::ALL);
 
@@ -588,7 +853,7 @@ impl event::EventHandler<ggez::GameError> for MainState {
                         right_pane = rest;
 
                         let list = List::new(vec![if let Some(item) = &manipulator.held_item {
-                            ListItem::new(data.name.get(*item).unwrap().name.as_str())
+                            ListItem::new(data.get_item_label(*item))
                         } else {
                             ListItem::new("-")
                         }]);
@@ -598,8 +863,86 @@ impl event::EventHandler<ggez::GameError> for MainState {
                         f.render_widget(list.block(block), manipulator_pane);
                     }
 
+                    if let Some(anatomy) = anatomy {
+                        let (anatomy_pane, rest) = Layout::default()
+                            .direction(LayoutDirection::Vertical)
+                            .constraints([
+                                Constraint::Length(anatomy.parts.len() as u16 + 2),
+                                Constraint::Min(0),
+                            ])
+                            .split(right_pane)
+                            .into_iter()
+                            .collect_tuple()
+                            .unwrap();
+
+                        right_pane = rest;
+
+                        let list = List::new(
+                            anatomy
+                                .parts
+                                .iter()
+                                .map(|part| {
+                                    let state = if part.is_disabled() {
+                                        "crippled"
+                                    } else if part.wound > 0 {
+                                        "wounded"
+                                    } else {
+                                        "fine"
+                                    };
+
+                                    ListItem::new(if part.bleeding > 0 {
+                                        format!("{}: {}, bleeding", part.name, state)
+                                    } else {
+                                        format!("{}: {}", part.name, state)
+                                    })
+                                })
+                                .collect::<Vec<_>>(),
+                        );
+
+                        let block = Block::default().title("Body").borders(Borders::ALL);
+
+                        f.render_widget(list.block(block), anatomy_pane);
+                    }
+
+                    if let Some(status_effect) = status_effect.filter(|sts| !sts.effects.is_empty())
+                    {
+                        let (status_effect_pane, rest) = Layout::default()
+                            .direction(LayoutDirection::Vertical)
+                            .constraints([
+                                Constraint::Length(status_effect.effects.len() as u16 + 2),
+                                Constraint::Min(0),
+                            ])
+                            .split(right_pane)
+                            .into_iter()
+                            .collect_tuple()
+                            .unwrap();
+
+                        right_pane = rest;
+
+                        let list = List::new(
+                            status_effect
+                                .effects
+                                .iter()
+                                .map(|effect| {
+                                    let name = effect.effect_type.get_name();
+
+                                    ListItem::new(if let Some(remaining) = effect.remaining {
+                                        format!("{} ({})", name, remaining)
+                                    } else {
+                                        String::from(name)
+                                    })
+                                    .style(Style::default().fg(effect.effect_type.get_color()))
+                                })
+                                .collect::<Vec<_>>(),
+                        );
+
+                        let block = Block::default().title("Status").borders(Borders::ALL);
+
+                        f.render_widget(list.block(block), status_effect_pane);
+                    }
+
                     if let Some(ai_goal) = ai_goal {
-                        let (ai_goal_pane, _rest) = Layout::default()
+                        let (ai_goal_pane, rest) = Layout::default()
                             .direction(LayoutDirection::Vertical)
                             .constraints([
                                 Constraint::Length(
@@ -612,6 +955,8 @@ impl event::EventHandler<ggez::GameError> for MainState {
                             .collect_tuple()
                             .unwrap();
 
+                        right_pane = rest;
+
                         let list = List::new(
                             ai_goal
                                 .goal_stack
@@ -626,50 +971,120 @@ impl event::EventHandler<ggez::GameError> for MainState {
                         f.render_widget(list.block(block), ai_goal_pane);
                     }
 
-                    if let Some(health) = health {
-                        let max_value_str = format!("{}", health.max_value);
+                    if let Some((selected, _)) = debug_selection {
+                        let mut lines = vec![
+                            ListItem::new(format!(
+                                "Action: {}",
+                                data.ai_action
+                                    .get(selected)
+                                    .and_then(|action| action.current_action.as_ref())
+                                    .map_or(String::from("-"), |action| format!("{:?}", action))
+                            )),
+                        ];
+
+                        if let Some(selected_perception) = data.ai_perception.get(selected) {
+                            lines.push(ListItem::new(format!(
+                                "Sees {} food, {} allies, {} threats",
+                                selected_perception.food.len(),
+                                selected_perception.allies.len(),
+                                selected_perception.threats.len()
+                            )));
+                        }
+
+                        if let Some(selected_goal) = data.ai_goal.get(selected) {
+                            lines.extend(
+                                selected_goal
+                                    .goal_stack
+                                    .iter()
+                                    .rev()
+                                    .map(|goal| ListItem::new(goal.get_textual_representation(&data))),
+                            );
+                        }
 
-                        let (health_name_rect, health_bar_rect, _) = Layout::default()
+                        let (debug_pane, _rest) = Layout::default()
                             .direction(LayoutDirection::Vertical)
                             .constraints([
-                                Constraint::Length(1),
-                                Constraint::Length(1),
+                                Constraint::Length((lines.len() as u16).min(12) + 2),
                                 Constraint::Min(0),
                             ])
-                            .split(bottom_pane)
+                            .split(right_pane)
                             .into_iter()
                             .collect_tuple()
                             .unwrap();
 
-                        let (health_gauge_rect, health_display_rect) = Layout::default()
-                            .direction(LayoutDirection::Horizontal)
-                            .constraints([
-                                Constraint::Min(0),
-                                Constraint::Length(max_value_str.len() as u16 * 2 + 3),
-                            ])
-                            .split(health_bar_rect)
-                            .into_iter()
-                            .collect_tuple()
-                            .unwrap();
+                        let title = data
+                            .name
+                            .get(selected)
+                            .map_or(String::from("Debug"), |name| format!("Debug: {}", name.name));
+                        let block = Block::default().title(title).borders(Borders::ALL);
 
-                        let health_name = Paragraph::new("Health");
-                        let health_gauge = Gauge::default()
-                            .label("")
-                            .ratio(health.value as f64 / health.max_value as f64)
-                            .use_unicode(true)
-                            .gauge_style(Style::default().fg(TuiColor::Red));
-                        let health_display = Paragraph::new(format!(
-                            "[{:width$}/{}]",
-                            health.value,
-                            max_value_str,
-                            width = max_value_str.len()
-                        ));
-
-                        f.render_widget(health_name, health_name_rect);
-                        f.render_widget(health_gauge, health_gauge_rect);
-                        f.render_widget(health_display, health_display_rect);
+                        f.render_widget(List::new(lines).block(block), debug_pane);
                     }
 
+                    let (health_rect, hydration_rect, fatigue_rect) = Layout::default()
+                        .direction(LayoutDirection::Vertical)
+                        .constraints([
+                            Constraint::Length(2),
+                            Constraint::Length(2),
+                            Constraint::Length(2),
+                        ])
+                        .split(status_pane)
+                        .into_iter()
+                        .collect_tuple()
+                        .unwrap();
+
+                    if let Some(health) = health {
+                        render_gauge(
+                            f,
+                            health_rect,
+                            "Health",
+                            health.value as usize,
+                            health.max_value as usize,
+                            TuiColor::Red,
+                        );
+                    }
+
+                    if let Some(hydration) = hydration {
+                        render_gauge(
+                            f,
+                            hydration_rect,
+                            "Hydration",
+                            hydration.value,
+                            hydration.max_value,
+                            TuiColor::Blue,
+                        );
+                    }
+
+                    if let Some(fatigue) = fatigue {
+                        render_gauge(
+                            f,
+                            fatigue_rect,
+                            if fatigue.is_asleep() {
+                                "Fatigue (asleep)"
+                            } else {
+                                "Fatigue"
+                            },
+                            fatigue.fatigue,
+                            FatigueComponent::get_max_fatigue(),
+                            TuiColor::Magenta,
+                        );
+                    }
+
+                    //Show as many of the newest messages as fit
+                    let shown_messages = message_pane.height.saturating_sub(2) as usize;
+                    let messages: Vec<_> = data
+                        .message_log
+                        .messages
+                        .iter()
+                        .skip(data.message_log.messages.len().saturating_sub(shown_messages))
+                        .map(|message| ListItem::new(message.as_str()))
+                        .collect();
+                    let block = Block::default()
+                        .title(format!("Messages - {}", data.clock.get_time_string()))
+                        .borders(Borders::ALL);
+
+                    f.render_widget(List::new(messages).block(block), message_pane);
+
                     if let Some(popup) = &input.popup {
                         popup.render(f, map_pane, &data);
                     }
@@ -685,6 +1100,53 @@ impl event::EventHandler<ggez::GameError> for MainState {
     }
 }
 
+//A named bar over a row of the bottom pane, with the exact value beside it
+fn render_gauge(
+    f: &mut Frame<Ui>,
+    rect: Rect,
+    name: &str,
+    value: usize,
+    max_value: usize,
+    color: TuiColor,
+) {
+    let max_value_str = format!("{}", max_value);
+
+    let (name_rect, bar_rect) = Layout::default()
+        .direction(LayoutDirection::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1)])
+        .split(rect)
+        .into_iter()
+        .collect_tuple()
+        .unwrap();
+
+    let (gauge_rect, display_rect) = Layout::default()
+        .direction(LayoutDirection::Horizontal)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(max_value_str.len() as u16 * 2 + 3),
+        ])
+        .split(bar_rect)
+        .into_iter()
+        .collect_tuple()
+        .unwrap();
+
+    let gauge = Gauge::default()
+        .label("")
+        .ratio((value as f64 / max_value as f64).min(1.0))
+        .use_unicode(true)
+        .gauge_style(Style::default().fg(color));
+    let display = Paragraph::new(format!(
+        "[{:width$}/{}]",
+        value,
+        max_value_str,
+        width = max_value_str.len()
+    ));
+
+    f.render_widget(Paragraph::new(name), name_rect);
+    f.render_widget(gauge, gauge_rect);
+    f.render_widget(display, display_rect);
+}
+
 pub fn main() -> GameResult {
     fern::Dispatch::new()
         .format(|out, message, record| {
@@ -10,34 +10,57 @@ pub use crate::{
             ai_goal_status::AIGoalStatus,
             ai_goal_trait::AIGoalTrait,
             a_star::AStar,
+            chunk_graph::{
+                ChunkGraph,
+                ChunkNode,
+            },
             diet::Diet,
             disposition::Disposition,
+            drink_source::DrinkSource,
+            flow_field::FlowField,
+            flow_field_target::FlowFieldTarget,
             goals::{
                 arm_self::ArmSelfGoal,
                 attack_entity::AttackEntityGoal,
                 attack_in_direction::AttackInDirectionGoal,
                 build::BuildGoal,
+                build_shelter::BuildShelterGoal,
+                butcher::ButcherGoal,
+                command_pets::CommandPetsGoal,
                 craft::CraftGoal,
+                dig::DigGoal,
+                drink::DrinkGoal,
                 drop_item::DropItemGoal,
                 eat_from_inventory::EatFromInventoryGoal,
                 eat_from_world::EatFromWorldGoal,
                 eat::EatGoal,
+                feed_entity::FeedEntityGoal,
                 flee_danger::FleeDangerGoal,
                 fulfil_hunger::FulfilHungerGoal,
+                fulfil_thirst::FulfilThirstGoal,
+                gather_item::GatherItemGoal,
                 group_with_allies::GroupWithAlliesGoal,
                 hold_item::HoldItemGoal,
+                investigate_noise::InvestigateNoiseGoal,
                 kill_entity::KillEntityGoal,
                 move_in_direction::MoveInDirectionGoal,
                 move_to_entity::MoveToEntityGoal,
+                obey_owner::ObeyOwnerGoal,
+                settle::SettleGoal,
+                sleep::SleepGoal,
                 stow_item::StowItemGoal,
+                throw::ThrowGoal,
+                track_scent::TrackScentGoal,
                 travel_path::TravelPathGoal,
                 travel_to_position::TravelToPositionGoal,
                 wander::WanderGoal,
             },
+            pet_order::PetOrder,
             shadowcast::{
                 Shadowcast,
                 ShadowcastCallbacks
             },
+            wake_condition::WakeCondition,
         },
         assets::{
             sprite::Sprite,
@@ -46,21 +69,44 @@ pub use crate::{
             symbol_builder::SymbolBuilder,
         },
         crafting::{
+            ingredient_role::IngredientRole,
             material::Material,
             material_shape::MaterialShape,
+            quality::Quality,
             recipe::Recipe,
             recipe_ingredient::RecipeIngredient,
+            recipe_output::RecipeOutput,
             recipe_requirement::RecipeRequirement,
+            workstation::Workstation,
         },
         creatures::{
+            activity_schedule::ActivitySchedule,
+            attribute::Attribute,
+            body_part::BodyPart,
+            body_part_type::BodyPartType,
+            body_plan::BodyPlan,
+            creature_size::CreatureSize,
+            hunger_stage::HungerStage,
+            metabolism::Metabolism,
+            skill::Skill,
             species::Species,
         },
         effects::{
+            heard_sound::HeardSound,
             particle_type::ParticleType,
+            sound::Sound,
+            sound_type::SoundType,
+            stat_modifiers::StatModifiers,
+            status_effect::StatusEffect,
+            status_effect_type::StatusEffectType,
         },
         equipment::{
             attack::Attack,
+            attack_outcome::AttackOutcome,
             attack_type::AttackType,
+            combat_stats::CombatStats,
+            damage::Damage,
+            damage_type::DamageType,
         },
         geometry::{
             position::{
@@ -94,6 +140,7 @@ pub use crate::{
             chunk::Chunk,
             chunk_tile::ChunkTile,
             saved_chunk::SavedChunk,
+            scent::Scent,
             tile_layout::TileLayout,
             tile_type::TileType,
             tile_variant::TileVariant,
@@ -108,47 +155,72 @@ pub use crate::{
             ai_goal::AIGoalComponent,
             ai_perception::AIPerceptionComponent,
             ai_personality::AIPersonalityComponent,
+            anatomy::AnatomyComponent,
+            armour::ArmourComponent,
             attack::AttackComponent,
+            attributes::AttributesComponent,
             butcherable::ButcherableComponent,
             collider::ColliderComponent,
             collision::CollisionComponent,
             death::DeathComponent,
             digestion::DigestionComponent,
+            digger::DiggerComponent,
             draw::DrawComponent,
+            durability::DurabilityComponent,
             edible::EdibleComponent,
+            fatigue::FatigueComponent,
             field_of_view::FieldOfViewComponent,
             health::HealthComponent,
+            hydration::HydrationComponent,
             id::IdComponent,
             input::InputComponent,
             intended_movement::IntendedMovementComponent,
             inventory::InventoryComponent,
             item::ItemComponent,
+            life_cycle::LifeCycleComponent,
             material::MaterialComponent,
             manipulator::ManipulatorComponent,
+            movement_cost::MovementCostComponent,
             name::NameComponent,
             particle::ParticleComponent,
             particle_emitter::ParticleEmitterComponent,
             pathing::PathingComponent,
             position::PositionComponent,
+            projectile::ProjectileComponent,
+            rot::RotComponent,
             save_marker::SaveMarkerComponent,
+            skills::SkillsComponent,
             species::SpeciesComponent,
+            status_effect::StatusEffectComponent,
+            tame::TameComponent,
+            throwable::ThrowableComponent,
             to_save::ToSaveComponent,
             veg_propagation::VegPropagationComponent,
             velocity::VelocityComponent,
+            warmth::WarmthComponent,
+            water_container::WaterContainerComponent,
         },
         resources::{
+            clock::ClockResource,
+            debug_view::DebugViewResource,
+            flow_fields::FlowFieldResource,
             gen_package::GenPackageResource,
             id_generator::IdGeneratorResource,
             keyboard::KeyboardResource,
+            message_log::MessageLogResource,
             mouse::MouseResource,
             particle_map::ParticleMapResource,
             pending_load::PendingLoadResource,
+            recipes::{RecipeResource, SETTLER_TOOL_RECIPE},
             save_marker_allocator::SaveMarkerAllocatorResource,
+            sound::SoundResource,
             tile_world::TileWorldResource,
             viewport::ViewportResource,
             weather::WeatherResource,
         },
         system_data::{
+            character_data::CharacterData,
+            combat_data::CombatData,
             crafting_data::CraftingData,
             goal_data::GoalData,
             world_data::WorldData,
@@ -161,16 +233,26 @@ pub use crate::{
             collision_calculation::CollisionCalculationSystem,
             collision_resolution::CollisionResolutionSystem,
             digestion_resolution::DigestionResolutionSystem,
+            durability::DurabilitySystem,
+            fatigue_resolution::FatigueResolutionSystem,
             field_of_view_calculation::FieldOfViewCalculationSystem,
+            flow_field_calculation::FlowFieldCalculationSystem,
             goal_resolution::GoalResolutionSystem,
             health_resolution::HealthResolutionSystem,
+            hearing_calculation::HearingCalculationSystem,
+            hydration_resolution::HydrationResolutionSystem,
             input_resolution::InputResolutionSystem,
+            life_cycle::LifeCycleSystem,
             movement_resolution::MovementResolutionSystem,
             particle_emitter::ParticleEmitterSystem,
             particle::ParticleSystem,
             perception_resolution::PerceptionResolutionSystem,
+            projectile::ProjectileSystem,
             propagation::PropagationSystem,
+            rot::RotSystem,
             save_load::SaveLoadSystem,
+            scent_decay::ScentDecaySystem,
+            status_effect::StatusEffectSystem,
             weather::WeatherSystem,
             world_maintenance::WorldMaintenanceSystem,
         },
@@ -1,4 +1,7 @@
-use std::{env, path::PathBuf};
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
 
 use ggez::event::{KeyCode, KeyMods};
 use serde::{Deserialize, Serialize};
@@ -25,6 +28,15 @@ pub fn get_random_transforms_from_seed(seed: usize) -> (Rotation, Mirror) {
     )
 }
 
+pub fn capitalise(text: &str) -> String {
+    let mut chars = text.chars();
+
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
 pub fn fulfills_material_requirements(
     material: &MaterialComponent,
     (required_material, required_shape): (Option<Material>, Option<MaterialShape>),
@@ -154,6 +166,25 @@ pub fn save_path() -> PathBuf {
     .join("saves")
 }
 
+pub fn chunk_filename(chunk_pos: IPosition) -> PathBuf {
+    save_path().join(format!("c_{:+04}_{:+04}.bin", chunk_pos.x, chunk_pos.y))
+}
+
+pub fn clock_filename() -> PathBuf {
+    save_path().join("clock.bin")
+}
+
+//The other way around from chunk_filename, for picking saved chunks out of the save directory
+pub fn chunk_pos_from_filename(path: &Path) -> Option<IPosition> {
+    let name = path.file_name()?.to_str()?;
+    let mut coords = name.strip_prefix("c_")?.strip_suffix(".bin")?.split('_');
+
+    let x = coords.next()?.parse().ok()?;
+    let y = coords.next()?.parse().ok()?;
+
+    Some(IPosition::new(x, y))
+}
+
 pub fn serialize_data<T: Serialize>(t: &T, buf: &mut Vec<u8>) {
     rmp_serde::encode::write(buf, t).unwrap()
 }
@@ -171,3 +202,26 @@ pub fn deserializer(
 ) -> rmp_serde::decode::Deserializer<rmp_serde::decode::ReadReader<&[u8]>> {
     rmp_serde::decode::Deserializer::new(buf)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_filenames_round_trip() {
+        for chunk_pos in [
+            IPosition::new(0, 0),
+            IPosition::new(-3, 12),
+            IPosition::new(7, -1),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                chunk_pos_from_filename(&chunk_filename(*chunk_pos)),
+                Some(*chunk_pos)
+            );
+        }
+
+        assert_eq!(chunk_pos_from_filename(Path::new("e_0000000001.bin")), None);
+    }
+}